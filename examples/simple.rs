@@ -11,18 +11,14 @@ fn main() {
     let otff = OpenTypeFontFile::parse(buf).unwrap();
 
     for font in otff {
-        for table in font.iter() {
-            match table.tag() {
-                TableTag::Head => {
-                    let font_header_table = FontHeaderTable::parse(table.as_slice().unwrap_or_default()).unwrap().1;
-                    assert_eq!(font_header_table.font_revision(), 140050);
-                },
-                TableTag::Name => {
-                    let naming_table = NamingTable::parse(table.as_slice().unwrap_or_default()).unwrap().1;
-                    assert_eq!(naming_table.string_offset(), 318);
-                },
-                _ => {}
-            }
+        if let Some(table) = font.table(TableTag::Head) {
+            let font_header_table = FontHeaderTable::parse(table.as_slice().unwrap_or_default()).unwrap().1;
+            assert_eq!(font_header_table.font_revision(), 140050);
+        }
+
+        if let Some(table) = font.table(TableTag::Name) {
+            let naming_table = NamingTable::parse(table.as_slice().unwrap_or_default()).unwrap().1;
+            assert_eq!(naming_table.string_offset(), 318);
         }
     }
-}
\ No newline at end of file
+}
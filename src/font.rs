@@ -1,8 +1,29 @@
-use offset_table::OffsetTable;
+use metrics::FontMetrics;
+use offset_table::{OffsetTable, SfntVersion};
+use outline::{NullBuilder, OutlineBuilder};
+use parser::Parse;
 use std::ops;
 use table::Table;
-use tables::TableTag;
-use table_record::parse_table_record;
+use tables::{GlyphId, Tag, TableTag};
+use tables::cff::CompactFontFormatTable;
+use tables::cff2::CompactFontFormat2Table;
+use tables::cmap::CharacterGlyphIndexMappingTable;
+use tables::glyf::GlyphDataTable;
+use tables::head::FontHeaderTable;
+use tables::hhea::HorizontalHeaderTable;
+use tables::hmtx::HorizontalMetricsTable;
+use tables::loca::IndexToLocationTable;
+use tables::maxp::MaximumProfileTable;
+use tables::os2::Os2;
+use tables::post::PostScriptTable;
+use tables::vhea::VerticalHeaderTable;
+use tables::vmtx::VerticalMetricsTable;
+use tables::vorg::VerticalOriginTable;
+use table_record::{TableRecord, parse_table_record};
+use types::Rect;
+
+/// Size of a TableRecord in bytes.
+const TABLE_RECORD_SIZE: usize = 16;
 
 pub struct Font<'otf> {
     buf: &'otf[u8],
@@ -19,6 +40,249 @@ impl<'otf> Font<'otf> {
         }
     }
 
+    /// Find the table identified by the given table tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::tables::TableTag;
+    ///
+    /// let buf = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    ///
+    /// let table = font.table(TableTag::Head).unwrap();
+    /// assert_eq!(table.tag(), TableTag::Head);
+    /// assert_eq!(table.as_slice().unwrap().len(), 54);
+    ///
+    /// assert!(font.table(TableTag::Cff).is_none());
+    /// ```
+    pub fn table(&self, table_tag: TableTag) -> Option<Table<'otf>> {
+        self.table_by_tag(Tag::from(table_tag))
+    }
+
+    /// Find the table identified by the given tag.
+    ///
+    /// Tables that are not known by [TableTag](tables/enum.TableTag.html) are returned as
+    /// `TableTag::Unknown`.
+    pub fn table_by_tag(&self, tag: Tag) -> Option<Table<'otf>> {
+        let table_record = self.find_table_record(tag)?;
+
+        Some(Table::new(self.buf, TableTag::from(table_record.table_tag()), table_record.checksum(),
+                        table_record.offset() as usize, table_record.length() as usize))
+    }
+
+    /// Binary search of a TableRecord.
+    ///
+    /// Entries in the Table Record are sorted in ascending order by tag, the searchRange,
+    /// entrySelector and rangeShift fields of the Offset Table are used to drive the search. If
+    /// those fields are inconsistent with the number of tables, they are computed again from
+    /// numTables.
+    fn find_table_record(&self, tag: Tag) -> Option<TableRecord> {
+        let num_tables = usize::from(self.offset_table.num_tables());
+
+        if num_tables == 0 {
+            return None;
+        }
+
+        let records = self.remainder.get(..num_tables * TABLE_RECORD_SIZE)?;
+        let record_at = |index: usize| -> Option<TableRecord> {
+            parse_table_record(&records[index * TABLE_RECORD_SIZE..]).ok().map(|(_, table_record)| table_record)
+        };
+
+        let (search_range, entry_selector, range_shift) = search_parameters(&self.offset_table);
+
+        // If the tag is greater or equal to the tag of the record at rangeShift, the search is
+        // restricted to the last searchRange records, else to the first searchRange records.
+        let mut index = 0;
+        if range_shift > 0 && record_at(range_shift)?.table_tag() <= tag {
+            index = range_shift;
+        }
+
+        let mut step = search_range;
+        for _ in 0..entry_selector {
+            step /= 2;
+            if record_at(index + step)?.table_tag() <= tag {
+                index += step;
+            }
+        }
+
+        record_at(index).filter(|table_record| table_record.table_tag() == tag)
+    }
+
+    /// Glyph mapped to the given character by the preferred Unicode subtable of the 'cmap' table.
+    ///
+    /// Return None if the character is not mapped, or if the 'cmap' table is missing or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    ///
+    /// let buf = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    ///
+    /// assert_eq!(font.glyph_index('o'), Some(83));
+    /// assert_eq!(font.glyph_index('\u{1F600}'), None);
+    /// ```
+    pub fn glyph_index(&self, character: char) -> Option<GlyphId> {
+        let cmap = CharacterGlyphIndexMappingTable::parse(self.table(TableTag::Cmap)?.as_slice().ok()?).ok()?;
+
+        cmap.best_unicode_subtable()?.get_glyph_id(u32::from(character))
+    }
+
+    /// Layout metrics of the font.
+    ///
+    /// The 'head', 'hhea', 'hmtx' and 'maxp' tables are required, return None if one of them is
+    /// missing or malformed. The 'OS/2' and 'post' tables are optional.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    ///
+    /// let buf = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    ///
+    /// let font_metrics = font.metrics().unwrap();
+    ///
+    /// assert_eq!(font_metrics.units_per_em(), 2048);
+    /// assert_eq!(font_metrics.ascender(), 1900);
+    /// assert_eq!(font_metrics.cap_height(), Some(1456));
+    /// assert_eq!(font_metrics.underline_position(), Some(-150));
+    ///
+    /// // Glyph 'o'
+    /// assert_eq!(font_metrics.advance_width(83), Some(1168));
+    /// assert_eq!(font_metrics.left_side_bearing(83), Some(91));
+    /// ```
+    pub fn metrics(&self) -> Option<FontMetrics> {
+        let font_header_table = FontHeaderTable::parse(self.table(TableTag::Head)?.as_slice().ok()?).ok()?.1;
+        let horizontal_header_table = HorizontalHeaderTable::parse(self.table(TableTag::Hhea)?.as_slice().ok()?).ok()?.1;
+        let maximum_profile_table = MaximumProfileTable::parse(self.table(TableTag::Maxp)?.as_slice().ok()?).ok()?.1;
+        let horizontal_metrics_table = HorizontalMetricsTable::parse(self.table(TableTag::Hmtx)?.as_slice().ok()?,
+            horizontal_header_table.number_of_hmetrics(), maximum_profile_table.num_glyphs()).ok()?;
+
+        let os2 = self.table(TableTag::Os2)
+            .and_then(|table| Os2::parse(table.as_slice().ok()?).ok())
+            .map(|(_, os2)| os2);
+        let post_script_table = self.table(TableTag::Post)
+            .and_then(|table| PostScriptTable::parse(table.as_slice().ok()?).ok())
+            .map(|(_, post_script_table)| post_script_table);
+
+        Some(FontMetrics::new(font_header_table, horizontal_header_table, horizontal_metrics_table, os2,
+                              post_script_table))
+    }
+
+    /// Send the outline of the given glyph to the builder and return its bounding box.
+    ///
+    /// The outline is read from the 'glyf' and 'loca' tables of TrueType fonts, and from the
+    /// 'CFF ' or 'CFF2' table of CFF fonts, the default instance being used for variable fonts.
+    /// Return None if the glyph has no outline (e.g. the space character), if the glyph ID is out
+    /// of bounds or if the tables are missing or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::outline::OutlineBuilder;
+    /// use otf::types::Rect;
+    ///
+    /// #[derive(Default)]
+    /// struct ContourCounter {
+    ///     contours: usize
+    /// }
+    ///
+    /// impl OutlineBuilder for ContourCounter {
+    ///     fn move_to(&mut self, _x: f32, _y: f32) {}
+    ///     fn line_to(&mut self, _x: f32, _y: f32) {}
+    ///     fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+    ///     fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+    ///     fn close(&mut self) { self.contours += 1; }
+    /// }
+    ///
+    /// let buf = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    ///
+    /// // Glyph 'o'
+    /// let mut counter = ContourCounter::default();
+    /// assert_eq!(font.outline_glyph(83, &mut counter), Some(Rect::new(91, -20, 1076, 1102)));
+    /// assert_eq!(counter.contours, 2);
+    /// ```
+    pub fn outline_glyph<B: OutlineBuilder>(&self, glyph_id: GlyphId, builder: &mut B) -> Option<Rect<i16>> {
+        match self.offset_table.sfnt_version() {
+            SfntVersion::TrueType => {
+                let font_header_table = FontHeaderTable::parse(self.table(TableTag::Head)?.as_slice().ok()?).ok()?.1;
+                let maximum_profile_table = MaximumProfileTable::parse(self.table(TableTag::Maxp)?.as_slice().ok()?).ok()?.1;
+                let loca = IndexToLocationTable::parse(self.table(TableTag::Loca)?.as_slice().ok()?,
+                    font_header_table.index_to_loc_format(), maximum_profile_table.num_glyphs()).ok()?;
+                let glyf = GlyphDataTable::parse(self.table(TableTag::Glyf)?.as_slice().ok()?, loca).ok()?;
+
+                glyf.outline(glyph_id, builder).ok()?
+            },
+            SfntVersion::CFF => match self.table(TableTag::Cff) {
+                Some(table) => CompactFontFormatTable::parse(table.as_slice().ok()?).ok()?
+                    .outline(glyph_id, builder).ok()?,
+                None => CompactFontFormat2Table::parse(self.table(TableTag::Cff2)?.as_slice().ok()?).ok()?
+                    .outline(glyph_id, &[], builder).ok()?
+            }
+        }
+    }
+
+    /// Y coordinate of the vertical origin of the given glyph, used to lay out vertical text.
+    ///
+    /// The origin is read from the 'VORG' table of CFF fonts. Otherwise it is computed from the
+    /// top side bearing of the 'vmtx' table and the top of the glyph bounding box. Glyphs without
+    /// vertical metrics or without outline fall back to the ascender of the font (see
+    /// [FontMetrics](metrics/struct.FontMetrics.html#method.ascender)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    ///
+    /// let buf = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    ///
+    /// // No vertical metrics
+    /// assert_eq!(font.vertical_origin(83), Some(1900));
+    /// ```
+    pub fn vertical_origin(&self, glyph_id: GlyphId) -> Option<i16> {
+        if self.offset_table.sfnt_version() == SfntVersion::CFF {
+            if let Some(table) = self.table(TableTag::Vorg) {
+                let vertical_origin_table = VerticalOriginTable::parse(table.as_slice().ok()?).ok()?.1;
+                return Some(vertical_origin_table.vert_origin_y(glyph_id));
+            }
+        }
+
+        if let Some(vertical_origin) = self.vertical_origin_from_metrics(glyph_id) {
+            return Some(vertical_origin);
+        }
+
+        self.metrics().map(|font_metrics| font_metrics.ascender())
+    }
+
+    /// Vertical origin computed as `tsb + yMax` from the 'vhea' and 'vmtx' tables.
+    fn vertical_origin_from_metrics(&self, glyph_id: GlyphId) -> Option<i16> {
+        let vertical_header_table = VerticalHeaderTable::parse(self.table(TableTag::Vhea)?.as_slice().ok()?).ok()?.1;
+        let maximum_profile_table = MaximumProfileTable::parse(self.table(TableTag::Maxp)?.as_slice().ok()?).ok()?.1;
+        let vertical_metrics_table = VerticalMetricsTable::parse(self.table(TableTag::Vmtx)?.as_slice().ok()?,
+            vertical_header_table.num_of_long_ver_metrics(), maximum_profile_table.num_glyphs()).ok()?;
+        let bounding_box = self.outline_glyph(glyph_id, &mut NullBuilder)?;
+
+        vertical_metrics_table.vertical_origin(glyph_id, bounding_box)
+            .map(|vertical_origin| vertical_origin.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16)
+    }
+
     /// TableRecord iterator. Each iteration will parse the next TableRecord lazily.
     pub fn iter(&self) -> FontIterator {
         FontIterator {
@@ -63,33 +327,231 @@ impl<'otf> Iterator for FontIterator<'otf> {
 
     /// Try to parse the next TableRecord.
     ///
-    /// If the parsing fail or if the last TableRecord has been parsed, return None. If the
-    /// table tag is unknown, skip and try to parse the next one.
+    /// If the parsing fail or if the last TableRecord has been parsed, return None. Tables with an
+    /// unknown tag are returned as `TableTag::Unknown`.
     fn next(&mut self) -> Option<Table<'otf>> {
-        loop {
-            if self.pos >= self.num_tables {
-                break;
-            }
-
-            match parse_table_record(self.remainder) {
-                Ok((bytes, table_record)) => {
-                    self.remainder = bytes;
-                    self.pos = self.pos + 1;
-
-                    if let Some(tag) = TableTag::parse(table_record.table_tag()) {
-                        return Some(Table::new(self.buf, tag, table_record.checksum(),
-                                                table_record.offset() as usize, table_record.length() as usize));
-                    }
-                },
-                _ => break
-            }
+        if self.pos >= self.num_tables {
+            return None;
         }
 
-        None
+        match parse_table_record(self.remainder) {
+            Ok((bytes, table_record)) => {
+                self.remainder = bytes;
+                self.pos += 1;
+
+                Some(Table::new(self.buf, TableTag::from(table_record.table_tag()), table_record.checksum(),
+                                table_record.offset() as usize, table_record.length() as usize))
+            },
+            _ => None
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let size = usize::from(self.num_tables);
         (size, Some(size))
     }
-}
\ No newline at end of file
+}
+
+/// Return the searchRange, entrySelector and rangeShift of the Offset Table expressed in number
+/// of records rather than in bytes.
+fn search_parameters(offset_table: &OffsetTable) -> (usize, u16, usize) {
+    let num_tables = usize::from(offset_table.num_tables());
+    let search_range = usize::from(offset_table.search_range());
+    let entry_selector = offset_table.entry_selector();
+    let range_shift = usize::from(offset_table.range_shift());
+
+    if entry_selector < 16 && search_range == TABLE_RECORD_SIZE << entry_selector &&
+        search_range <= num_tables * TABLE_RECORD_SIZE &&
+        range_shift == num_tables * TABLE_RECORD_SIZE - search_range {
+        return (search_range / TABLE_RECORD_SIZE, entry_selector, range_shift / TABLE_RECORD_SIZE);
+    }
+
+    // (Maximum power of 2 <= numTables)
+    let entry_selector = (usize::BITS - 1 - num_tables.leading_zeros()) as u16;
+    let search_range = 1 << entry_selector;
+
+    (search_range, entry_selector, num_tables - search_range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use offset_table::parse_offset_table;
+    use table_record::compute_checksum;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf");
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    fn font(buf: &[u8]) -> Font {
+        let (remainder, offset_table) = parse_offset_table(buf).unwrap();
+        Font::new(buf, remainder, offset_table)
+    }
+
+    /// Copy of a font with additional tables. The new table directory is placed before the data
+    /// of the font, whose table offsets are shifted accordingly.
+    fn font_with_tables(buf: &[u8], tables: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let (remainder, offset_table) = parse_offset_table(buf).unwrap();
+        let num_tables = usize::from(offset_table.num_tables()) + tables.len();
+        let directory_size = 12 + num_tables * TABLE_RECORD_SIZE;
+
+        let mut data = buf.to_vec();
+        let mut table_records: Vec<Vec<u8>> = remainder[..usize::from(offset_table.num_tables()) * TABLE_RECORD_SIZE]
+            .chunks(TABLE_RECORD_SIZE)
+            .map(|table_record| table_record.to_vec())
+            .collect();
+
+        for &(tag, table) in tables {
+            data.resize((data.len() + 3) & !3, 0);
+            let offset = data.len();
+            data.extend_from_slice(table);
+            data.resize((data.len() + 3) & !3, 0);
+
+            let mut table_record = tag.to_vec();
+            table_record.extend(&compute_checksum(&data[offset..]).unwrap().1.to_be_bytes());
+            table_record.extend(&(offset as u32).to_be_bytes());
+            table_record.extend(&(table.len() as u32).to_be_bytes());
+            table_records.push(table_record);
+        }
+
+        table_records.sort();
+
+        // The search parameters are left to zero to be computed again from numTables
+        let mut bytes = buf[..4].to_vec();
+        bytes.extend(&(num_tables as u16).to_be_bytes());
+        bytes.extend(&[0; 6]);
+        for mut table_record in table_records {
+            let offset = u32::from_be_bytes([table_record[8], table_record[9], table_record[10], table_record[11]]);
+            table_record[8..12].copy_from_slice(&(offset + directory_size as u32).to_be_bytes());
+            bytes.extend(table_record);
+        }
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn case_font_table() {
+        let font = font(ROBOTO_REGULAR);
+
+        for table in font.iter() {
+            let found = font.table(table.tag()).unwrap();
+            assert_eq!(found.tag(), table.tag());
+            assert_eq!(found.as_slice().unwrap(), table.as_slice().unwrap());
+        }
+
+        assert!(font.table(TableTag::Cff).is_none());
+        assert!(font.table(TableTag::Vorg).is_none());
+        assert!(font.table_by_tag(Tag::new(b"zzzz")).is_none());
+        assert!(font.table_by_tag(Tag::new(b"AAAA")).is_none());
+    }
+
+    #[test]
+    fn case_font_unknown_tables() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x20, 0x00, 0x01, 0x00, 0x10, 0x46, 0x46,
+            0x54, 0x4D, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x00, 0x00, 0x04,
+            0x53, 0x69, 0x6C, 0x66, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x00,
+            0x00, 0x00, 0x68, 0x65, 0x61, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+        let font = font(bytes);
+        let tags: Vec<TableTag> = font.iter().map(|table| table.tag()).collect();
+
+        assert_eq!(tags, vec![
+            TableTag::Unknown(Tag::new(b"FFTM")),
+            TableTag::Unknown(Tag::new(b"Silf")),
+            TableTag::Head]);
+
+        let table = font.table_by_tag(Tag::new(b"FFTM")).unwrap();
+        assert_eq!(table.tag(), TableTag::Unknown(Tag::new(b"FFTM")));
+        assert_eq!(table.as_slice().unwrap(), &[0x00, 0x00, 0x00, 0x01]);
+        assert_eq!(format!("{}", table.tag()), "FFTM");
+    }
+
+    #[test]
+    fn case_font_table_invalid_search_parameters() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0xFF, 0xFF, 0x63, 0x6D,
+            0x61, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x00, 0x00, 0x00,
+            0x68, 0x65, 0x61, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x00,
+            0x00, 0x00, 0x6E, 0x61, 0x6D, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C,
+            0x00, 0x00, 0x00, 0x00];
+
+        let font = font(bytes);
+
+        assert_eq!(search_parameters(&font), (2, 1, 1));
+        assert_eq!(font.table(TableTag::Cmap).unwrap().tag(), TableTag::Cmap);
+        assert_eq!(font.table(TableTag::Head).unwrap().tag(), TableTag::Head);
+        assert_eq!(font.table(TableTag::Name).unwrap().tag(), TableTag::Name);
+        assert!(font.table(TableTag::Post).is_none());
+    }
+
+    #[test]
+    fn case_font_outline_glyph() {
+        let roboto = font(ROBOTO_REGULAR);
+
+        // Glyph 'Á'
+        assert_eq!(roboto.outline_glyph(640, &mut NullBuilder), Some(Rect::new(28, 0, 1309, 1846)));
+        // Glyph 'space'
+        assert_eq!(roboto.outline_glyph(4, &mut NullBuilder), None);
+        assert_eq!(roboto.outline_glyph(1294, &mut NullBuilder), None);
+
+        let source_serif = font(SOURCE_SERIF_PRO_REGULAR);
+
+        // Glyph 'A'
+        assert_eq!(source_serif.outline_glyph(2, &mut NullBuilder), Some(Rect::new(5, 0, 653, 674)));
+        assert_eq!(source_serif.outline_glyph(1414, &mut NullBuilder), None);
+    }
+
+    #[test]
+    fn case_font_glyph_index() {
+        let roboto = font(ROBOTO_REGULAR);
+
+        assert_eq!(roboto.glyph_index('A'), Some(37));
+        assert_eq!(roboto.glyph_index('\u{20AC}'), Some(413));
+        assert_eq!(roboto.glyph_index('\u{1F16B}'), None);
+
+        let source_serif = font(SOURCE_SERIF_PRO_REGULAR);
+
+        assert_eq!(source_serif.glyph_index('A'), Some(2));
+        // Only mapped by the format 12 subtable
+        assert_eq!(source_serif.glyph_index('\u{1F16B}'), Some(1071));
+    }
+
+    #[test]
+    fn case_font_vertical_origin() {
+        let source_serif = font(SOURCE_SERIF_PRO_REGULAR);
+
+        // Neither 'VORG' nor 'vmtx', the ascender is used
+        assert!(source_serif.table(TableTag::Vorg).is_none());
+        assert_eq!(source_serif.vertical_origin(2), Some(1036));
+
+        // Default vertical origin 880, and 900 for the glyph 'A'
+        let vorg: &[u8] = &[0x00, 0x01, 0x00, 0x00, 0x03, 0x70, 0x00, 0x01, 0x00, 0x02, 0x03, 0x84];
+        let buf = font_with_tables(SOURCE_SERIF_PRO_REGULAR, &[(b"VORG", vorg)]);
+        let source_serif = font(&buf);
+
+        assert_eq!(source_serif.vertical_origin(2), Some(900));
+        assert_eq!(source_serif.vertical_origin(3), Some(880));
+
+        // One longVerMetric record with a top side bearing of 100, then a top side bearing of 50
+        // for every other glyph
+        let vhea: &[u8] = &[
+            0x00, 0x01, 0x10, 0x00, 0x04, 0x00, 0xFC, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        let roboto = font(ROBOTO_REGULAR);
+        let num_glyphs = MaximumProfileTable::parse(roboto.table(TableTag::Maxp).unwrap().as_slice().unwrap())
+            .unwrap().1.num_glyphs();
+        let mut vmtx: Vec<u8> = vec![0x08, 0x00, 0x00, 0x64];
+        for _ in 1..num_glyphs {
+            vmtx.extend(&[0x00, 0x32]);
+        }
+
+        // 'VORG' must be ignored by TrueType fonts
+        let buf = font_with_tables(ROBOTO_REGULAR, &[(b"vhea", vhea), (b"vmtx", &vmtx), (b"VORG", vorg)]);
+        let roboto = font(&buf);
+
+        // Glyph 'Á', whose yMax is 1846
+        assert_eq!(roboto.vertical_origin(640), Some(1896));
+    }
+}
@@ -11,8 +11,10 @@ pub mod parser;
 
 mod error;
 mod font;
+pub mod metrics;
 mod offset_table;
 mod otff;
+pub mod outline;
 mod table;
 mod table_record;
 mod ttc_header;
@@ -0,0 +1,193 @@
+//! Layout metrics of a font, gathered from the 'head', 'hhea', 'hmtx', 'OS/2' and 'post' tables.
+
+use tables::GlyphId;
+use tables::head::FontHeaderTable;
+use tables::hhea::HorizontalHeaderTable;
+use tables::hmtx::HorizontalMetricsTable;
+use tables::os2::{FontSelectionFlags, Os2};
+use tables::post::PostScriptTable;
+use types::Fixed;
+
+/// Font-wide and per-glyph horizontal metrics, in font design units.
+///
+/// The 'OS/2' and 'post' tables are optional, the metrics they provide being None when the table
+/// is missing.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FontMetrics {
+    font_header_table: FontHeaderTable,
+    horizontal_header_table: HorizontalHeaderTable,
+    horizontal_metrics_table: HorizontalMetricsTable,
+    os2: Option<Os2>,
+    post_script_table: Option<PostScriptTable>
+}
+
+impl FontMetrics {
+    pub fn new(font_header_table: FontHeaderTable, horizontal_header_table: HorizontalHeaderTable,
+               horizontal_metrics_table: HorizontalMetricsTable, os2: Option<Os2>,
+               post_script_table: Option<PostScriptTable>) -> FontMetrics {
+        FontMetrics {
+            font_header_table,
+            horizontal_header_table,
+            horizontal_metrics_table,
+            os2,
+            post_script_table
+        }
+    }
+
+    /// Number of font design units per em.
+    pub fn units_per_em(&self) -> u16 {
+        self.font_header_table.units_per_em()
+    }
+
+    /// Distance from the baseline to the top of the line.
+    ///
+    /// The typographic ascender of the 'OS/2' table is used when the USE_TYPO_METRICS flag is
+    /// set, otherwise the ascender of the 'hhea' table.
+    pub fn ascender(&self) -> i16 {
+        match self.typo_metrics() {
+            Some(os2) => os2.s_typo_ascender(),
+            None => self.horizontal_header_table.ascender()
+        }
+    }
+
+    /// Distance from the baseline to the bottom of the line, usually negative.
+    ///
+    /// The typographic descender of the 'OS/2' table is used when the USE_TYPO_METRICS flag is
+    /// set, otherwise the descender of the 'hhea' table.
+    pub fn descender(&self) -> i16 {
+        match self.typo_metrics() {
+            Some(os2) => os2.s_typo_descender(),
+            None => self.horizontal_header_table.descender()
+        }
+    }
+
+    /// Gap between the bottom of a line and the top of the next one.
+    ///
+    /// The typographic line gap of the 'OS/2' table is used when the USE_TYPO_METRICS flag is
+    /// set, otherwise the line gap of the 'hhea' table.
+    pub fn line_gap(&self) -> i16 {
+        match self.typo_metrics() {
+            Some(os2) => os2.s_typo_line_gap(),
+            None => self.horizontal_header_table.line_gap()
+        }
+    }
+
+    /// Height of the capital letters above the baseline, defined from version 2 of the 'OS/2'
+    /// table.
+    pub fn cap_height(&self) -> Option<i16> {
+        self.os2.as_ref().and_then(Os2::s_cap_height)
+    }
+
+    /// Height of the non-ascending lowercase letters above the baseline, defined from version 2
+    /// of the 'OS/2' table.
+    pub fn x_height(&self) -> Option<i16> {
+        self.os2.as_ref().and_then(Os2::sx_height)
+    }
+
+    /// Position of the top of the underline, from the 'post' table.
+    pub fn underline_position(&self) -> Option<i16> {
+        self.post_script_table.as_ref().map(PostScriptTable::underline_position)
+    }
+
+    /// Thickness of the underline, from the 'post' table.
+    pub fn underline_thickness(&self) -> Option<i16> {
+        self.post_script_table.as_ref().map(PostScriptTable::underline_thickness)
+    }
+
+    /// Position of the top of the strikeout stroke relative to the baseline, from the 'OS/2'
+    /// table.
+    pub fn strikeout_position(&self) -> Option<i16> {
+        self.os2.as_ref().map(Os2::y_strikeout_position)
+    }
+
+    /// Thickness of the strikeout stroke, from the 'OS/2' table.
+    pub fn strikeout_size(&self) -> Option<i16> {
+        self.os2.as_ref().map(Os2::y_strikeout_size)
+    }
+
+    /// Italic angle in counter-clockwise degrees from the vertical, from the 'post' table.
+    pub fn italic_angle(&self) -> Option<Fixed> {
+        self.post_script_table.as_ref().map(PostScriptTable::italic_angle)
+    }
+
+    /// Advance width of the given glyph.
+    ///
+    /// Glyphs past the last longHorMetric record use the advance width of the last record.
+    pub fn advance_width(&self, glyph_id: GlyphId) -> Option<u16> {
+        self.horizontal_metrics_table.advance_width(glyph_id)
+    }
+
+    /// Left side bearing of the given glyph.
+    pub fn left_side_bearing(&self, glyph_id: GlyphId) -> Option<i16> {
+        self.horizontal_metrics_table.left_side_bearing(glyph_id)
+    }
+
+    /// The 'OS/2' table if its typographic metrics should be used for the line spacing.
+    fn typo_metrics(&self) -> Option<&Os2> {
+        self.os2.as_ref().filter(|os2| os2.fs_selection().contains(FontSelectionFlags::USE_TYPO_METRICS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otff::OpenTypeFontFile;
+    use parser::Parse;
+    use tables::TableTag;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../fonts/Roboto/Roboto-Regular.ttf");
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    #[test]
+    fn case_font_metrics() {
+        let font = OpenTypeFontFile::parse(SOURCE_SERIF_PRO_REGULAR).unwrap().into_iter().next().unwrap();
+        let font_metrics = font.metrics().unwrap();
+
+        assert_eq!(font_metrics.units_per_em(), 1000);
+        assert_eq!(font_metrics.ascender(), 1036);
+        assert_eq!(font_metrics.descender(), -335);
+        assert_eq!(font_metrics.line_gap(), 0);
+        assert_eq!(font_metrics.cap_height(), Some(670));
+        assert_eq!(font_metrics.x_height(), Some(475));
+        assert_eq!(font_metrics.underline_position(), Some(-50));
+        assert_eq!(font_metrics.underline_thickness(), Some(50));
+        assert_eq!(font_metrics.strikeout_position(), Some(285));
+        assert_eq!(font_metrics.strikeout_size(), Some(50));
+        assert_eq!(font_metrics.italic_angle(), Some(0));
+
+        assert_eq!(font_metrics.advance_width(2), Some(664));
+        assert_eq!(font_metrics.left_side_bearing(2), Some(5));
+        // Past the last longHorMetric record
+        assert_eq!(font_metrics.advance_width(1413), Some(578));
+        assert_eq!(font_metrics.left_side_bearing(1413), Some(20));
+        assert_eq!(font_metrics.advance_width(1414), None);
+        assert_eq!(font_metrics.left_side_bearing(1414), None);
+    }
+
+    #[test]
+    fn case_font_metrics_use_typo_metrics() {
+        let font = OpenTypeFontFile::parse(ROBOTO_REGULAR).unwrap().into_iter().next().unwrap();
+        let font_metrics = font.metrics().unwrap();
+
+        assert_eq!(font_metrics.ascender(), 1900);
+        assert_eq!(font_metrics.descender(), -500);
+        assert_eq!(font_metrics.line_gap(), 0);
+
+        // Set USE_TYPO_METRICS in fsSelection
+        let mut os2_bytes = font.table(TableTag::Os2).unwrap().as_slice().unwrap().to_vec();
+        os2_bytes[63] |= 0x80;
+
+        let font_metrics = FontMetrics::new(
+            FontHeaderTable::parse(font.table(TableTag::Head).unwrap().as_slice().unwrap()).unwrap().1,
+            HorizontalHeaderTable::parse(font.table(TableTag::Hhea).unwrap().as_slice().unwrap()).unwrap().1,
+            HorizontalMetricsTable::parse(font.table(TableTag::Hmtx).unwrap().as_slice().unwrap(), 1294, 1294).unwrap(),
+            Some(Os2::parse(&os2_bytes).unwrap().1),
+            None);
+
+        assert_eq!(font_metrics.ascender(), 1536);
+        assert_eq!(font_metrics.descender(), -512);
+        assert_eq!(font_metrics.line_gap(), 102);
+        assert_eq!(font_metrics.underline_position(), None);
+        assert_eq!(font_metrics.advance_width(37), Some(1336));
+    }
+}
@@ -82,9 +82,21 @@ impl<'otf> Iterator for OpenTypeFontFileIterator<'otf> {
                     Some(Font::new(self.otff.buf, self.otff.remainder, *offset_table))
                 }
             },
-            OpenTypeFontKind::FontCollection(_ttc_header) => {
-                // TODO
-                None
+            OpenTypeFontKind::FontCollection(ttc_header) => {
+                // Each member font has its own Offset Table, located from the beginning of the
+                // file. Table offsets of the member fonts are also measured from the beginning of
+                // the file, thus tables shared between several fonts of the collection are
+                // resolved against the same buffer.
+                let offset = *ttc_header.offset_table().get(self.pos)? as usize;
+                let input = self.otff.buf.get(offset..)?;
+
+                match parse_offset_table(input) {
+                    Ok((remainder, offset_table)) => {
+                        self.pos += 1;
+                        Some(Font::new(self.otff.buf, remainder, offset_table))
+                    },
+                    _ => None
+                }
             }
         }
     }
@@ -92,11 +104,13 @@ impl<'otf> Iterator for OpenTypeFontFileIterator<'otf> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         match &self.otff.kind {
             OpenTypeFontKind::Font(_) => {
-                (1, Some(1))
+                let num_fonts = 1usize.saturating_sub(self.pos);
+                (num_fonts, Some(num_fonts))
             },
             OpenTypeFontKind::FontCollection(ttc_header) => {
-                let num_fonts = ttc_header.offset_table().len();
-                (num_fonts, Some(num_fonts))
+                // The iteration stops at the first member font that fails to parse
+                let num_fonts = ttc_header.offset_table().len().saturating_sub(self.pos);
+                (0, Some(num_fonts))
             }
         }
     }
@@ -114,6 +128,8 @@ pub fn parse_otff(input: &[u8]) -> IResult<&[u8], OpenTypeFontKind>
 mod tests {
     use super::*;
     use offset_table::SfntVersion;
+    use table::Table;
+    use tables::TableTag;
 
     #[test]
     fn case_open_type_font_file() {
@@ -133,4 +149,48 @@ mod tests {
             _ => assert!(false)
         }
     }
+
+    #[test]
+    fn case_open_type_font_file_collection() {
+        let bytes: &[u8]  = &[
+            // TTC Header
+            0x74, 0x74, 0x63, 0x66, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0x00, 0x14, 0x00, 0x00, 0x00, 0x30,
+            // First font
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x63, 0x6D,
+            0x61, 0x70, 0x00, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x5C, 0x00, 0x00, 0x00, 0x04,
+            // Second font
+            0x4F, 0x54, 0x54, 0x4F, 0x00, 0x02, 0x00, 0x20, 0x00, 0x01, 0x00, 0x00, 0x63, 0x6D,
+            0x61, 0x70, 0x00, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x5C, 0x00, 0x00, 0x00, 0x04,
+            0x6E, 0x61, 0x6D, 0x65, 0x04, 0x05, 0x06, 0x07, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00,
+            0x00, 0x04,
+            // Tables
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+        let mut fonts_iter = OpenTypeFontFile::parse(bytes).unwrap().into_iter();
+        assert_eq!(fonts_iter.size_hint(), (0, Some(2)));
+
+        let fonts: Vec<Font> = fonts_iter.by_ref().collect();
+        assert_eq!(fonts_iter.size_hint(), (0, Some(0)));
+
+        assert_eq!(fonts.len(), 2);
+        assert_eq!(fonts[0].sfnt_version(), SfntVersion::TrueType);
+        assert_eq!(fonts[0].num_tables(), 1);
+        assert_eq!(fonts[1].sfnt_version(), SfntVersion::CFF);
+        assert_eq!(fonts[1].num_tables(), 2);
+
+        let first_font_tables: Vec<Table> = fonts[0].iter().collect();
+        let second_font_tables: Vec<Table> = fonts[1].iter().collect();
+
+        assert_eq!(first_font_tables.len(), 1);
+        assert_eq!(second_font_tables.len(), 2);
+        assert_eq!(first_font_tables[0].tag(), TableTag::Cmap);
+        assert_eq!(second_font_tables[0].tag(), TableTag::Cmap);
+        assert_eq!(second_font_tables[1].tag(), TableTag::Name);
+
+        // The 'cmap' table is shared between the two fonts
+        assert_eq!(first_font_tables[0].as_slice().unwrap(), &[0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(second_font_tables[0].as_slice().unwrap(), &[0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(second_font_tables[1].as_slice().unwrap(), &[0x04, 0x05, 0x06, 0x07]);
+    }
 }
@@ -0,0 +1,219 @@
+//! Glyph outlines are described by a sequence of path commands sent to an
+//! [OutlineBuilder](trait.OutlineBuilder.html), in font design units with the y axis going up.
+
+use types::Rect;
+
+/// A sink receiving the path commands of a glyph outline.
+///
+/// Every contour starts with a `move_to` and ends with a `close`.
+pub trait OutlineBuilder {
+    /// Start a new contour at the given point.
+    fn move_to(&mut self, x: f32, y: f32);
+
+    /// Straight line from the current point.
+    fn line_to(&mut self, x: f32, y: f32);
+
+    /// Quadratic Bézier curve from the current point, with (x1, y1) as control point.
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32);
+
+    /// Cubic Bézier curve from the current point, with (x1, y1) and (x2, y2) as control points.
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32);
+
+    /// Close the current contour.
+    fn close(&mut self);
+}
+
+/// Builder discarding the path commands, used when only the bounding box of an outline is needed.
+pub(crate) struct NullBuilder;
+
+impl OutlineBuilder for NullBuilder {
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+}
+
+/// Forward the path commands to another builder while computing the exact bounding box of the
+/// outline, curve extrema included.
+pub(crate) struct BoundingBoxBuilder<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    x: f32,
+    y: f32,
+    bbox: Option<(f32, f32, f32, f32)>
+}
+
+impl<'a> BoundingBoxBuilder<'a> {
+    pub(crate) fn new(builder: &'a mut dyn OutlineBuilder) -> BoundingBoxBuilder<'a> {
+        BoundingBoxBuilder {
+            builder,
+            x: 0.0,
+            y: 0.0,
+            bbox: None
+        }
+    }
+
+    /// Bounding box of the outline, None if no path command was emitted.
+    pub(crate) fn bounding_box(&self) -> Option<Rect<i16>> {
+        self.bbox.map(|(x_min, y_min, x_max, y_max)| Rect::new(
+            x_min.floor() as i16, y_min.floor() as i16, x_max.ceil() as i16, y_max.ceil() as i16))
+    }
+
+    fn extend(&mut self, x: f32, y: f32) {
+        self.bbox = Some(match self.bbox {
+            Some((x_min, y_min, x_max, y_max)) => (x_min.min(x), y_min.min(y), x_max.max(x), y_max.max(y)),
+            None => (x, y, x, y)
+        });
+    }
+}
+
+impl<'a> OutlineBuilder for BoundingBoxBuilder<'a> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.extend(x, y);
+        self.x = x;
+        self.y = y;
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.extend(x, y);
+        self.x = x;
+        self.y = y;
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = (self.x, self.y);
+
+        for t in quad_extrema(x0, x1, x).iter().chain(quad_extrema(y0, y1, y).iter()).filter_map(|t| *t) {
+            let mt = 1.0 - t;
+            let px = mt * mt * x0 + 2.0 * mt * t * x1 + t * t * x;
+            let py = mt * mt * y0 + 2.0 * mt * t * y1 + t * t * y;
+            self.extend(px, py);
+        }
+
+        self.extend(x, y);
+        self.x = x;
+        self.y = y;
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = (self.x, self.y);
+
+        for t in cubic_extrema(x0, x1, x2, x).iter().chain(cubic_extrema(y0, y1, y2, y).iter()).filter_map(|t| *t) {
+            let mt = 1.0 - t;
+            let px = mt * mt * mt * x0 + 3.0 * mt * mt * t * x1 + 3.0 * mt * t * t * x2 + t * t * t * x;
+            let py = mt * mt * mt * y0 + 3.0 * mt * mt * t * y1 + 3.0 * mt * t * t * y2 + t * t * t * y;
+            self.extend(px, py);
+        }
+
+        self.extend(x, y);
+        self.x = x;
+        self.y = y;
+        self.builder.curve_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+fn in_curve(t: f32) -> Option<f32> {
+    if t > 0.0 && t < 1.0 { Some(t) } else { None }
+}
+
+/// Parameter of the extremum of a quadratic Bézier curve along one axis, if any.
+fn quad_extrema(p0: f32, p1: f32, p2: f32) -> [Option<f32>; 1] {
+    let denominator = p0 - 2.0 * p1 + p2;
+
+    if denominator == 0.0 {
+        return [None];
+    }
+
+    [in_curve((p0 - p1) / denominator)]
+}
+
+/// Parameters of the extrema of a cubic Bézier curve along one axis, if any.
+fn cubic_extrema(p0: f32, p1: f32, p2: f32, p3: f32) -> [Option<f32>; 2] {
+    // Roots of the derivative a.t² + b.t + c (divided by 3)
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+
+    if a.abs() < f32::EPSILON {
+        if b == 0.0 {
+            return [None, None];
+        }
+
+        return [in_curve(-c / b), None];
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+
+    let root = discriminant.sqrt();
+
+    [in_curve((-b + root) / (2.0 * a)), in_curve((-b - root) / (2.0 * a))]
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Builder recording the path commands in the SVG path notation, shared by the tests of the
+    /// outline sources.
+    #[derive(Default)]
+    pub(crate) struct PathRecorder {
+        pub(crate) commands: Vec<String>
+    }
+
+    impl OutlineBuilder for PathRecorder {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.commands.push(format!("M {} {}", x, y));
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            self.commands.push(format!("L {} {}", x, y));
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            self.commands.push(format!("Q {} {} {} {}", x1, y1, x, y));
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            self.commands.push(format!("C {} {} {} {} {} {}", x1, y1, x2, y2, x, y));
+        }
+
+        fn close(&mut self) {
+            self.commands.push("Z".to_string());
+        }
+    }
+
+    #[test]
+    fn case_bounding_box_builder_empty() {
+        let mut null_builder = NullBuilder;
+        let builder = BoundingBoxBuilder::new(&mut null_builder);
+
+        assert_eq!(builder.bounding_box(), None);
+    }
+
+    #[test]
+    fn case_bounding_box_builder_curves() {
+        let mut null_builder = NullBuilder;
+        let mut builder = BoundingBoxBuilder::new(&mut null_builder);
+
+        builder.move_to(0.0, 0.0);
+        builder.line_to(100.0, 0.0);
+        // Extremum at (150, 50)
+        builder.quad_to(200.0, 50.0, 100.0, 100.0);
+        // Extremum at (-37.5, 175)
+        builder.curve_to(0.0, 200.0, -100.0, 200.0, -100.0, 100.0);
+        builder.close();
+
+        assert_eq!(builder.bounding_box(), Some(Rect::new(-100, 0, 150, 175)));
+    }
+}
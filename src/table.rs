@@ -29,7 +29,7 @@ impl<'otf> Table<'otf> {
         // All tables must begin on four-byte boundaries, and any remaining space between tables
         // is padded with zeros. The length of all tables should be recorded in the table record
         // with their actual length (not their padded length).
-        let offset_limit = self.offset + self.length + self.length % 4;
+        let offset_limit = self.offset + ((self.length + 3) & !3);
 
         let table_padded_buf = self.buf.get(self.offset..offset_limit).ok_or(Error::new("Table slice out of bounds"))?;
 
@@ -0,0 +1,251 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16};
+use tables::GlyphId;
+
+/// Charsets
+///
+/// Charset data is located via the offset operand to the charset operator in the Top DICT. Each
+/// charset is described by a format-type identifier byte followed by format-specific data. Only
+/// glyphs 1 and above are described, the .notdef glyph being always the first glyph.
+///
+/// For name-keyed fonts the charset maps glyph IDs to the String IDs of the glyph names, for
+/// CID-keyed fonts it maps glyph IDs to CIDs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Charset {
+    /// Predefined ISOAdobe charset, SIDs 0 to 228 in order.
+    IsoAdobe,
+    /// Predefined Expert charset.
+    Expert,
+    /// Predefined Expert Subset charset.
+    ExpertSubset,
+    /// Array of SIDs (or CIDs) for glyphs 1 and above.
+    Format0(Vec<u16>),
+    /// Ranges of consecutive SIDs (or CIDs) with a count of glyphs stored on 8 bits.
+    Format1(Vec<CharsetRange>),
+    /// Ranges of consecutive SIDs (or CIDs) with a count of glyphs stored on 16 bits.
+    Format2(Vec<CharsetRange>)
+}
+
+impl Charset {
+    /// String ID (or CID) of the given glyph.
+    pub fn sid(&self, glyph_id: GlyphId) -> Option<u16> {
+        if glyph_id == 0 {
+            return Some(0);
+        }
+
+        match self {
+            Charset::IsoAdobe => if glyph_id <= 228 { Some(glyph_id) } else { None },
+            Charset::Expert => EXPERT_CHARSET.get(usize::from(glyph_id)).cloned(),
+            Charset::ExpertSubset => EXPERT_SUBSET_CHARSET.get(usize::from(glyph_id)).cloned(),
+            Charset::Format0(sids) => sids.get(usize::from(glyph_id) - 1).cloned(),
+            Charset::Format1(ranges) | Charset::Format2(ranges) => {
+                let mut first_glyph_id = 1u32;
+
+                for range in ranges {
+                    let last_glyph_id = first_glyph_id + u32::from(range.n_left);
+
+                    if u32::from(glyph_id) <= last_glyph_id {
+                        let sid = u32::from(range.first) + u32::from(glyph_id) - first_glyph_id;
+                        return if sid <= u32::from(u16::MAX) { Some(sid as u16) } else { None };
+                    }
+
+                    first_glyph_id = last_glyph_id + 1;
+                }
+
+                None
+            }
+        }
+    }
+
+    /// Glyph with the given String ID (or CID).
+    pub fn glyph_id(&self, sid: u16) -> Option<GlyphId> {
+        if sid == 0 {
+            return Some(0);
+        }
+
+        match self {
+            Charset::IsoAdobe => if sid <= 228 { Some(sid) } else { None },
+            Charset::Expert => position(&EXPERT_CHARSET, sid),
+            Charset::ExpertSubset => position(&EXPERT_SUBSET_CHARSET, sid),
+            Charset::Format0(sids) => position(sids, sid).map(|glyph_id| glyph_id + 1),
+            Charset::Format1(ranges) | Charset::Format2(ranges) => {
+                let mut first_glyph_id = 1u32;
+
+                for range in ranges {
+                    if sid >= range.first && u32::from(sid) <= u32::from(range.first) + u32::from(range.n_left) {
+                        let glyph_id = first_glyph_id + u32::from(sid - range.first);
+                        return if glyph_id <= u32::from(GlyphId::MAX) { Some(glyph_id as GlyphId) } else { None };
+                    }
+
+                    first_glyph_id += u32::from(range.n_left) + 1;
+                }
+
+                None
+            }
+        }
+    }
+}
+
+fn position(sids: &[u16], sid: u16) -> Option<GlyphId> {
+    sids.iter().position(|&s| s == sid).map(|glyph_id| glyph_id as GlyphId)
+}
+
+/// A range of consecutive SIDs (or CIDs) assigned to consecutive glyphs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CharsetRange {
+    first: u16,
+    n_left: u16
+}
+
+impl CharsetRange {
+    /// First SID (or CID) in range.
+    pub fn first(&self) -> u16 {
+        self.first
+    }
+
+    /// Glyphs left in range (excluding first).
+    pub fn n_left(&self) -> u16 {
+        self.n_left
+    }
+}
+
+/// Parse a charset stored in the font.
+///
+/// * `num_glyphs` - The number of glyphs is determined by the number of objects of the
+///   CharStrings INDEX.
+pub fn parse_charset(input: &[u8], num_glyphs: u16) -> IResult<&[u8], Charset>
+{
+    let (input, format) = be_u8(input)?;
+
+    // The .notdef glyph is omitted
+    let num_glyphs = num_glyphs.saturating_sub(1);
+
+    match format {
+        0 => {
+            let (input, sids) = count(be_u16, usize::from(num_glyphs))(input)?;
+            Ok((input, Charset::Format0(sids)))
+        },
+        1 => {
+            let (input, ranges) = parse_charset_ranges(input, num_glyphs, |input| {
+                let (input, n_left) = be_u8(input)?;
+                Ok((input, u16::from(n_left)))
+            })?;
+            Ok((input, Charset::Format1(ranges)))
+        },
+        2 => {
+            let (input, ranges) = parse_charset_ranges(input, num_glyphs, be_u16)?;
+            Ok((input, Charset::Format2(ranges)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_charset_ranges<'a, F>(input: &'a[u8], num_glyphs: u16, n_left_parser: F) -> IResult<&'a[u8], Vec<CharsetRange>>
+    where F: Fn(&'a[u8]) -> IResult<&'a[u8], u16>
+{
+    let mut input = input;
+    let mut ranges = Vec::new();
+    let mut covered_glyphs = 0u32;
+
+    // The number of ranges is not explicitly specified in the font, the ranges are read until
+    // all the glyphs are covered
+    while covered_glyphs < u32::from(num_glyphs) {
+        let (remainder, first) = be_u16(input)?;
+        let (remainder, n_left) = n_left_parser(remainder)?;
+        input = remainder;
+
+        covered_glyphs += u32::from(n_left) + 1;
+        ranges.push(CharsetRange {
+            first,
+            n_left
+        });
+    }
+
+    Ok((input, ranges))
+}
+
+static EXPERT_CHARSET: [u16; 166] = [
+    0, 1, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+    261, 262, 263, 264, 265, 266, 109, 110, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277,
+    278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296,
+    297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315,
+    316, 317, 318, 158, 155, 163, 319, 320, 321, 322, 323, 324, 325, 326, 150, 164, 169, 327, 328,
+    329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347,
+    348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366,
+    367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378
+];
+
+static EXPERT_SUBSET_CHARSET: [u16; 87] = [
+    0, 1, 231, 232, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243, 244, 245, 246, 247,
+    248, 27, 28, 249, 250, 251, 253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265,
+    266, 109, 110, 267, 268, 269, 270, 272, 300, 301, 302, 305, 314, 315, 158, 155, 163, 320, 321,
+    322, 323, 324, 325, 326, 150, 164, 169, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337,
+    338, 339, 340, 341, 342, 343, 344, 345, 346
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_charset_format0() {
+        let bytes: &[u8]  = &[0x00, 0x00, 0x22, 0x01, 0x88, 0x00, 0x01];
+
+        let charset = parse_charset(bytes, 4).unwrap().1;
+
+        assert_eq!(charset.sid(0), Some(0));
+        assert_eq!(charset.sid(1), Some(34));
+        assert_eq!(charset.sid(2), Some(392));
+        assert_eq!(charset.sid(3), Some(1));
+        assert_eq!(charset.sid(4), None);
+        assert_eq!(charset.glyph_id(392), Some(2));
+        assert_eq!(charset.glyph_id(35), None);
+    }
+
+    #[test]
+    fn case_charset_format1() {
+        let bytes: &[u8]  = &[0x01, 0x00, 0x01, 0x21, 0x00, 0xAE, 0x00, 0xFF];
+
+        let (remainder, charset) = parse_charset(bytes, 36).unwrap();
+
+        assert_eq!(remainder, &[0xFF]);
+        assert_eq!(charset.sid(1), Some(1));
+        assert_eq!(charset.sid(34), Some(34));
+        assert_eq!(charset.sid(35), Some(174));
+        assert_eq!(charset.sid(36), None);
+        assert_eq!(charset.glyph_id(174), Some(35));
+        assert_eq!(charset.glyph_id(35), None);
+    }
+
+    #[test]
+    fn case_charset_format2() {
+        let bytes: &[u8]  = &[0x02, 0x00, 0x01, 0x01, 0x2B];
+
+        let charset = parse_charset(bytes, 301).unwrap().1;
+
+        assert_eq!(charset.sid(300), Some(300));
+        assert_eq!(charset.glyph_id(300), Some(300));
+        assert_eq!(charset.sid(301), None);
+    }
+
+    #[test]
+    fn case_charset_predefined() {
+        assert_eq!(Charset::IsoAdobe.sid(228), Some(228));
+        assert_eq!(Charset::IsoAdobe.sid(229), None);
+        assert_eq!(Charset::Expert.sid(2), Some(229));
+        assert_eq!(Charset::Expert.glyph_id(378), Some(165));
+        assert_eq!(Charset::ExpertSubset.sid(86), Some(346));
+        assert_eq!(Charset::ExpertSubset.glyph_id(229), None);
+    }
+
+    #[test]
+    fn case_charset_invalid_format() {
+        let bytes: &[u8]  = &[0x03, 0x00, 0x01];
+
+        assert!(parse_charset(bytes, 2).is_err());
+    }
+}
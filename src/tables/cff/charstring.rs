@@ -0,0 +1,760 @@
+//! Type 2 charstring interpreter.
+//!
+//! A Type 2 charstring program is a sequence of unsigned 8-bit bytes that encode numbers and
+//! operators. The numbers are pushed on the argument stack, the operators consume the arguments
+//! and build the glyph outline with relative coordinates.
+//!
+//! CFF2 charstrings drop the advance width, the endchar and return operators, and add the
+//! blend and vsindex operators to interpolate the arguments at the variation coordinates.
+//!
+//! https://docs.microsoft.com/en-gb/typography/opentype/spec/cff2charstr
+
+use error::Error;
+use outline::OutlineBuilder;
+use tables::cff::index::Index;
+use tables::variation::ItemVariationStore;
+use types::F2Dot14;
+
+// One-byte operators
+const HSTEM: u8 = 1;
+const VSTEM: u8 = 3;
+const VMOVETO: u8 = 4;
+const RLINETO: u8 = 5;
+const HLINETO: u8 = 6;
+const VLINETO: u8 = 7;
+const RRCURVETO: u8 = 8;
+const CALLSUBR: u8 = 10;
+const RETURN: u8 = 11;
+const ESCAPE: u8 = 12;
+const ENDCHAR: u8 = 14;
+const VSINDEX: u8 = 15;
+const BLEND: u8 = 16;
+const HSTEMHM: u8 = 18;
+const HINTMASK: u8 = 19;
+const CNTRMASK: u8 = 20;
+const RMOVETO: u8 = 21;
+const HMOVETO: u8 = 22;
+const VSTEMHM: u8 = 23;
+const RCURVELINE: u8 = 24;
+const RLINECURVE: u8 = 25;
+const VVCURVETO: u8 = 26;
+const HHCURVETO: u8 = 27;
+const SHORTINT: u8 = 28;
+const CALLGSUBR: u8 = 29;
+const VHCURVETO: u8 = 30;
+const HVCURVETO: u8 = 31;
+
+// Two-byte operators, the first byte being the escape byte 12
+const DOTSECTION: u8 = 0;
+const HFLEX: u8 = 34;
+const FLEX: u8 = 35;
+const HFLEX1: u8 = 36;
+const FLEX1: u8 = 37;
+
+/// Maximum number of arguments on the argument stack.
+const MAX_ARGUMENTS_STACK_LEN: usize = 48;
+
+/// Maximum number of arguments on the argument stack of a CFF2 charstring.
+const MAX_CFF2_ARGUMENTS_STACK_LEN: usize = 513;
+
+/// Maximum nesting of subroutine calls.
+const MAX_CALL_DEPTH: u8 = 10;
+
+/// Accented character described by the endchar operator with four arguments, a deprecated
+/// feature inherited from the Type 1 seac operator. The base and the accent characters are
+/// identified by their code in the Standard encoding.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Seac {
+    pub adx: f32,
+    pub ady: f32,
+    pub base_code: u8,
+    pub accent_code: u8
+}
+
+pub(crate) struct CharStringInterpreter<'a, 'otf: 'a> {
+    global_subrs: &'a Index<'otf>,
+    local_subrs: Option<&'a Index<'otf>>,
+    path: Path<'a>,
+    stack: Vec<f32>,
+    num_stems: usize,
+    has_width: bool,
+    is_ended: bool,
+    seac: Option<Seac>,
+    variation: Option<Variation<'a>>
+}
+
+/// Variation state of a CFF2 charstring.
+struct Variation<'a> {
+    variation_store: Option<&'a ItemVariationStore>,
+    coordinates: &'a [F2Dot14],
+    /// Scalars of the regions of the current ItemVariationData subtable.
+    scalars: Option<Vec<f32>>
+}
+
+impl<'a> Variation<'a> {
+    fn set_vsindex(&mut self, vsindex: u16) {
+        let coordinates = self.coordinates;
+        self.scalars = self.variation_store
+            .and_then(|variation_store| variation_store.region_scalars(vsindex, coordinates));
+    }
+}
+
+impl<'a, 'otf: 'a> CharStringInterpreter<'a, 'otf> {
+    /// * `x`, `y` - Origin of the glyph, the charstring coordinates being relative.
+    pub(crate) fn new(global_subrs: &'a Index<'otf>, local_subrs: Option<&'a Index<'otf>>,
+                      builder: &'a mut dyn OutlineBuilder, x: f32, y: f32) -> CharStringInterpreter<'a, 'otf> {
+        CharStringInterpreter {
+            global_subrs,
+            local_subrs,
+            path: Path {
+                builder,
+                x,
+                y,
+                is_open: false
+            },
+            stack: Vec::with_capacity(MAX_ARGUMENTS_STACK_LEN),
+            num_stems: 0,
+            has_width: false,
+            is_ended: false,
+            seac: None,
+            variation: None
+        }
+    }
+
+    /// Interpreter of CFF2 charstrings.
+    ///
+    /// * `x`, `y` - Origin of the glyph, the charstring coordinates being relative.
+    /// * `variation_store` - Variation data referenced by the blend operator.
+    /// * `coordinates` - Normalized variation coordinates, in the order of the 'fvar' axes.
+    /// * `vsindex` - Default ItemVariationData subtable, as specified by the Private DICT.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new_cff2(global_subrs: &'a Index<'otf>, local_subrs: Option<&'a Index<'otf>>,
+                           builder: &'a mut dyn OutlineBuilder, x: f32, y: f32,
+                           variation_store: Option<&'a ItemVariationStore>, coordinates: &'a [F2Dot14],
+                           vsindex: u16) -> CharStringInterpreter<'a, 'otf> {
+        let mut variation = Variation {
+            variation_store,
+            coordinates,
+            scalars: None
+        };
+
+        variation.set_vsindex(vsindex);
+
+        CharStringInterpreter {
+            stack: Vec::with_capacity(MAX_CFF2_ARGUMENTS_STACK_LEN),
+            // CFF2 charstrings do not hold the advance width
+            has_width: true,
+            variation: Some(variation),
+            ..CharStringInterpreter::new(global_subrs, local_subrs, builder, x, y)
+        }
+    }
+
+    /// Interpret the charstring of a glyph, return the accented character description if the
+    /// glyph is made of a base and an accent glyph.
+    pub(crate) fn run(mut self, char_string: &[u8]) -> Result<Option<Seac>, Error> {
+        self.parse(char_string, 0)?;
+
+        if self.variation.is_some() {
+            // CFF2 charstrings end with the data
+            self.path.close();
+        }
+        else if !self.is_ended {
+            return Err(Error::new("Charstring does not end with endchar"));
+        }
+
+        Ok(self.seac)
+    }
+
+    fn parse(&mut self, char_string: &[u8], depth: u8) -> Result<(), Error> {
+        if depth > MAX_CALL_DEPTH {
+            return Err(Error::new("Charstring subroutines nested too deeply"));
+        }
+
+        let mut pos = 0;
+
+        while pos < char_string.len() {
+            let b0 = char_string[pos];
+            pos += 1;
+
+            match b0 {
+                HSTEM | VSTEM | HSTEMHM | VSTEMHM => {
+                    self.parse_width(0);
+                    self.num_stems += self.stack.len() / 2;
+                    self.stack.clear();
+                },
+                HINTMASK | CNTRMASK => {
+                    // The arguments are the values of an implicit vstemhm
+                    self.parse_width(0);
+                    self.num_stems += self.stack.len() / 2;
+                    self.stack.clear();
+
+                    // The mask holds one bit per stem hint
+                    pos += (self.num_stems + 7) / 8;
+
+                    if pos > char_string.len() {
+                        return Err(Error::new("Charstring hintmask out of bounds"));
+                    }
+                },
+                RMOVETO => {
+                    self.parse_width(2);
+                    check_arguments(&self.stack, self.stack.len() == 2)?;
+                    self.path.move_to(self.stack[0], self.stack[1]);
+                    self.stack.clear();
+                },
+                HMOVETO => {
+                    self.parse_width(1);
+                    check_arguments(&self.stack, self.stack.len() == 1)?;
+                    self.path.move_to(self.stack[0], 0.0);
+                    self.stack.clear();
+                },
+                VMOVETO => {
+                    self.parse_width(1);
+                    check_arguments(&self.stack, self.stack.len() == 1)?;
+                    self.path.move_to(0.0, self.stack[0]);
+                    self.stack.clear();
+                },
+                RLINETO => {
+                    rlineto(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                HLINETO => {
+                    alternating_lines(&self.stack, true, &mut self.path)?;
+                    self.stack.clear();
+                },
+                VLINETO => {
+                    alternating_lines(&self.stack, false, &mut self.path)?;
+                    self.stack.clear();
+                },
+                RRCURVETO => {
+                    rrcurveto(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                RCURVELINE => {
+                    rcurveline(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                RLINECURVE => {
+                    rlinecurve(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                VVCURVETO => {
+                    vvcurveto(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                HHCURVETO => {
+                    hhcurveto(&self.stack, &mut self.path)?;
+                    self.stack.clear();
+                },
+                HVCURVETO => {
+                    alternating_curves(&self.stack, true, &mut self.path)?;
+                    self.stack.clear();
+                },
+                VHCURVETO => {
+                    alternating_curves(&self.stack, false, &mut self.path)?;
+                    self.stack.clear();
+                },
+                CALLSUBR | CALLGSUBR => {
+                    let subrs = if b0 == CALLSUBR {
+                        self.local_subrs.ok_or_else(|| Error::new("Charstring calls missing local subroutines"))?
+                    }
+                    else {
+                        self.global_subrs
+                    };
+
+                    let subr_index = self.stack.pop()
+                        .ok_or_else(|| Error::new("Charstring argument stack underflow"))? as i32;
+                    let subr = get_subr(subrs, subr_index)?;
+
+                    self.parse(subr, depth + 1)?;
+
+                    if self.is_ended {
+                        return Ok(());
+                    }
+                },
+                RETURN if self.variation.is_none() => {
+                    return Ok(());
+                },
+                ENDCHAR if self.variation.is_none() => {
+                    self.parse_width(0);
+
+                    if self.stack.len() == 4 {
+                        self.seac = Some(Seac {
+                            adx: self.stack[0],
+                            ady: self.stack[1],
+                            base_code: self.stack[2] as u8,
+                            accent_code: self.stack[3] as u8
+                        });
+                    }
+
+                    self.stack.clear();
+                    self.path.close();
+                    self.is_ended = true;
+
+                    return Ok(());
+                },
+                VSINDEX if self.variation.is_some() => {
+                    let vsindex = self.stack.pop()
+                        .ok_or_else(|| Error::new("Charstring argument stack underflow"))?;
+                    check_arguments(&self.stack, self.stack.is_empty() && vsindex >= 0.0)?;
+
+                    if let Some(ref mut variation) = self.variation {
+                        variation.set_vsindex(vsindex as u16);
+                    }
+                },
+                BLEND if self.variation.is_some() => {
+                    self.blend()?;
+                },
+                ESCAPE => {
+                    let b1 = *char_string.get(pos).ok_or_else(|| Error::new("Charstring operator out of bounds"))?;
+                    pos += 1;
+
+                    match b1 {
+                        DOTSECTION => {},
+                        HFLEX => hflex(&self.stack, &mut self.path)?,
+                        FLEX => flex(&self.stack, &mut self.path)?,
+                        HFLEX1 => hflex1(&self.stack, &mut self.path)?,
+                        FLEX1 => flex1(&self.stack, &mut self.path)?,
+                        _ => return Err(Error::new(format!("Unsupported charstring operator 12 {}", b1)))
+                    }
+
+                    self.stack.clear();
+                },
+                SHORTINT => {
+                    let bytes = char_string.get(pos..pos + 2)
+                        .ok_or_else(|| Error::new("Charstring operand out of bounds"))?;
+                    pos += 2;
+
+                    self.push(f32::from(i16::from(bytes[0]) << 8 | i16::from(bytes[1])))?;
+                },
+                32..=246 => {
+                    self.push(f32::from(i16::from(b0) - 139))?;
+                },
+                247..=254 => {
+                    let b1 = *char_string.get(pos).ok_or_else(|| Error::new("Charstring operand out of bounds"))?;
+                    pos += 1;
+
+                    let value = if b0 < 251 {
+                        (i16::from(b0) - 247) * 256 + i16::from(b1) + 108
+                    }
+                    else {
+                        -(i16::from(b0) - 251) * 256 - i16::from(b1) - 108
+                    };
+
+                    self.push(f32::from(value))?;
+                },
+                255 => {
+                    // 16.16 fixed point number
+                    let bytes = char_string.get(pos..pos + 4)
+                        .ok_or_else(|| Error::new("Charstring operand out of bounds"))?;
+                    pos += 4;
+
+                    let value = bytes.iter().fold(0u32, |value, &byte| (value << 8) | u32::from(byte)) as i32;
+                    self.push(value as f32 / 65536.0)?;
+                },
+                _ => return Err(Error::new(format!("Unsupported charstring operator {}", b0)))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The first stack-clearing operator may be preceded by the advance width of the glyph,
+    /// which is detected by an odd number of extra arguments. Missing arguments are left to the
+    /// argument checks of the operator.
+    fn parse_width(&mut self, num_arguments: usize) {
+        if !self.has_width {
+            if self.stack.len() > num_arguments && self.stack.len() % 2 != num_arguments % 2 {
+                self.stack.remove(0);
+            }
+
+            self.has_width = true;
+        }
+    }
+
+    /// {v1 .. vn} {d11 .. d1k} .. {dn1 .. dnk} n blend
+    ///
+    /// Replace the n default values by their interpolation with the k deltas of each value,
+    /// k being the number of regions of the current ItemVariationData subtable.
+    fn blend(&mut self) -> Result<(), Error> {
+        let scalars = self.variation.as_ref()
+            .and_then(|variation| variation.scalars.as_ref())
+            .ok_or_else(|| Error::new("Charstring blend without variation data"))?;
+
+        let num_values = self.stack.pop()
+            .ok_or_else(|| Error::new("Charstring argument stack underflow"))?;
+        check_arguments(&self.stack, num_values >= 0.0)?;
+
+        let num_values = num_values as usize;
+        let num_operands = num_values * (scalars.len() + 1);
+        check_arguments(&self.stack, num_operands <= self.stack.len())?;
+
+        let base = self.stack.len() - num_operands;
+        let (values, deltas) = self.stack[base..].split_at_mut(num_values);
+
+        for (value, deltas) in values.iter_mut().zip(deltas.chunks(scalars.len().max(1))) {
+            *value += deltas.iter().zip(scalars.iter()).map(|(delta, scalar)| delta * scalar).sum::<f32>();
+        }
+
+        self.stack.truncate(base + num_values);
+
+        Ok(())
+    }
+
+    fn push(&mut self, value: f32) -> Result<(), Error> {
+        let max_stack_len = if self.variation.is_some() { MAX_CFF2_ARGUMENTS_STACK_LEN } else { MAX_ARGUMENTS_STACK_LEN };
+
+        if self.stack.len() >= max_stack_len {
+            return Err(Error::new("Charstring argument stack overflow"));
+        }
+
+        self.stack.push(value);
+        Ok(())
+    }
+}
+
+/// The subroutine numbers are biased according to the number of subroutines, so that more
+/// subroutines can be referenced by small (one byte) numbers.
+pub(crate) fn subrs_bias(subrs: &Index) -> i32 {
+    match subrs.len() {
+        0..=1239 => 107,
+        1240..=33899 => 1131,
+        _ => 32768
+    }
+}
+
+fn get_subr<'otf>(subrs: &Index<'otf>, subr_index: i32) -> Result<&'otf[u8], Error> {
+    let subr_index = subr_index + subrs_bias(subrs);
+
+    if subr_index < 0 {
+        return Err(Error::new(format!("Invalid subroutine index {}", subr_index)));
+    }
+
+    subrs.get(subr_index as u32).ok_or_else(|| Error::new(format!("Invalid subroutine index {}", subr_index)))
+}
+
+fn check_arguments(stack: &[f32], is_valid: bool) -> Result<(), Error> {
+    if !is_valid {
+        return Err(Error::new(format!("Invalid number of charstring arguments {}", stack.len())));
+    }
+
+    Ok(())
+}
+
+/// Current point and contour state of the outline.
+struct Path<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    x: f32,
+    y: f32,
+    is_open: bool
+}
+
+impl<'a> Path<'a> {
+    fn move_to(&mut self, dx: f32, dy: f32) {
+        // A moveto operator closes the previous contour
+        self.close();
+
+        self.x += dx;
+        self.y += dy;
+        self.builder.move_to(self.x, self.y);
+        self.is_open = true;
+    }
+
+    fn line_to(&mut self, dx: f32, dy: f32) -> Result<(), Error> {
+        if !self.is_open {
+            return Err(Error::new("Charstring path does not start with moveto"));
+        }
+
+        self.x += dx;
+        self.y += dy;
+        self.builder.line_to(self.x, self.y);
+
+        Ok(())
+    }
+
+    fn curve_to(&mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx3: f32, dy3: f32) -> Result<(), Error> {
+        if !self.is_open {
+            return Err(Error::new("Charstring path does not start with moveto"));
+        }
+
+        let x1 = self.x + dx1;
+        let y1 = self.y + dy1;
+        let x2 = x1 + dx2;
+        let y2 = y1 + dy2;
+        self.x = x2 + dx3;
+        self.y = y2 + dy3;
+        self.builder.curve_to(x1, y1, x2, y2, self.x, self.y);
+
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        if self.is_open {
+            self.builder.close();
+            self.is_open = false;
+        }
+    }
+}
+
+/// {dxa dya}+ rlineto
+fn rlineto(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, !args.is_empty() && args.len() % 2 == 0)?;
+
+    for line in args.chunks(2) {
+        path.line_to(line[0], line[1])?;
+    }
+
+    Ok(())
+}
+
+/// hlineto and vlineto: lines alternating between horizontal and vertical.
+fn alternating_lines(args: &[f32], horizontal: bool, path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, !args.is_empty())?;
+
+    let mut horizontal = horizontal;
+
+    for &d in args {
+        if horizontal {
+            path.line_to(d, 0.0)?;
+        }
+        else {
+            path.line_to(0.0, d)?;
+        }
+
+        horizontal = !horizontal;
+    }
+
+    Ok(())
+}
+
+/// {dxa dya dxb dyb dxc dyc}+ rrcurveto
+fn rrcurveto(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, !args.is_empty() && args.len() % 6 == 0)?;
+
+    for curve in args.chunks(6) {
+        path.curve_to(curve[0], curve[1], curve[2], curve[3], curve[4], curve[5])?;
+    }
+
+    Ok(())
+}
+
+/// {dxa dya dxb dyb dxc dyc}+ dxd dyd rcurveline
+fn rcurveline(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() >= 8 && (args.len() - 2) % 6 == 0)?;
+
+    let (curves, line) = args.split_at(args.len() - 2);
+    rrcurveto(curves, path)?;
+    path.line_to(line[0], line[1])
+}
+
+/// {dxa dya}+ dxb dyb dxc dyc dxd dyd rlinecurve
+fn rlinecurve(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() >= 8 && (args.len() - 6) % 2 == 0)?;
+
+    let (lines, curve) = args.split_at(args.len() - 6);
+    rlineto(lines, path)?;
+    rrcurveto(curve, path)
+}
+
+/// dx1? {dya dxb dyb dyc}+ vvcurveto
+fn vvcurveto(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    let (mut dx1, curves) = if args.len() % 2 == 1 { (args[0], &args[1..]) } else { (0.0, args) };
+    check_arguments(args, !curves.is_empty() && curves.len() % 4 == 0)?;
+
+    for curve in curves.chunks(4) {
+        path.curve_to(dx1, curve[0], curve[1], curve[2], 0.0, curve[3])?;
+        dx1 = 0.0;
+    }
+
+    Ok(())
+}
+
+/// dy1? {dxa dxb dyb dxc}+ hhcurveto
+fn hhcurveto(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    let (mut dy1, curves) = if args.len() % 2 == 1 { (args[0], &args[1..]) } else { (0.0, args) };
+    check_arguments(args, !curves.is_empty() && curves.len() % 4 == 0)?;
+
+    for curve in curves.chunks(4) {
+        path.curve_to(curve[0], dy1, curve[1], curve[2], curve[3], 0.0)?;
+        dy1 = 0.0;
+    }
+
+    Ok(())
+}
+
+/// hvcurveto and vhcurveto: curves alternating between starting horizontal and ending vertical,
+/// and starting vertical and ending horizontal. The last curve may have an extra argument for
+/// its last coordinate.
+fn alternating_curves(args: &[f32], horizontal: bool, path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() >= 4 && args.len() % 4 <= 1)?;
+
+    let mut horizontal = horizontal;
+    let mut curves = args;
+
+    while curves.len() >= 4 {
+        let df = if curves.len() == 5 { curves[4] } else { 0.0 };
+
+        if horizontal {
+            path.curve_to(curves[0], 0.0, curves[1], curves[2], df, curves[3])?;
+        }
+        else {
+            path.curve_to(0.0, curves[0], curves[1], curves[2], curves[3], df)?;
+        }
+
+        horizontal = !horizontal;
+        curves = &curves[4..];
+    }
+
+    Ok(())
+}
+
+/// dx1 dy1 dx2 dy2 dx3 dy3 dx4 dy4 dx5 dy5 dx6 dy6 fd flex
+fn flex(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() == 13)?;
+
+    path.curve_to(args[0], args[1], args[2], args[3], args[4], args[5])?;
+    path.curve_to(args[6], args[7], args[8], args[9], args[10], args[11])
+}
+
+/// dx1 dx2 dy2 dx3 dx4 dx5 dx6 hflex
+fn hflex(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() == 7)?;
+
+    path.curve_to(args[0], 0.0, args[1], args[2], args[3], 0.0)?;
+    path.curve_to(args[4], 0.0, args[5], -args[2], args[6], 0.0)
+}
+
+/// dx1 dy1 dx2 dy2 dx3 dx4 dx5 dy5 dx6 hflex1
+fn hflex1(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() == 9)?;
+
+    path.curve_to(args[0], args[1], args[2], args[3], args[4], 0.0)?;
+    path.curve_to(args[5], 0.0, args[6], args[7], args[8], -(args[1] + args[3] + args[7]))
+}
+
+/// dx1 dy1 dx2 dy2 dx3 dy3 dx4 dy4 dx5 dy5 d6 flex1
+fn flex1(args: &[f32], path: &mut Path) -> Result<(), Error> {
+    check_arguments(args, args.len() == 11)?;
+
+    let dx: f32 = args[..10].iter().step_by(2).sum();
+    let dy: f32 = args[1..10].iter().step_by(2).sum();
+
+    // The last point returns to the starting point along the least significant axis
+    let (dx6, dy6) = if dx.abs() > dy.abs() { (args[10], -dy) } else { (-dx, args[10]) };
+
+    path.curve_to(args[0], args[1], args[2], args[3], args[4], args[5])?;
+    path.curve_to(args[6], args[7], args[8], args[9], dx6, dy6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use outline::tests::PathRecorder;
+    use tables::cff::index::parse_index;
+
+    fn interpret(char_string: &[u8], global_subrs: &Index, local_subrs: Option<&Index>)
+        -> Result<(Vec<String>, Option<Seac>), Error> {
+        let mut recorder = PathRecorder::default();
+        let seac = CharStringInterpreter::new(global_subrs, local_subrs, &mut recorder, 0.0, 0.0)
+            .run(char_string)?;
+
+        Ok((recorder.commands, seac))
+    }
+
+    fn empty_index() -> Index<'static> {
+        parse_index(&[0x00, 0x00]).unwrap().1
+    }
+
+    #[test]
+    fn case_char_string_lines() {
+        // 500 10 20 rmoveto 100 hlineto 50 vlineto -100 -50 rlineto endchar
+        let char_string: &[u8] = &[
+            0xF8, 0x88, 0x95, 0x9F, 0x15, 0xEF, 0x06, 0xBD, 0x07, 0x27, 0x59, 0x05, 0x0E];
+
+        let (commands, seac) = interpret(char_string, &empty_index(), None).unwrap();
+
+        assert_eq!(commands, vec!["M 10 20", "L 110 20", "L 110 70", "L 10 20", "Z"]);
+        assert_eq!(seac, None);
+    }
+
+    #[test]
+    fn case_char_string_curves() {
+        // 10 hstem 0 0 rmoveto 10 20 30 40 hvcurveto 10 20 30 40 5 vhcurveto
+        // 1 2 3 4 5 6 7 8 rcurveline endchar
+        let char_string: &[u8] = &[
+            0x95, 0x01, 0x8B, 0x8B, 0x15, 0x95, 0x9F, 0xA9, 0xB3, 0x1F, 0x95, 0x9F, 0xA9, 0xB3,
+            0x90, 0x1E, 0x8C, 0x8D, 0x8E, 0x8F, 0x90, 0x91, 0x92, 0x93, 0x18, 0x0E];
+
+        let (commands, _) = interpret(char_string, &empty_index(), None).unwrap();
+
+        assert_eq!(commands, vec![
+            "M 0 0",
+            "C 10 0 30 30 30 70",
+            "C 30 80 50 110 90 115",
+            "C 91 117 94 121 99 127",
+            "L 106 135",
+            "Z"]);
+    }
+
+    #[test]
+    fn case_char_string_subrs_and_hintmask() {
+        // Global subroutine 0 (biased -107): 0 0 rmoveto return
+        let global_subrs = parse_index(&[0x00, 0x01, 0x01, 0x01, 0x05, 0x8B, 0x8B, 0x15, 0x0B]).unwrap().1;
+        // Local subroutine 1 (biased -106): 100 0 rlineto return
+        let local_subrs = parse_index(&[
+            0x00, 0x02, 0x01, 0x01, 0x02, 0x06, 0x0B, 0xEF, 0x8B, 0x05, 0x0B]).unwrap().1;
+
+        // 300 10 20 hstemhm 30 40 hintmask 0xC0 -107 callgsubr -106 callsubr endchar
+        let char_string: &[u8] = &[
+            0xF7, 0xC0, 0x95, 0x9F, 0x12, 0xA9, 0xB3, 0x13, 0xC0, 0x20, 0x1D, 0x21, 0x0A, 0x0E];
+
+        let (commands, _) = interpret(char_string, &global_subrs, Some(&local_subrs)).unwrap();
+
+        assert_eq!(commands, vec!["M 0 0", "L 100 0", "Z"]);
+
+        // Local subroutines are missing
+        assert!(interpret(char_string, &global_subrs, None).is_err());
+    }
+
+    #[test]
+    fn case_char_string_flex() {
+        // 0 0 rmoveto 1 2 3 4 5 6 7 8 9 10 11 flex1 endchar
+        let char_string: &[u8] = &[
+            0x8B, 0x8B, 0x15, 0x8C, 0x8D, 0x8E, 0x8F, 0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96,
+            0x0C, 0x25, 0x0E];
+
+        let (commands, _) = interpret(char_string, &empty_index(), None).unwrap();
+
+        assert_eq!(commands, vec!["M 0 0", "C 1 2 4 6 9 12", "C 16 20 25 30 0 41", "Z"]);
+    }
+
+    #[test]
+    fn case_char_string_seac() {
+        // 100 0 65 194 endchar
+        let char_string: &[u8] = &[0xEF, 0x8B, 0xCC, 0xF7, 0x56, 0x0E];
+
+        let (commands, seac) = interpret(char_string, &empty_index(), None).unwrap();
+
+        assert!(commands.is_empty());
+        assert_eq!(seac, Some(Seac {
+            adx: 100.0,
+            ady: 0.0,
+            base_code: 65,
+            accent_code: 194
+        }));
+    }
+
+    #[test]
+    fn case_char_string_invalid() {
+        // Missing endchar
+        assert!(interpret(&[0x8B, 0x8B, 0x15], &empty_index(), None).is_err());
+        // Missing moveto
+        assert!(interpret(&[0x8B, 0x8B, 0x05, 0x0E], &empty_index(), None).is_err());
+        // Invalid number of arguments
+        assert!(interpret(&[0x8B, 0x8B, 0x15, 0x8B, 0x05, 0x0E], &empty_index(), None).is_err());
+        // Missing arguments of an operator taking an odd number of arguments: hmoveto endchar
+        assert!(interpret(&[0x16, 0x0E], &empty_index(), None).is_err());
+        // Recursive subroutine: -107 callgsubr
+        let global_subrs = parse_index(&[0x00, 0x01, 0x01, 0x01, 0x03, 0x20, 0x1D]).unwrap().1;
+        assert!(interpret(&[0x20, 0x1D, 0x0E], &global_subrs, None).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::number::complete::{be_u8, be_i16, be_i32};
+
+// One-byte operators
+pub const VERSION: u16 = 0;
+pub const NOTICE: u16 = 1;
+pub const FULL_NAME: u16 = 2;
+pub const FAMILY_NAME: u16 = 3;
+pub const WEIGHT: u16 = 4;
+pub const FONT_BBOX: u16 = 5;
+pub const CHARSET: u16 = 15;
+pub const ENCODING: u16 = 16;
+pub const CHAR_STRINGS: u16 = 17;
+pub const PRIVATE: u16 = 18;
+pub const SUBRS: u16 = 19;
+pub const DEFAULT_WIDTH_X: u16 = 20;
+pub const NOMINAL_WIDTH_X: u16 = 21;
+pub const VSINDEX: u16 = 22;
+pub const VSTORE: u16 = 24;
+
+// Two-byte operators, the first byte being the escape byte 12
+pub const COPYRIGHT: u16 = 0x0C00;
+pub const IS_FIXED_PITCH: u16 = 0x0C01;
+pub const ITALIC_ANGLE: u16 = 0x0C02;
+pub const CHARSTRING_TYPE: u16 = 0x0C06;
+pub const FONT_MATRIX: u16 = 0x0C07;
+pub const ROS: u16 = 0x0C1E;
+pub const CID_COUNT: u16 = 0x0C22;
+pub const FD_ARRAY: u16 = 0x0C24;
+pub const FD_SELECT: u16 = 0x0C25;
+pub const FONT_NAME: u16 = 0x0C26;
+
+/// A DICT operand.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Number {
+    Integer(i32),
+    Real(f64)
+}
+
+impl Number {
+    pub fn as_i32(&self) -> i32 {
+        match *self {
+            Number::Integer(value) => value,
+            Number::Real(value) => value as i32
+        }
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Number::Integer(value) => f64::from(value),
+            Number::Real(value) => value
+        }
+    }
+}
+
+/// Font dictionary data comprising key-value pairs represented in a compact tokenized format
+/// that is similar to that used to represent Type 1 charstrings. Dictionary keys are encoded as
+/// 1- or 2-byte operators and dictionary values are encoded as variable-size numeric operands
+/// that represent either integer or real values.
+///
+/// A DICT is represented as a sequence of operands followed by the operator they belong to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dict {
+    entries: Vec<(u16, Vec<Number>)>
+}
+
+impl Dict {
+    /// Operands of the given operator, if the operator is present.
+    pub fn get(&self, operator: u16) -> Option<&[Number]> {
+        self.entries.iter()
+            .find(|&&(entry_operator, _)| entry_operator == operator)
+            .map(|(_, operands)| operands.as_slice())
+    }
+
+    /// First operand of the given operator, as an integer.
+    pub fn get_i32(&self, operator: u16) -> Option<i32> {
+        self.get(operator).and_then(|operands| operands.first()).map(Number::as_i32)
+    }
+
+    /// First operand of the given operator, as a real number.
+    pub fn get_f64(&self, operator: u16) -> Option<f64> {
+        self.get(operator).and_then(|operands| operands.first()).map(Number::as_f64)
+    }
+
+    /// First operand of the given operator, as an offset.
+    pub fn get_offset(&self, operator: u16) -> Option<usize> {
+        self.get_i32(operator).and_then(|offset| if offset >= 0 { Some(offset as usize) } else { None })
+    }
+
+    /// Operands of the Private operator: the size and the offset of the Private DICT.
+    pub fn get_private(&self) -> Option<(usize, usize)> {
+        match self.get(PRIVATE) {
+            Some(&[size, offset]) if size.as_i32() >= 0 && offset.as_i32() >= 0 =>
+                Some((size.as_i32() as usize, offset.as_i32() as usize)),
+            _ => None
+        }
+    }
+}
+
+/// Parse a DICT, the whole input being consumed.
+pub fn parse_dict(input: &[u8]) -> IResult<&[u8], Dict>
+{
+    let mut input = input;
+    let mut entries = Vec::new();
+    let mut operands = Vec::new();
+
+    while !input.is_empty() {
+        let b0 = input[0];
+
+        match b0 {
+            0..=11 | 13..=24 => {
+                entries.push((u16::from(b0), operands));
+                operands = Vec::new();
+                input = &input[1..];
+            },
+            12 => {
+                let (remainder, b1) = be_u8(&input[1..])?;
+                entries.push((0x0C00 | u16::from(b1), operands));
+                operands = Vec::new();
+                input = remainder;
+            },
+            _ => {
+                let (remainder, operand) = parse_operand(input)?;
+                operands.push(operand);
+                input = remainder;
+            }
+        }
+    }
+
+    Ok((input, Dict {
+        entries
+    }))
+}
+
+fn parse_operand(input: &[u8]) -> IResult<&[u8], Number>
+{
+    let (input, b0) = be_u8(input)?;
+
+    match b0 {
+        28 => {
+            let (input, value) = be_i16(input)?;
+            Ok((input, Number::Integer(i32::from(value))))
+        },
+        29 => {
+            let (input, value) = be_i32(input)?;
+            Ok((input, Number::Integer(value)))
+        },
+        30 => parse_real(input),
+        32..=246 => Ok((input, Number::Integer(i32::from(b0) - 139))),
+        247..=250 => {
+            let (input, b1) = be_u8(input)?;
+            Ok((input, Number::Integer((i32::from(b0) - 247) * 256 + i32::from(b1) + 108)))
+        },
+        251..=254 => {
+            let (input, b1) = be_u8(input)?;
+            Ok((input, Number::Integer(-(i32::from(b0) - 251) * 256 - i32::from(b1) - 108)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+/// A real number operand is provided in addition to integer operands. This operand begins with
+/// a byte value of 30 followed by a variable-length sequence of bytes. Each byte is composed of
+/// two 4-bit nibbles. The first nibble of a pair is stored in the most significant 4 bits of a
+/// byte and the second nibble of a pair is stored in the least significant 4 bits of a byte.
+fn parse_real(input: &[u8]) -> IResult<&[u8], Number>
+{
+    let mut input = input;
+    let mut real = String::new();
+
+    'bytes: loop {
+        let (remainder, byte) = be_u8(input)?;
+        input = remainder;
+
+        for &nibble in &[byte >> 4, byte & 0x0F] {
+            match nibble {
+                0..=9 => real.push((b'0' + nibble) as char),
+                0xA => real.push('.'),
+                0xB => real.push('E'),
+                0xC => real.push_str("E-"),
+                0xE => real.push('-'),
+                0xF => break 'bytes,
+                _ => return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)))
+            }
+        }
+    }
+
+    match real.parse::<f64>() {
+        Ok(value) => Ok((input, Number::Real(value))),
+        Err(_) => Err(NomErr::Error(error_position!(input, ErrorKind::Verify)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_dict_operands() {
+        let bytes: &[u8]  = &[
+            0x8B, 0xEF, 0x27, 0xFA, 0x7C, 0xFE, 0x7C, 0x1C, 0x27, 0x10, 0x1C, 0xD8, 0xF0, 0x1D,
+            0x00, 0x01, 0x86, 0xA0, 0x05,
+            0x1E, 0xE2, 0xA2, 0x5F, 0x1E, 0x0A, 0x14, 0x05, 0x41, 0xC3, 0xFF, 0x0C, 0x07];
+
+        let dict = parse_dict(bytes).unwrap().1;
+
+        assert_eq!(dict.get(FONT_BBOX), Some(&[
+            Number::Integer(0), Number::Integer(100), Number::Integer(-100), Number::Integer(1000),
+            Number::Integer(-1000), Number::Integer(10000), Number::Integer(-10000),
+            Number::Integer(100000)][..]));
+        assert_eq!(dict.get(FONT_MATRIX), Some(&[
+            Number::Real(-2.25), Number::Real(0.140541E-3)][..]));
+        assert_eq!(dict.get(VERSION), None);
+    }
+
+    #[test]
+    fn case_dict_private() {
+        let bytes: &[u8]  = &[0xCD, 0x1C, 0x5E, 0xD8, 0x12, 0xAB, 0x13];
+
+        let dict = parse_dict(bytes).unwrap().1;
+
+        assert_eq!(dict.get_private(), Some((66, 24280)));
+        assert_eq!(dict.get_offset(SUBRS), Some(32));
+        assert_eq!(dict.get_i32(NOMINAL_WIDTH_X), None);
+    }
+
+    #[test]
+    fn case_dict_invalid_operand() {
+        let bytes: &[u8]  = &[0xFF, 0x00];
+
+        assert!(parse_dict(bytes).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16};
+use tables::GlyphId;
+use tables::cff::charset::Charset;
+
+/// Encodings
+///
+/// Encoding data is located via the offset operand to the Encoding operator in the Top DICT.
+/// Only one Encoding operator can be specified per font except for CIDFonts which specify no
+/// encoding. An encoding maps codes from 0 to 255 to glyphs, either directly for the custom
+/// encodings or through the String ID of the glyph name for the predefined encodings and the
+/// supplements.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Encoding {
+    /// Predefined Standard encoding.
+    Standard,
+    /// Predefined Expert encoding.
+    Expert,
+    /// Array of codes, one for each glyph starting at glyph 1.
+    Format0 {
+        codes: Vec<u8>,
+        supplements: Vec<EncodingSupplement>
+    },
+    /// Ranges of consecutive codes assigned to consecutive glyphs starting at glyph 1.
+    Format1 {
+        ranges: Vec<EncodingRange>,
+        supplements: Vec<EncodingSupplement>
+    }
+}
+
+impl Encoding {
+    /// Glyph mapped to the given code.
+    ///
+    /// * `charset` - The charset of the font used to resolve the String IDs of the glyph names.
+    pub fn glyph_id(&self, code: u8, charset: &Charset) -> Option<GlyphId> {
+        match self {
+            Encoding::Standard => sid_glyph_id(STANDARD_ENCODING[usize::from(code)], charset),
+            Encoding::Expert => sid_glyph_id(EXPERT_ENCODING[usize::from(code)], charset),
+            Encoding::Format0 { codes, supplements } => {
+                if let Some(glyph_id) = supplement_glyph_id(supplements, code, charset) {
+                    return Some(glyph_id);
+                }
+
+                codes.iter().position(|&c| c == code).map(|index| index as GlyphId + 1)
+            },
+            Encoding::Format1 { ranges, supplements } => {
+                if let Some(glyph_id) = supplement_glyph_id(supplements, code, charset) {
+                    return Some(glyph_id);
+                }
+
+                let mut first_glyph_id = 1u32;
+
+                for range in ranges {
+                    if code >= range.first && u16::from(code) <= u16::from(range.first) + u16::from(range.n_left) {
+                        return Some((first_glyph_id + u32::from(code - range.first)) as GlyphId);
+                    }
+
+                    first_glyph_id += u32::from(range.n_left) + 1;
+                }
+
+                None
+            }
+        }
+    }
+}
+
+fn sid_glyph_id(sid: u16, charset: &Charset) -> Option<GlyphId> {
+    // SID 0 (.notdef) marks an unencoded code
+    if sid == 0 {
+        return None;
+    }
+
+    charset.glyph_id(sid)
+}
+
+fn supplement_glyph_id(supplements: &[EncodingSupplement], code: u8, charset: &Charset) -> Option<GlyphId> {
+    supplements.iter()
+        .find(|supplement| supplement.code == code)
+        .and_then(|supplement| sid_glyph_id(supplement.glyph, charset))
+}
+
+/// A range of consecutive codes assigned to consecutive glyphs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EncodingRange {
+    first: u8,
+    n_left: u8
+}
+
+impl EncodingRange {
+    /// First code in range.
+    pub fn first(&self) -> u8 {
+        self.first
+    }
+
+    /// Codes left in range (excluding first).
+    pub fn n_left(&self) -> u8 {
+        self.n_left
+    }
+}
+
+/// Supplemental encoding, allowing multiple codes to be assigned to the same glyph.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EncodingSupplement {
+    code: u8,
+    glyph: u16
+}
+
+impl EncodingSupplement {
+    /// Encoding.
+    pub fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// String ID of the name of the glyph.
+    pub fn glyph(&self) -> u16 {
+        self.glyph
+    }
+}
+
+/// Parse an encoding stored in the font.
+pub fn parse_encoding(input: &[u8]) -> IResult<&[u8], Encoding>
+{
+    let (input, format) = be_u8(input)?;
+
+    // The high-order bit of the format is set if supplements are present
+    let has_supplements = format & 0x80 != 0;
+
+    match format & 0x7F {
+        0 => {
+            let (input, n_codes) = be_u8(input)?;
+            let (input, codes) = count(be_u8, usize::from(n_codes))(input)?;
+            let (input, supplements) = parse_encoding_supplements(input, has_supplements)?;
+            Ok((input, Encoding::Format0 {
+                codes,
+                supplements
+            }))
+        },
+        1 => {
+            let (input, n_ranges) = be_u8(input)?;
+            let (input, ranges) = count(parse_encoding_range, usize::from(n_ranges))(input)?;
+            let (input, supplements) = parse_encoding_supplements(input, has_supplements)?;
+            Ok((input, Encoding::Format1 {
+                ranges,
+                supplements
+            }))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_encoding_range(input: &[u8]) -> IResult<&[u8], EncodingRange>
+{
+    let (input, first) = be_u8(input)?;
+    let (input, n_left) = be_u8(input)?;
+
+    Ok((input, EncodingRange {
+        first,
+        n_left
+    }))
+}
+
+fn parse_encoding_supplements(input: &[u8], has_supplements: bool) -> IResult<&[u8], Vec<EncodingSupplement>>
+{
+    if !has_supplements {
+        return Ok((input, Vec::new()));
+    }
+
+    let (input, n_sups) = be_u8(input)?;
+    count(parse_encoding_supplement, usize::from(n_sups))(input)
+}
+
+fn parse_encoding_supplement(input: &[u8]) -> IResult<&[u8], EncodingSupplement>
+{
+    let (input, code) = be_u8(input)?;
+    let (input, glyph) = be_u16(input)?;
+
+    Ok((input, EncodingSupplement {
+        code,
+        glyph
+    }))
+}
+
+/// String IDs of the glyph names of the Standard encoding, indexed by code.
+static STANDARD_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74,
+    75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 0, 111, 112, 113, 114, 0,
+    115, 116, 117, 118, 119, 120, 121, 122, 0, 123, 0, 124, 125, 126, 127, 128, 129, 130, 131, 0,
+    132, 133, 0, 134, 135, 136, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 139, 0,
+    0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0, 0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0, 0,
+    0, 0
+];
+
+/// String IDs of the glyph names of the Expert encoding, indexed by code.
+static EXPERT_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 229, 230, 0, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252, 0, 253, 254, 255, 256, 257, 0, 0, 0, 258,
+    0, 0, 259, 260, 261, 262, 0, 0, 263, 264, 265, 0, 266, 109, 110, 267, 268, 269, 0, 270, 271,
+    272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290,
+    291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302, 303, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 304, 305, 306, 0, 0,
+    307, 308, 309, 310, 311, 0, 312, 0, 0, 313, 314, 315, 316, 317, 318, 0, 0, 0, 0, 0, 0, 0, 158,
+    155, 163, 319, 320, 321, 322, 323, 324, 325, 0, 0, 326, 150, 164, 169, 327, 328, 329, 330, 331,
+    332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350,
+    351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369,
+    370, 371, 372, 373, 374, 375, 376, 377, 378
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_encoding_format0() {
+        let bytes: &[u8]  = &[0x80, 0x02, 0x41, 0x42, 0x01, 0x61, 0x00, 0x22];
+
+        let encoding = parse_encoding(bytes).unwrap().1;
+        let charset = Charset::Format0(vec![34, 35]);
+
+        assert_eq!(encoding.glyph_id(0x41, &charset), Some(1));
+        assert_eq!(encoding.glyph_id(0x42, &charset), Some(2));
+        assert_eq!(encoding.glyph_id(0x61, &charset), Some(1));
+        assert_eq!(encoding.glyph_id(0x43, &charset), None);
+    }
+
+    #[test]
+    fn case_encoding_format1() {
+        let bytes: &[u8]  = &[0x01, 0x02, 0x41, 0x19, 0x61, 0x00];
+
+        let encoding = parse_encoding(bytes).unwrap().1;
+
+        assert_eq!(encoding.glyph_id(0x41, &Charset::IsoAdobe), Some(1));
+        assert_eq!(encoding.glyph_id(0x5A, &Charset::IsoAdobe), Some(26));
+        assert_eq!(encoding.glyph_id(0x61, &Charset::IsoAdobe), Some(27));
+        assert_eq!(encoding.glyph_id(0x62, &Charset::IsoAdobe), None);
+    }
+
+    #[test]
+    fn case_encoding_predefined() {
+        assert_eq!(Encoding::Standard.glyph_id(0x41, &Charset::IsoAdobe), Some(34));
+        assert_eq!(Encoding::Standard.glyph_id(0xE1, &Charset::IsoAdobe), Some(138));
+        assert_eq!(Encoding::Standard.glyph_id(0x80, &Charset::IsoAdobe), None);
+        assert_eq!(Encoding::Expert.glyph_id(0x21, &Charset::Expert), Some(2));
+    }
+
+    #[test]
+    fn case_encoding_invalid_format() {
+        let bytes: &[u8]  = &[0x02, 0x00];
+
+        assert!(parse_encoding(bytes).is_err());
+    }
+}
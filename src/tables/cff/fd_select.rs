@@ -0,0 +1,165 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+use tables::GlyphId;
+
+/// FDSelect
+///
+/// The FDSelect associates an FD (Font DICT) with a glyph by specifying an FD index for that
+/// glyph. The FD index is used to access one of the Font DICTs stored in the Font DICT INDEX.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FdSelect {
+    /// FD index of each glyph.
+    Format0(Vec<u8>),
+    /// Ranges of consecutive glyphs sharing the same FD index.
+    Format3 {
+        ranges: Vec<FdSelectRange>,
+        sentinel: u32
+    },
+    /// Ranges of consecutive glyphs sharing the same FD index, with 32-bit glyph indices and
+    /// 16-bit FD indices (CFF2).
+    Format4 {
+        ranges: Vec<FdSelectRange>,
+        sentinel: u32
+    }
+}
+
+impl FdSelect {
+    /// Index of the Font DICT used by the given glyph.
+    pub fn font_dict_index(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self {
+            FdSelect::Format0(fds) => fds.get(usize::from(glyph_id)).map(|&fd| u16::from(fd)),
+            FdSelect::Format3 { ranges, sentinel } | FdSelect::Format4 { ranges, sentinel } => {
+                if u32::from(glyph_id) >= *sentinel {
+                    return None;
+                }
+
+                // Ranges are sorted by first glyph, the range of the glyph is the last one
+                // starting at or before it
+                ranges.iter()
+                    .take_while(|range| range.first <= u32::from(glyph_id))
+                    .last()
+                    .map(|range| range.fd)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FdSelectRange {
+    first: u32,
+    fd: u16
+}
+
+impl FdSelectRange {
+    /// First glyph index in range.
+    pub fn first(&self) -> u32 {
+        self.first
+    }
+
+    /// FD index for all glyphs in range.
+    pub fn fd(&self) -> u16 {
+        self.fd
+    }
+}
+
+/// Parse a FDSelect stored in the font.
+///
+/// * `num_glyphs` - The number of glyphs is determined by the number of objects of the
+///   CharStrings INDEX.
+pub fn parse_fd_select(input: &[u8], num_glyphs: u16) -> IResult<&[u8], FdSelect>
+{
+    let (input, format) = be_u8(input)?;
+
+    match format {
+        0 => {
+            let (input, fds) = count(be_u8, usize::from(num_glyphs))(input)?;
+            Ok((input, FdSelect::Format0(fds)))
+        },
+        3 => {
+            let (input, n_ranges) = be_u16(input)?;
+            let (input, ranges) = count(parse_fd_select_range3, usize::from(n_ranges))(input)?;
+            let (input, sentinel) = be_u16(input)?;
+            Ok((input, FdSelect::Format3 {
+                ranges,
+                sentinel: u32::from(sentinel)
+            }))
+        },
+        4 => {
+            let (input, n_ranges) = be_u32(input)?;
+            let (input, ranges) = count(parse_fd_select_range4, n_ranges as usize)(input)?;
+            let (input, sentinel) = be_u32(input)?;
+            Ok((input, FdSelect::Format4 {
+                ranges,
+                sentinel
+            }))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_fd_select_range3(input: &[u8]) -> IResult<&[u8], FdSelectRange>
+{
+    let (input, first) = be_u16(input)?;
+    let (input, fd) = be_u8(input)?;
+
+    Ok((input, FdSelectRange {
+        first: u32::from(first),
+        fd: u16::from(fd)
+    }))
+}
+
+fn parse_fd_select_range4(input: &[u8]) -> IResult<&[u8], FdSelectRange>
+{
+    let (input, first) = be_u32(input)?;
+    let (input, fd) = be_u16(input)?;
+
+    Ok((input, FdSelectRange {
+        first,
+        fd
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_fd_select_format0() {
+        let bytes: &[u8]  = &[0x00, 0x00, 0x01, 0x01];
+
+        let fd_select = parse_fd_select(bytes, 3).unwrap().1;
+
+        assert_eq!(fd_select.font_dict_index(0), Some(0));
+        assert_eq!(fd_select.font_dict_index(2), Some(1));
+        assert_eq!(fd_select.font_dict_index(3), None);
+    }
+
+    #[test]
+    fn case_fd_select_format3() {
+        let bytes: &[u8]  = &[0x03, 0x00, 0x02, 0x00, 0x00, 0x01, 0x00, 0x0A, 0x00, 0x00, 0x14];
+
+        let fd_select = parse_fd_select(bytes, 20).unwrap().1;
+
+        assert_eq!(fd_select.font_dict_index(0), Some(1));
+        assert_eq!(fd_select.font_dict_index(9), Some(1));
+        assert_eq!(fd_select.font_dict_index(10), Some(0));
+        assert_eq!(fd_select.font_dict_index(19), Some(0));
+        assert_eq!(fd_select.font_dict_index(20), None);
+    }
+
+    #[test]
+    fn case_fd_select_format4() {
+        let bytes: &[u8]  = &[
+            0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08];
+
+        let fd_select = parse_fd_select(bytes, 8).unwrap().1;
+
+        assert_eq!(fd_select.font_dict_index(4), Some(300));
+        assert_eq!(fd_select.font_dict_index(5), Some(0));
+        assert_eq!(fd_select.font_dict_index(8), None);
+    }
+}
@@ -0,0 +1,184 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::bytes::complete::take;
+use nom::error::ErrorKind;
+use nom::number::complete::{be_u8, be_u16, be_u32};
+
+/// An INDEX is an array of variable-sized objects. It comprises a header, an offset array, and
+/// object data. The offset array specifies offsets within the object data. An object is
+/// retrieved by indexing the offset array and fetching the object at the specified offset. The
+/// object's length can be determined by subtracting its offset from the next offset in the
+/// offset array. An additional offset is added at the end of the offset array so the length of
+/// the last object may be determined.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Index<'otf> {
+    count: u32,
+    off_size: u8,
+    offsets: &'otf[u8],
+    data: &'otf[u8]
+}
+
+impl<'otf> Index<'otf> {
+    /// Number of objects stored in INDEX.
+    pub fn len(&self) -> u32 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the object at the given index.
+    pub fn get(&self, index: u32) -> Option<&'otf[u8]> {
+        if index >= self.count {
+            return None;
+        }
+
+        // Offsets are relative to the byte that precedes the object data
+        let start = self.offset(index)?.checked_sub(1)?;
+        let end = self.offset(index + 1)?.checked_sub(1)?;
+
+        self.data.get(start..end)
+    }
+
+    /// Iterator over the objects of the INDEX.
+    pub fn iter(&self) -> IndexIterator<'otf> {
+        IndexIterator {
+            index: *self,
+            pos: 0
+        }
+    }
+
+    fn offset(&self, index: u32) -> Option<usize> {
+        let off_size = usize::from(self.off_size);
+        let start = index as usize * off_size;
+        let bytes = self.offsets.get(start..start + off_size)?;
+
+        Some(bytes.iter().fold(0, |offset, &byte| (offset << 8) | usize::from(byte)))
+    }
+}
+
+pub struct IndexIterator<'otf> {
+    index: Index<'otf>,
+    pos: u32
+}
+
+impl<'otf> Iterator for IndexIterator<'otf> {
+    type Item = &'otf[u8];
+
+    fn next(&mut self) -> Option<&'otf[u8]> {
+        let object = self.index.get(self.pos)?;
+        self.pos += 1;
+        Some(object)
+    }
+}
+
+/// Parse an INDEX whose count is stored on 16 bits (CFF).
+pub fn parse_index(input: &[u8]) -> IResult<&[u8], Index<'_>>
+{
+    let (input, count) = be_u16(input)?;
+    parse_index_data(input, u32::from(count))
+}
+
+/// Parse an INDEX whose count is stored on 32 bits (CFF2).
+pub fn parse_index2(input: &[u8]) -> IResult<&[u8], Index<'_>>
+{
+    let (input, count) = be_u32(input)?;
+    parse_index_data(input, count)
+}
+
+fn parse_index_data(input: &[u8], count: u32) -> IResult<&[u8], Index<'_>>
+{
+    // An empty INDEX is represented by a count field with a 0 value and no additional fields
+    if count == 0 {
+        return Ok((input, Index {
+            count,
+            off_size: 0,
+            offsets: &input[..0],
+            data: &input[..0]
+        }));
+    }
+
+    let (input, off_size) = be_u8(input)?;
+
+    if !(1..=4).contains(&off_size) {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let offsets_len = (count as usize).checked_add(1)
+        .and_then(|num_offsets| num_offsets.checked_mul(usize::from(off_size)))
+        .ok_or_else(|| NomErr::Error(error_position!(input, ErrorKind::TooLarge)))?;
+    let (input, offsets) = take(offsets_len)(input)?;
+
+    let mut index = Index {
+        count,
+        off_size,
+        offsets,
+        data: &input[..0]
+    };
+
+    let data_len = index.offset(count)
+        .and_then(|last_offset| last_offset.checked_sub(1))
+        .ok_or_else(|| NomErr::Error(error_position!(input, ErrorKind::Verify)))?;
+    let (input, data) = take(data_len)(input)?;
+
+    index.data = data;
+
+    Ok((input, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_index() {
+        let bytes: &[u8]  = &[0x00, 0x03, 0x01, 0x01, 0x02, 0x02, 0x06, 0x41, 0x42, 0x43, 0x44,
+            0x45, 0xFF];
+
+        let (remainder, index) = parse_index(bytes).unwrap();
+
+        assert_eq!(remainder, &[0xFF]);
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.get(0), Some(&[0x41][..]));
+        assert_eq!(index.get(1), Some(&[][..]));
+        assert_eq!(index.get(2), Some(&[0x42, 0x43, 0x44, 0x45][..]));
+        assert_eq!(index.get(3), None);
+        assert_eq!(index.iter().count(), 3);
+    }
+
+    #[test]
+    fn case_index_empty() {
+        let bytes: &[u8]  = &[0x00, 0x00, 0xFF];
+
+        let (remainder, index) = parse_index(bytes).unwrap();
+
+        assert_eq!(remainder, &[0xFF]);
+        assert!(index.is_empty());
+        assert_eq!(index.get(0), None);
+    }
+
+    #[test]
+    fn case_index2() {
+        let bytes: &[u8]  = &[0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x01, 0x00, 0x03, 0x41, 0x42];
+
+        let (remainder, index) = parse_index2(bytes).unwrap();
+
+        assert_eq!(remainder, &[] as &[u8]);
+        assert_eq!(index.get(0), Some(&[0x41, 0x42][..]));
+    }
+
+    #[test]
+    fn case_index_invalid_off_size() {
+        let bytes: &[u8]  = &[0x00, 0x01, 0x05, 0x01, 0x02, 0x41];
+
+        assert!(parse_index(bytes).is_err());
+    }
+
+    #[test]
+    fn case_index_invalid_data_length() {
+        let bytes: &[u8]  = &[0x00, 0x01, 0x01, 0x01, 0x04, 0x41];
+
+        assert!(parse_index(bytes).is_err());
+    }
+}
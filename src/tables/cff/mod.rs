@@ -0,0 +1,658 @@
+use error::Error;
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::number::complete::be_u8;
+use outline::{BoundingBoxBuilder, OutlineBuilder};
+use std::str;
+use tables::GlyphId;
+use types::Rect;
+
+pub mod charset;
+pub(crate) mod charstring;
+pub(crate) mod dict;
+pub mod encoding;
+pub mod fd_select;
+pub mod index;
+mod std_strings;
+
+use self::charset::{Charset, parse_charset};
+use self::charstring::CharStringInterpreter;
+use self::dict::{Dict, parse_dict};
+use self::encoding::{Encoding, parse_encoding};
+use self::fd_select::{FdSelect, parse_fd_select};
+use self::index::{Index, parse_index};
+use self::std_strings::STANDARD_STRINGS;
+
+/// Compact Font Format Table
+///
+/// This table contains a Compact Font Format font representation (also known as a PostScript
+/// Type 1, or CIDFont) and is structured according to Adobe Technical Note #5176: “The Compact
+/// Font Format Specification,” and Adobe Technical Note #5177: “Type 2 Charstring Format.”
+///
+/// The CFF data is made of a header followed by the Name INDEX, the Top DICT INDEX, the String
+/// INDEX and the Global Subr INDEX. The other structures (CharStrings INDEX, charset, encoding,
+/// Private DICT, FDArray and FDSelect) are located with offsets stored in the Top DICT. An
+/// OpenType font contains a single font in its CFF data.
+///
+/// More information on ['CFF '](https://docs.microsoft.com/en-gb/typography/opentype/spec/cff)
+pub struct CompactFontFormatTable<'otf> {
+    major_version: u8,
+    minor_version: u8,
+    name_index: Index<'otf>,
+    top_dict: TopDict,
+    string_index: Index<'otf>,
+    global_subrs: Index<'otf>,
+    char_strings: Index<'otf>,
+    charset: Charset,
+    encoding: Encoding,
+    font_kind: FontKind<'otf>
+}
+
+impl<'otf> CompactFontFormatTable<'otf> {
+    /// Format major version (starting at 1).
+    pub fn major_version(&self) -> u8 {
+        self.major_version
+    }
+
+    /// Format minor version (starting at 0).
+    pub fn minor_version(&self) -> u8 {
+        self.minor_version
+    }
+
+    /// PostScript name of the font, stored in the Name INDEX.
+    pub fn name(&self) -> Option<&'otf str> {
+        self.name_index.get(0).and_then(|name| str::from_utf8(name).ok())
+    }
+
+    /// Top DICT of the font.
+    pub fn top_dict(&self) -> &TopDict {
+        &self.top_dict
+    }
+
+    /// Get a string by its String ID (SID).
+    ///
+    /// SIDs 0 to 390 refer to the standard strings, the following SIDs refer to the strings of
+    /// the String INDEX.
+    pub fn string(&self, sid: u16) -> Option<&'otf str> {
+        match STANDARD_STRINGS.get(usize::from(sid)) {
+            Some(string) => Some(string),
+            None => self.string_index.get(u32::from(sid) - STANDARD_STRINGS.len() as u32)
+                .and_then(|string| str::from_utf8(string).ok())
+        }
+    }
+
+    /// Subroutines shared by all the glyphs of the font.
+    pub fn global_subrs(&self) -> &Index<'otf> {
+        &self.global_subrs
+    }
+
+    /// Charstrings of the glyphs, indexed by glyph ID.
+    pub fn char_strings(&self) -> &Index<'otf> {
+        &self.char_strings
+    }
+
+    /// Number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u16 {
+        self.char_strings.len() as u16
+    }
+
+    pub fn charset(&self) -> &Charset {
+        &self.charset
+    }
+
+    pub fn encoding(&self) -> &Encoding {
+        &self.encoding
+    }
+
+    /// Private data of a name-keyed font or Font DICTs of a CID-keyed font.
+    pub fn font_kind(&self) -> &FontKind<'otf> {
+        &self.font_kind
+    }
+
+    /// Name of the given glyph, None for CID-keyed fonts whose glyphs are not named.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&'otf str> {
+        match self.font_kind {
+            FontKind::NameKeyed(_) => self.charset.sid(glyph_id).and_then(|sid| self.string(sid)),
+            FontKind::CidKeyed { .. } => None
+        }
+    }
+
+    /// CID of the given glyph, None for name-keyed fonts.
+    pub fn glyph_cid(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self.font_kind {
+            FontKind::NameKeyed(_) => None,
+            FontKind::CidKeyed { .. } => self.charset.sid(glyph_id)
+        }
+    }
+
+    /// Glyph mapped to the given code by the encoding of the font, None for CID-keyed fonts
+    /// which have no encoding.
+    pub fn glyph_id_by_code(&self, code: u8) -> Option<GlyphId> {
+        match self.font_kind {
+            FontKind::NameKeyed(_) => self.encoding.glyph_id(code, &self.charset),
+            FontKind::CidKeyed { .. } => None
+        }
+    }
+
+    /// Private DICT used by the charstring of the given glyph.
+    pub fn private_dict(&self, glyph_id: GlyphId) -> Option<&PrivateDict<'otf>> {
+        match self.font_kind {
+            FontKind::NameKeyed(ref private_dict) => Some(private_dict),
+            FontKind::CidKeyed { ref fd_array, ref fd_select } => fd_select.font_dict_index(glyph_id)
+                .and_then(|fd| fd_array.get(usize::from(fd)))
+                .map(FontDict::private_dict)
+        }
+    }
+
+    /// Interpret the charstring of the given glyph and send its outline to the builder.
+    ///
+    /// Return the bounding box of the outline, None if the glyph has no outline (e.g. the space
+    /// character), or an error if the glyph ID is out of bounds or the charstring is malformed.
+    pub fn outline<B: OutlineBuilder>(&self, glyph_id: GlyphId, builder: &mut B) -> Result<Option<Rect<i16>>, Error> {
+        let mut bounding_box_builder = BoundingBoxBuilder::new(builder);
+        self.outline_glyph(glyph_id, &mut bounding_box_builder, 0.0, 0.0, true)?;
+
+        Ok(bounding_box_builder.bounding_box())
+    }
+
+    fn outline_glyph(&self, glyph_id: GlyphId, builder: &mut dyn OutlineBuilder, x: f32, y: f32,
+                     allow_seac: bool) -> Result<(), Error> {
+        let char_string = self.char_strings.get(u32::from(glyph_id))
+            .ok_or_else(|| Error::new(format!("Glyph {} out of bounds", glyph_id)))?;
+        let local_subrs = self.private_dict(glyph_id).and_then(|private_dict| private_dict.local_subrs.as_ref());
+
+        let seac = CharStringInterpreter::new(&self.global_subrs, local_subrs, builder, x, y).run(char_string)?;
+
+        if let Some(seac) = seac {
+            if !allow_seac {
+                return Err(Error::new(format!("Glyph {} has nested accented characters", glyph_id)));
+            }
+
+            // The base and accent characters are identified by their Standard encoding code
+            let base_glyph_id = Encoding::Standard.glyph_id(seac.base_code, &self.charset)
+                .ok_or_else(|| Error::new(format!("Invalid seac base character {}", seac.base_code)))?;
+            let accent_glyph_id = Encoding::Standard.glyph_id(seac.accent_code, &self.charset)
+                .ok_or_else(|| Error::new(format!("Invalid seac accent character {}", seac.accent_code)))?;
+
+            self.outline_glyph(base_glyph_id, builder, x, y, false)?;
+            self.outline_glyph(accent_glyph_id, builder, x + seac.adx, y + seac.ady, false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse Compact Font Format Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::cff::CompactFontFormatTable;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x01, 0x00, 0x04, 0x04, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+    ///     0x54, 0x65, 0x73, 0x74, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1B,
+    ///     0xF8, 0x1B, 0x02, 0x1D, 0x00, 0x00, 0x00, 0x51, 0x0F, 0x1D, 0x00, 0x00, 0x00, 0x58, 0x11,
+    ///     0x1D, 0x00, 0x00, 0x00, 0x0B, 0x1D, 0x00, 0x00, 0x00, 0x93, 0x12, 0x00, 0x01, 0x04, 0x00,
+    ///     0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0D, 0x54, 0x65, 0x73, 0x74, 0x20, 0x52, 0x65, 0x67,
+    ///     0x75, 0x6C, 0x61, 0x72, 0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x7D, 0x00, 0xAB, 0x00, 0x04,
+    ///     0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+    ///     0x00, 0x1F, 0x00, 0x00, 0x00, 0x25, 0x8B, 0x0E, 0xEF, 0x8B, 0x8B, 0x15, 0xF8, 0x88, 0x06,
+    ///     0xF9, 0x50, 0x07, 0xFB, 0x8E, 0xFD, 0x50, 0x05, 0x0E, 0x27, 0xF7, 0x5C, 0xF9, 0x50, 0x15,
+    ///     0xEF, 0xEF, 0x05, 0x59, 0x06, 0x0E, 0xEF, 0x8B, 0xCC, 0xF7, 0x56, 0x0E, 0x95, 0x14, 0xF8,
+    ///     0x88, 0x15, 0x1D, 0x00, 0x00, 0x00, 0x0B, 0x13, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01,
+    ///     0x00, 0x00, 0x00, 0x04, 0x8B, 0x06, 0x0B];
+    ///
+    /// let cff = CompactFontFormatTable::parse(bytes).unwrap();
+    ///
+    /// assert_eq!(cff.name(), Some("Test"));
+    /// assert_eq!(cff.num_glyphs(), 4);
+    /// assert_eq!(cff.glyph_name(1), Some("A"));
+    /// assert_eq!(cff.glyph_id_by_code(b'A'), Some(1));
+    /// ```
+    pub fn parse(buf: &'otf[u8]) -> Result<CompactFontFormatTable<'otf>, Error> {
+        let (_, (major_version, minor_version, hdr_size)) = parse_header(buf)?;
+
+        let input = buf.get(usize::from(hdr_size)..).ok_or_else(|| Error::new("CFF header out of bounds"))?;
+        let (input, name_index) = parse_index(input)?;
+        let (input, top_dict_index) = parse_index(input)?;
+        let (input, string_index) = parse_index(input)?;
+        let (_, global_subrs) = parse_index(input)?;
+
+        let top_dict = top_dict_index.get(0).ok_or_else(|| Error::new("CFF Top DICT missing"))?;
+        let (_, top_dict) = parse_dict(top_dict)?;
+
+        let char_strings_offset = top_dict.get_offset(dict::CHAR_STRINGS)
+            .ok_or_else(|| Error::new("CFF CharStrings INDEX missing"))?;
+        let (_, char_strings) = parse_index(slice_at(buf, char_strings_offset)?)?;
+
+        if char_strings.len() > u32::from(u16::MAX) {
+            return Err(Error::new(format!("Invalid number of glyphs {}", char_strings.len())));
+        }
+
+        let num_glyphs = char_strings.len() as u16;
+
+        // Charset offsets 0 to 2 refer to the predefined charsets
+        let charset = match top_dict.get_offset(dict::CHARSET).unwrap_or(0) {
+            0 => Charset::IsoAdobe,
+            1 => Charset::Expert,
+            2 => Charset::ExpertSubset,
+            offset => parse_charset(slice_at(buf, offset)?, num_glyphs)?.1
+        };
+
+        let is_cid_keyed = top_dict.get(dict::ROS).is_some();
+
+        // Encoding offsets 0 and 1 refer to the predefined encodings, CID-keyed fonts have no
+        // encoding
+        let encoding = match top_dict.get_offset(dict::ENCODING).unwrap_or(0) {
+            0 => Encoding::Standard,
+            1 => Encoding::Expert,
+            _ if is_cid_keyed => Encoding::Standard,
+            offset => parse_encoding(slice_at(buf, offset)?)?.1
+        };
+
+        let font_kind = if is_cid_keyed {
+            let fd_array_offset = top_dict.get_offset(dict::FD_ARRAY)
+                .ok_or_else(|| Error::new("CFF FDArray missing"))?;
+            let fd_select_offset = top_dict.get_offset(dict::FD_SELECT)
+                .ok_or_else(|| Error::new("CFF FDSelect missing"))?;
+
+            let (_, fd_array_index) = parse_index(slice_at(buf, fd_array_offset)?)?;
+            let fd_array = fd_array_index.iter()
+                .map(|font_dict| parse_font_dict(buf, font_dict))
+                .collect::<Result<Vec<FontDict>, Error>>()?;
+            let (_, fd_select) = parse_fd_select(slice_at(buf, fd_select_offset)?, num_glyphs)?;
+
+            FontKind::CidKeyed {
+                fd_array,
+                fd_select
+            }
+        }
+        else {
+            FontKind::NameKeyed(parse_private_dict(buf, &top_dict)?)
+        };
+
+        Ok(CompactFontFormatTable {
+            major_version,
+            minor_version,
+            name_index,
+            top_dict: TopDict::new(&top_dict),
+            string_index,
+            global_subrs,
+            char_strings,
+            charset,
+            encoding,
+            font_kind
+        })
+    }
+}
+
+/// Fonts are either name-keyed, the glyphs being identified by their name, or CID-keyed, the
+/// glyphs being identified by a CID and grouped in Font DICTs having their own Private DICT.
+pub enum FontKind<'otf> {
+    NameKeyed(PrivateDict<'otf>),
+    CidKeyed {
+        fd_array: Vec<FontDict<'otf>>,
+        fd_select: FdSelect
+    }
+}
+
+/// Top DICT
+///
+/// Data of the font which is not specific to a glyph. Strings are identified by their String ID
+/// (SID), see [string](struct.CompactFontFormatTable.html#method.string).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopDict {
+    version: Option<u16>,
+    notice: Option<u16>,
+    copyright: Option<u16>,
+    full_name: Option<u16>,
+    family_name: Option<u16>,
+    weight: Option<u16>,
+    is_fixed_pitch: bool,
+    italic_angle: f32,
+    font_bbox: Rect<f32>,
+    font_matrix: [f32; 6],
+    charstring_type: i32,
+    ros: Option<(u16, u16, i32)>,
+    cid_count: u32
+}
+
+impl TopDict {
+    fn new(dict: &Dict) -> TopDict {
+        let sid = |operator| dict.get_i32(operator).map(|sid| sid as u16);
+        let numbers = |operator, defaults: &[f32]| -> Vec<f32> {
+            match dict.get(operator) {
+                Some(operands) if operands.len() == defaults.len() =>
+                    operands.iter().map(|operand| operand.as_f64() as f32).collect(),
+                _ => defaults.to_vec()
+            }
+        };
+
+        let font_bbox = numbers(dict::FONT_BBOX, &[0.0, 0.0, 0.0, 0.0]);
+        let font_matrix = numbers(dict::FONT_MATRIX, &[0.001, 0.0, 0.0, 0.001, 0.0, 0.0]);
+
+        TopDict {
+            version: sid(dict::VERSION),
+            notice: sid(dict::NOTICE),
+            copyright: sid(dict::COPYRIGHT),
+            full_name: sid(dict::FULL_NAME),
+            family_name: sid(dict::FAMILY_NAME),
+            weight: sid(dict::WEIGHT),
+            is_fixed_pitch: dict.get_i32(dict::IS_FIXED_PITCH).unwrap_or(0) != 0,
+            italic_angle: dict.get_f64(dict::ITALIC_ANGLE).unwrap_or(0.0) as f32,
+            font_bbox: Rect::new(font_bbox[0], font_bbox[1], font_bbox[2], font_bbox[3]),
+            font_matrix: [font_matrix[0], font_matrix[1], font_matrix[2], font_matrix[3], font_matrix[4], font_matrix[5]],
+            charstring_type: dict.get_i32(dict::CHARSTRING_TYPE).unwrap_or(2),
+            ros: match dict.get(dict::ROS) {
+                Some(&[registry, ordering, supplement]) =>
+                    Some((registry.as_i32() as u16, ordering.as_i32() as u16, supplement.as_i32())),
+                _ => None
+            },
+            cid_count: dict.get_i32(dict::CID_COUNT).unwrap_or(8720) as u32
+        }
+    }
+
+    /// SID of the version string.
+    pub fn version(&self) -> Option<u16> {
+        self.version
+    }
+
+    /// SID of the notice string.
+    pub fn notice(&self) -> Option<u16> {
+        self.notice
+    }
+
+    /// SID of the copyright string.
+    pub fn copyright(&self) -> Option<u16> {
+        self.copyright
+    }
+
+    /// SID of the full name string.
+    pub fn full_name(&self) -> Option<u16> {
+        self.full_name
+    }
+
+    /// SID of the family name string.
+    pub fn family_name(&self) -> Option<u16> {
+        self.family_name
+    }
+
+    /// SID of the weight string.
+    pub fn weight(&self) -> Option<u16> {
+        self.weight
+    }
+
+    pub fn is_fixed_pitch(&self) -> bool {
+        self.is_fixed_pitch
+    }
+
+    pub fn italic_angle(&self) -> f32 {
+        self.italic_angle
+    }
+
+    pub fn font_bbox(&self) -> Rect<f32> {
+        self.font_bbox
+    }
+
+    /// Transformation from glyph space to text space, [0.001 0 0 0.001 0 0] by default.
+    pub fn font_matrix(&self) -> [f32; 6] {
+        self.font_matrix
+    }
+
+    /// Format of the charstrings, only Type 2 charstrings are supported.
+    pub fn charstring_type(&self) -> i32 {
+        self.charstring_type
+    }
+
+    /// Registry SID, Ordering SID and Supplement of a CID-keyed font.
+    pub fn ros(&self) -> Option<(u16, u16, i32)> {
+        self.ros
+    }
+
+    /// Number of CIDs of a CID-keyed font.
+    pub fn cid_count(&self) -> u32 {
+        self.cid_count
+    }
+}
+
+/// Private DICT
+///
+/// Hinting data and widths of the glyphs, along with the local subroutines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PrivateDict<'otf> {
+    default_width_x: f32,
+    nominal_width_x: f32,
+    local_subrs: Option<Index<'otf>>
+}
+
+impl<'otf> PrivateDict<'otf> {
+    /// Width of the glyphs whose charstring has no width.
+    pub fn default_width_x(&self) -> f32 {
+        self.default_width_x
+    }
+
+    /// Width added to the width stored in the charstrings.
+    pub fn nominal_width_x(&self) -> f32 {
+        self.nominal_width_x
+    }
+
+    /// Subroutines of the glyphs using this Private DICT.
+    pub fn local_subrs(&self) -> Option<&Index<'otf>> {
+        self.local_subrs.as_ref()
+    }
+}
+
+/// Font DICT of a CID-keyed font.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FontDict<'otf> {
+    font_name: Option<u16>,
+    private_dict: PrivateDict<'otf>
+}
+
+impl<'otf> FontDict<'otf> {
+    /// SID of the name of the Font DICT.
+    pub fn font_name(&self) -> Option<u16> {
+        self.font_name
+    }
+
+    pub fn private_dict(&self) -> &PrivateDict<'otf> {
+        &self.private_dict
+    }
+}
+
+fn parse_header(input: &[u8]) -> IResult<&[u8], (u8, u8, u8)>
+{
+    let (input, major_version) = be_u8(input)?;
+
+    if major_version != 1 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (input, minor_version) = be_u8(input)?;
+    let (input, hdr_size) = be_u8(input)?;
+    // Absolute offsets are encoded as DICT operands in the font, offSize is unused
+    let (input, _off_size) = be_u8(input)?;
+
+    Ok((input, (major_version, minor_version, hdr_size)))
+}
+
+fn slice_at(buf: &[u8], offset: usize) -> Result<&[u8], Error> {
+    buf.get(offset..).ok_or_else(|| Error::new(format!("CFF offset {} out of bounds", offset)))
+}
+
+fn parse_font_dict<'otf>(buf: &'otf[u8], font_dict: &[u8]) -> Result<FontDict<'otf>, Error> {
+    let (_, font_dict) = parse_dict(font_dict)?;
+
+    Ok(FontDict {
+        font_name: font_dict.get_i32(dict::FONT_NAME).map(|sid| sid as u16),
+        private_dict: parse_private_dict(buf, &font_dict)?
+    })
+}
+
+/// Parse the Private DICT referenced by the Private operator of a Top DICT or a Font DICT.
+fn parse_private_dict<'otf>(buf: &'otf[u8], dict: &Dict) -> Result<PrivateDict<'otf>, Error> {
+    let (size, offset) = match dict.get_private() {
+        Some(private) => private,
+        None => return Ok(PrivateDict {
+            default_width_x: 0.0,
+            nominal_width_x: 0.0,
+            local_subrs: None
+        })
+    };
+
+    let private_dict = offset.checked_add(size).and_then(|end| buf.get(offset..end))
+        .ok_or_else(|| Error::new("CFF Private DICT out of bounds"))?;
+    let (_, private_dict) = parse_dict(private_dict)?;
+
+    // The local subroutines offset is relative to the beginning of the Private DICT
+    let local_subrs = match private_dict.get_offset(dict::SUBRS) {
+        Some(subrs_offset) => Some(parse_index(slice_at(buf, offset + subrs_offset)?)?.1),
+        None => None
+    };
+
+    Ok(PrivateDict {
+        default_width_x: private_dict.get_f64(dict::DEFAULT_WIDTH_X).unwrap_or(0.0) as f32,
+        nominal_width_x: private_dict.get_f64(dict::NOMINAL_WIDTH_X).unwrap_or(0.0) as f32,
+        local_subrs
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use outline::tests::PathRecorder;
+
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../../../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    // Name-keyed font: .notdef, A, acute and Aacute made of A and acute with seac
+    static NAME_KEYED_CFF: &[u8] = &[
+        0x01, 0x00, 0x04, 0x04, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x54, 0x65, 0x73, 0x74, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1B,
+        0xF8, 0x1B, 0x02, 0x1D, 0x00, 0x00, 0x00, 0x51, 0x0F, 0x1D, 0x00, 0x00, 0x00, 0x58, 0x11,
+        0x1D, 0x00, 0x00, 0x00, 0x0B, 0x1D, 0x00, 0x00, 0x00, 0x93, 0x12, 0x00, 0x01, 0x04, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0D, 0x54, 0x65, 0x73, 0x74, 0x20, 0x52, 0x65, 0x67,
+        0x75, 0x6C, 0x61, 0x72, 0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x7D, 0x00, 0xAB, 0x00, 0x04,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x1F, 0x00, 0x00, 0x00, 0x25, 0x8B, 0x0E, 0xEF, 0x8B, 0x8B, 0x15, 0xF8, 0x88, 0x06,
+        0xF9, 0x50, 0x07, 0xFB, 0x8E, 0xFD, 0x50, 0x05, 0x0E, 0x27, 0xF7, 0x5C, 0xF9, 0x50, 0x15,
+        0xEF, 0xEF, 0x05, 0x59, 0x06, 0x0E, 0xEF, 0x8B, 0xCC, 0xF7, 0x56, 0x0E, 0x95, 0x14, 0xF8,
+        0x88, 0x15, 0x1D, 0x00, 0x00, 0x00, 0x0B, 0x13, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x04, 0x8B, 0x06, 0x0B];
+
+    // CID-keyed font: glyphs 1 and 2 use the same charstring with different local subroutines
+    static CID_KEYED_CFF: &[u8] = &[
+        0x01, 0x00, 0x04, 0x04, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x54, 0x65, 0x73, 0x74, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x26,
+        0xF8, 0x1B, 0xF8, 0x1C, 0x8B, 0x0C, 0x1E, 0xFA, 0x7E, 0x0C, 0x22, 0x1D, 0x00, 0x00, 0x00,
+        0x75, 0x0F, 0x1D, 0x00, 0x00, 0x00, 0x7A, 0x0C, 0x25, 0x1D, 0x00, 0x00, 0x00, 0xCD, 0x0C,
+        0x24, 0x1D, 0x00, 0x00, 0x00, 0x85, 0x11, 0x00, 0x04, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x0E, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x1A,
+        0x41, 0x64, 0x6F, 0x62, 0x65, 0x49, 0x64, 0x65, 0x6E, 0x74, 0x69, 0x74, 0x79, 0x54, 0x65,
+        0x73, 0x74, 0x2D, 0x30, 0x54, 0x65, 0x73, 0x74, 0x2D, 0x31, 0x00, 0x00, 0x02, 0x03, 0xE8,
+        0x00, 0x01, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00, 0x03, 0x00, 0x03,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x0C, 0x0E, 0x20, 0x0A, 0xEF, 0x06, 0x0E, 0x20, 0x0A, 0xEF, 0x06, 0x0E, 0x1D, 0x00,
+        0x00, 0x00, 0x06, 0x13, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x8B, 0xBD, 0x15, 0x0B, 0x1D, 0x00, 0x00, 0x00, 0x06, 0x13, 0x00, 0x01, 0x04, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x8B, 0x59, 0x15, 0x0B, 0x00, 0x02, 0x04, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x1F, 0xF8, 0x1D, 0x0C, 0x26, 0x1D,
+        0x00, 0x00, 0x00, 0x06, 0x1D, 0x00, 0x00, 0x00, 0xA3, 0x12, 0xF8, 0x1E, 0x0C, 0x26, 0x1D,
+        0x00, 0x00, 0x00, 0x06, 0x1D, 0x00, 0x00, 0x00, 0xB8, 0x12];
+
+    #[test]
+    fn case_compact_font_format_table() {
+        let cff = CompactFontFormatTable::parse(&SOURCE_SERIF_PRO_REGULAR[12892..122408]).unwrap();
+
+        assert_eq!(cff.major_version(), 1);
+        assert_eq!(cff.minor_version(), 0);
+        assert_eq!(cff.name(), Some("SourceSerifPro-Regular"));
+        assert_eq!(cff.num_glyphs(), 1414);
+        assert_eq!(cff.global_subrs().len(), 613);
+        assert_eq!(cff.top_dict().family_name().and_then(|sid| cff.string(sid)), Some("Source Serif Pro"));
+        assert_eq!(cff.top_dict().full_name(), None);
+        assert_eq!(cff.top_dict().font_bbox(), Rect::new(-240.0, -335.0, 2235.0, 1002.0));
+        assert_eq!(cff.top_dict().ros(), None);
+
+        match cff.font_kind() {
+            FontKind::NameKeyed(private_dict) => {
+                assert_eq!(private_dict.nominal_width_x(), 604.0);
+                assert_eq!(private_dict.local_subrs().map(Index::len), Some(613));
+            },
+            _ => assert!(false)
+        }
+
+        assert_eq!(cff.glyph_name(0), Some(".notdef"));
+        assert_eq!(cff.glyph_name(2), Some("A"));
+        assert_eq!(cff.glyph_name(59), Some("Amacron"));
+        assert_eq!(cff.glyph_name(1186), Some("uni1E42.sc"));
+        assert_eq!(cff.glyph_cid(2), None);
+        assert_eq!(cff.glyph_id_by_code(b'A'), Some(2));
+    }
+
+    #[test]
+    fn case_compact_font_format_table_outline() {
+        let cff = CompactFontFormatTable::parse(&SOURCE_SERIF_PRO_REGULAR[12892..122408]).unwrap();
+
+        let mut recorder = PathRecorder::default();
+        assert_eq!(cff.outline(2, &mut recorder).unwrap(), Some(Rect::new(5, 0, 653, 674)));
+        assert_eq!(&recorder.commands[..5], &["M 309 562", "L 409 264", "L 208 264", "Z", "M 5 0"]);
+
+        // Glyph 'space'
+        assert_eq!(cff.outline(1, &mut PathRecorder::default()).unwrap(), None);
+        assert!(cff.outline(1414, &mut PathRecorder::default()).is_err());
+    }
+
+    #[test]
+    fn case_compact_font_format_table_seac() {
+        let cff = CompactFontFormatTable::parse(NAME_KEYED_CFF).unwrap();
+
+        assert_eq!(cff.string(391), Some("Test Regular"));
+        assert_eq!(cff.string(392), None);
+        assert_eq!(cff.glyph_name(3), Some("Aacute"));
+
+        let mut recorder = PathRecorder::default();
+        assert_eq!(cff.outline(3, &mut recorder).unwrap(), Some(Rect::new(0, 0, 500, 800)));
+        assert_eq!(recorder.commands, vec![
+            "M 0 0", "L 500 0", "L 500 700", "L 250 0", "Z",
+            "M 300 700", "L 400 800", "L 350 800", "Z"]);
+    }
+
+    #[test]
+    fn case_compact_font_format_table_cid_keyed() {
+        let cff = CompactFontFormatTable::parse(CID_KEYED_CFF).unwrap();
+
+        assert_eq!(cff.top_dict().ros(), Some((391, 392, 0)));
+        assert_eq!(cff.top_dict().cid_count(), 1002);
+        assert_eq!(cff.glyph_cid(2), Some(1001));
+        assert_eq!(cff.glyph_name(2), None);
+        assert_eq!(cff.glyph_id_by_code(b'A'), None);
+
+        match cff.font_kind() {
+            FontKind::CidKeyed { fd_array, .. } => {
+                let font_names: Vec<Option<&str>> = fd_array.iter()
+                    .map(|font_dict| font_dict.font_name().and_then(|sid| cff.string(sid)))
+                    .collect();
+                assert_eq!(font_names, vec![Some("Test-0"), Some("Test-1")]);
+            },
+            _ => assert!(false)
+        }
+
+        let mut recorder = PathRecorder::default();
+        assert_eq!(cff.outline(1, &mut recorder).unwrap(), Some(Rect::new(0, 50, 100, 50)));
+        assert_eq!(recorder.commands, vec!["M 0 50", "L 100 50", "Z"]);
+
+        let mut recorder = PathRecorder::default();
+        assert_eq!(cff.outline(2, &mut recorder).unwrap(), Some(Rect::new(0, -50, 100, -50)));
+        assert_eq!(recorder.commands, vec!["M 0 -50", "L 100 -50", "Z"]);
+    }
+
+    #[test]
+    fn case_compact_font_format_table_invalid_version() {
+        let bytes: &[u8]  = &[0x02, 0x00, 0x05, 0x00, 0x00];
+
+        assert!(CompactFontFormatTable::parse(bytes).is_err());
+    }
+}
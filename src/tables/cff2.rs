@@ -0,0 +1,371 @@
+use error::Error;
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::number::complete::{be_u8, be_u16};
+use outline::{BoundingBoxBuilder, OutlineBuilder};
+use tables::GlyphId;
+use tables::cff::charstring::CharStringInterpreter;
+use tables::cff::dict::{self, parse_dict};
+use tables::cff::fd_select::{FdSelect, parse_fd_select};
+use tables::cff::index::{Index, parse_index2};
+use tables::variation::{ItemVariationStore, parse_item_variation_store};
+use types::{F2Dot14, Rect};
+
+/// Compact Font Format (CFF) Version 2
+///
+/// This table contains a CFF2 font representation, the version of the Compact Font Format
+/// used by variable OpenType fonts with PostScript outlines. The glyph outlines are described
+/// by Type 2 charstrings whose arguments may be interpolated with the blend operator.
+///
+/// Compared to the 'CFF ' table, the Name INDEX, the String INDEX, the encoding and the charset
+/// are removed, the names being provided by the 'name' and 'post' tables, and the fonts are
+/// always organized with Font DICTs. The CFF2 data is made of a header followed by the Top DICT
+/// and the Global Subr INDEX, the other structures (CharStrings INDEX, VariationStore, FDArray
+/// and FDSelect) being located with offsets stored in the Top DICT.
+///
+/// More information on ['CFF2'](https://docs.microsoft.com/en-gb/typography/opentype/spec/cff2)
+pub struct CompactFontFormat2Table<'otf> {
+    major_version: u8,
+    minor_version: u8,
+    font_matrix: [f32; 6],
+    global_subrs: Index<'otf>,
+    char_strings: Index<'otf>,
+    variation_store: Option<ItemVariationStore>,
+    fd_array: Vec<PrivateDict<'otf>>,
+    fd_select: Option<FdSelect>
+}
+
+impl<'otf> CompactFontFormat2Table<'otf> {
+    /// Format major version (set to 2).
+    pub fn major_version(&self) -> u8 {
+        self.major_version
+    }
+
+    /// Format minor version (set to 0).
+    pub fn minor_version(&self) -> u8 {
+        self.minor_version
+    }
+
+    /// Transformation from glyph space to text space, [0.001 0 0 0.001 0 0] by default.
+    pub fn font_matrix(&self) -> [f32; 6] {
+        self.font_matrix
+    }
+
+    /// Subroutines shared by all the glyphs of the font.
+    pub fn global_subrs(&self) -> &Index<'otf> {
+        &self.global_subrs
+    }
+
+    /// Charstrings of the glyphs, indexed by glyph ID.
+    pub fn char_strings(&self) -> &Index<'otf> {
+        &self.char_strings
+    }
+
+    /// Number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u16 {
+        self.char_strings.len() as u16
+    }
+
+    /// Variation data referenced by the blend operators of the charstrings and the Private DICTs.
+    pub fn variation_store(&self) -> Option<&ItemVariationStore> {
+        self.variation_store.as_ref()
+    }
+
+    /// Private DICTs of the Font DICTs, in the order of the FDArray.
+    pub fn fd_array(&self) -> &[PrivateDict<'otf>] {
+        &self.fd_array
+    }
+
+    /// Associates the glyphs with a Font DICT, None if the font has a single Font DICT.
+    pub fn fd_select(&self) -> Option<&FdSelect> {
+        self.fd_select.as_ref()
+    }
+
+    /// Private DICT used by the charstring of the given glyph.
+    pub fn private_dict(&self, glyph_id: GlyphId) -> Option<&PrivateDict<'otf>> {
+        let fd = match self.fd_select {
+            Some(ref fd_select) => fd_select.font_dict_index(glyph_id)?,
+            None => 0
+        };
+
+        self.fd_array.get(usize::from(fd))
+    }
+
+    /// Interpret the charstring of the given glyph at the given variation coordinates and send
+    /// its outline to the builder.
+    ///
+    /// * `coordinates` - Normalized coordinates of the instance, in the order of the axes of
+    ///   the 'fvar' table. An empty slice selects the default instance.
+    ///
+    /// Return the bounding box of the outline, None if the glyph has no outline (e.g. the space
+    /// character), or an error if the glyph ID is out of bounds or the charstring is malformed.
+    pub fn outline<B: OutlineBuilder>(&self, glyph_id: GlyphId, coordinates: &[F2Dot14],
+                                      builder: &mut B) -> Result<Option<Rect<i16>>, Error> {
+        let char_string = self.char_strings.get(u32::from(glyph_id))
+            .ok_or_else(|| Error::new(format!("Glyph {} out of bounds", glyph_id)))?;
+        let private_dict = self.private_dict(glyph_id);
+        let local_subrs = private_dict.and_then(|private_dict| private_dict.local_subrs.as_ref());
+        let vsindex = private_dict.map(PrivateDict::vsindex).unwrap_or(0);
+
+        let mut bounding_box_builder = BoundingBoxBuilder::new(builder);
+
+        CharStringInterpreter::new_cff2(&self.global_subrs, local_subrs, &mut bounding_box_builder, 0.0, 0.0,
+                                        self.variation_store.as_ref(), coordinates, vsindex)
+            .run(char_string)?;
+
+        Ok(bounding_box_builder.bounding_box())
+    }
+
+    /// Parse Compact Font Format (CFF) Version 2 Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::cff2::CompactFontFormat2Table;
+    /// use otf::types::Rect;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x02, 0x00, 0x05, 0x00, 0x1A, 0x1D, 0x00, 0x00, 0x00, 0x78, 0x11, 0x1D, 0x00, 0x00, 0x00,
+    ///     0x29, 0x18, 0x1D, 0x00, 0x00, 0x00, 0xAE, 0x0C, 0x24, 0x1D, 0x00, 0x00, 0x00, 0x5D, 0x0C,
+    ///     0x25, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x04, 0x8B, 0x8B, 0x15, 0x00, 0x32, 0x00, 0x01,
+    ///     0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x2A, 0x00,
+    ///     0x01, 0x00, 0x02, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x01, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    ///     0x00, 0x00, 0x00, 0x04, 0x01, 0x01, 0x01, 0x0B, 0x12, 0x1B, 0x20, 0x1D, 0xEF, 0xBD, 0x77,
+    ///     0x8C, 0x10, 0x06, 0xEF, 0x07, 0x20, 0x0A, 0xEF, 0xBD, 0x8C, 0x10, 0x06, 0x8C, 0x0F, 0x20,
+    ///     0x1D, 0xEF, 0xBD, 0x8C, 0x10, 0x06, 0x8C, 0x16, 0x1D, 0x00, 0x00, 0x00, 0x08, 0x13, 0x00,
+    ///     0x00, 0x00, 0x01, 0x01, 0x01, 0x04, 0x8B, 0x8B, 0x15, 0x00, 0x00, 0x00, 0x02, 0x01, 0x01,
+    ///     0x0C, 0x17, 0x1D, 0x00, 0x00, 0x00, 0x00, 0x1D, 0x00, 0x00, 0x00, 0x9C, 0x12, 0x1D, 0x00,
+    ///     0x00, 0x00, 0x08, 0x1D, 0x00, 0x00, 0x00, 0x9C, 0x12];
+    ///
+    /// let cff2 = CompactFontFormat2Table::parse(bytes).unwrap();
+    ///
+    /// assert_eq!(cff2.num_glyphs(), 4);
+    ///
+    /// struct NoOutline;
+    ///
+    /// impl otf::outline::OutlineBuilder for NoOutline {
+    ///     fn move_to(&mut self, _x: f32, _y: f32) {}
+    ///     fn line_to(&mut self, _x: f32, _y: f32) {}
+    ///     fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+    ///     fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+    ///     fn close(&mut self) {}
+    /// }
+    ///
+    /// // Default instance and instance at the half of the axis
+    /// assert_eq!(cff2.outline(1, &[], &mut NoOutline).unwrap(), Some(Rect::new(0, 0, 100, 100)));
+    /// assert_eq!(cff2.outline(1, &[0x2000], &mut NoOutline).unwrap(), Some(Rect::new(0, 0, 125, 100)));
+    /// ```
+    pub fn parse(buf: &'otf[u8]) -> Result<CompactFontFormat2Table<'otf>, Error> {
+        let (_, (major_version, minor_version, header_size, top_dict_length)) = parse_header(buf)?;
+
+        let top_dict_start = usize::from(header_size);
+        let top_dict_end = top_dict_start + usize::from(top_dict_length);
+        let top_dict = buf.get(top_dict_start..top_dict_end).ok_or_else(|| Error::new("CFF2 Top DICT out of bounds"))?;
+        let (_, top_dict) = parse_dict(top_dict)?;
+
+        // The Global Subr INDEX immediately follows the Top DICT
+        let (_, global_subrs) = parse_index2(&buf[top_dict_end..])?;
+
+        let char_strings_offset = top_dict.get_offset(dict::CHAR_STRINGS)
+            .ok_or_else(|| Error::new("CFF2 CharStrings INDEX missing"))?;
+        let (_, char_strings) = parse_index2(slice_at(buf, char_strings_offset)?)?;
+
+        if char_strings.len() > u32::from(u16::MAX) {
+            return Err(Error::new(format!("Invalid number of glyphs {}", char_strings.len())));
+        }
+
+        let num_glyphs = char_strings.len() as u16;
+
+        // The VariationStore is preceded by its 16-bit length
+        let variation_store = match top_dict.get_offset(dict::VSTORE) {
+            Some(offset) => Some(parse_item_variation_store(slice_at(buf, offset + 2)?)?.1),
+            None => None
+        };
+
+        let fd_array_offset = top_dict.get_offset(dict::FD_ARRAY)
+            .ok_or_else(|| Error::new("CFF2 FDArray missing"))?;
+        let (_, fd_array_index) = parse_index2(slice_at(buf, fd_array_offset)?)?;
+        let fd_array = fd_array_index.iter()
+            .map(|font_dict| parse_font_dict(buf, font_dict))
+            .collect::<Result<Vec<PrivateDict>, Error>>()?;
+
+        // The FDSelect is only required when the FDArray has more than one Font DICT
+        let fd_select = match top_dict.get_offset(dict::FD_SELECT) {
+            Some(offset) => Some(parse_fd_select(slice_at(buf, offset)?, num_glyphs)?.1),
+            None => None
+        };
+
+        let font_matrix = match top_dict.get(dict::FONT_MATRIX) {
+            Some(operands) if operands.len() == 6 => {
+                let mut font_matrix = [0.0; 6];
+
+                for (value, operand) in font_matrix.iter_mut().zip(operands) {
+                    *value = operand.as_f64() as f32;
+                }
+
+                font_matrix
+            },
+            _ => [0.001, 0.0, 0.0, 0.001, 0.0, 0.0]
+        };
+
+        Ok(CompactFontFormat2Table {
+            major_version,
+            minor_version,
+            font_matrix,
+            global_subrs,
+            char_strings,
+            variation_store,
+            fd_array,
+            fd_select
+        })
+    }
+}
+
+/// Private DICT
+///
+/// Hinting data of the glyphs, along with the local subroutines. Unlike the CFF Private DICT,
+/// it holds no widths, which are provided by the 'hmtx' and 'HVAR' tables.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PrivateDict<'otf> {
+    vsindex: u16,
+    local_subrs: Option<Index<'otf>>
+}
+
+impl<'otf> PrivateDict<'otf> {
+    /// Index of the ItemVariationData subtable used by the blend operators, unless the
+    /// charstring selects another subtable with the vsindex operator.
+    pub fn vsindex(&self) -> u16 {
+        self.vsindex
+    }
+
+    /// Subroutines of the glyphs using this Private DICT.
+    pub fn local_subrs(&self) -> Option<&Index<'otf>> {
+        self.local_subrs.as_ref()
+    }
+}
+
+fn parse_header(input: &[u8]) -> IResult<&[u8], (u8, u8, u8, u16)>
+{
+    let (input, major_version) = be_u8(input)?;
+
+    if major_version != 2 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (input, minor_version) = be_u8(input)?;
+    let (input, header_size) = be_u8(input)?;
+    let (input, top_dict_length) = be_u16(input)?;
+
+    Ok((input, (major_version, minor_version, header_size, top_dict_length)))
+}
+
+fn slice_at(buf: &[u8], offset: usize) -> Result<&[u8], Error> {
+    buf.get(offset..).ok_or_else(|| Error::new(format!("CFF2 offset {} out of bounds", offset)))
+}
+
+/// Parse a Font DICT, whose only entry is the Private DICT.
+fn parse_font_dict<'otf>(buf: &'otf[u8], font_dict: &[u8]) -> Result<PrivateDict<'otf>, Error> {
+    let (_, font_dict) = parse_dict(font_dict)?;
+
+    let (size, offset) = font_dict.get_private()
+        .ok_or_else(|| Error::new("CFF2 Private DICT missing"))?;
+
+    let private_dict = offset.checked_add(size).and_then(|end| buf.get(offset..end))
+        .ok_or_else(|| Error::new("CFF2 Private DICT out of bounds"))?;
+    let (_, private_dict) = parse_dict(private_dict)?;
+
+    // The local subroutines offset is relative to the beginning of the Private DICT
+    let local_subrs = match private_dict.get_offset(dict::SUBRS) {
+        Some(subrs_offset) => Some(parse_index2(slice_at(buf, offset + subrs_offset)?)?.1),
+        None => None
+    };
+
+    Ok(PrivateDict {
+        vsindex: private_dict.get_i32(dict::VSINDEX).unwrap_or(0) as u16,
+        local_subrs
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use outline::tests::PathRecorder;
+
+    // Variable font with one axis and two regions, (0, 1, 1) and (-1, -1, 0). Glyph 1 blends
+    // its first line with both regions, glyphs 2 and 3 blend their line with the second region
+    // only, selected by the Private DICT of the second Font DICT and by the vsindex operator.
+    static VARIABLE_CFF2: &[u8] = &[
+        0x02, 0x00, 0x05, 0x00, 0x1A, 0x1D, 0x00, 0x00, 0x00, 0x78, 0x11, 0x1D, 0x00, 0x00, 0x00,
+        0x29, 0x18, 0x1D, 0x00, 0x00, 0x00, 0xAE, 0x0C, 0x24, 0x1D, 0x00, 0x00, 0x00, 0x5D, 0x0C,
+        0x25, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x04, 0x8B, 0x8B, 0x15, 0x00, 0x32, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x2A, 0x00,
+        0x01, 0x00, 0x02, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x01, 0x01, 0x01, 0x0B, 0x12, 0x1B, 0x20, 0x1D, 0xEF, 0xBD, 0x77,
+        0x8C, 0x10, 0x06, 0xEF, 0x07, 0x20, 0x0A, 0xEF, 0xBD, 0x8C, 0x10, 0x06, 0x8C, 0x0F, 0x20,
+        0x1D, 0xEF, 0xBD, 0x8C, 0x10, 0x06, 0x8C, 0x16, 0x1D, 0x00, 0x00, 0x00, 0x08, 0x13, 0x00,
+        0x00, 0x00, 0x01, 0x01, 0x01, 0x04, 0x8B, 0x8B, 0x15, 0x00, 0x00, 0x00, 0x02, 0x01, 0x01,
+        0x0C, 0x17, 0x1D, 0x00, 0x00, 0x00, 0x00, 0x1D, 0x00, 0x00, 0x00, 0x9C, 0x12, 0x1D, 0x00,
+        0x00, 0x00, 0x08, 0x1D, 0x00, 0x00, 0x00, 0x9C, 0x12];
+
+    fn outline(cff2: &CompactFontFormat2Table, glyph_id: GlyphId, coordinates: &[F2Dot14]) -> Vec<String> {
+        let mut recorder = PathRecorder::default();
+        cff2.outline(glyph_id, coordinates, &mut recorder).unwrap();
+        recorder.commands
+    }
+
+    #[test]
+    fn case_compact_font_format_2_table() {
+        let cff2 = CompactFontFormat2Table::parse(VARIABLE_CFF2).unwrap();
+
+        assert_eq!(cff2.major_version(), 2);
+        assert_eq!(cff2.minor_version(), 0);
+        assert_eq!(cff2.font_matrix(), [0.001, 0.0, 0.0, 0.001, 0.0, 0.0]);
+        assert_eq!(cff2.num_glyphs(), 4);
+        assert_eq!(cff2.global_subrs().len(), 1);
+        assert_eq!(cff2.fd_array().len(), 2);
+        assert_eq!(cff2.private_dict(1).map(PrivateDict::vsindex), Some(0));
+        assert_eq!(cff2.private_dict(2).map(PrivateDict::vsindex), Some(1));
+        assert_eq!(cff2.private_dict(2).and_then(PrivateDict::local_subrs).map(Index::len), Some(1));
+        assert_eq!(cff2.private_dict(4), None);
+
+        let variation_store = cff2.variation_store().unwrap();
+        assert_eq!(variation_store.variation_region_list().axis_count(), 1);
+        assert_eq!(variation_store.item_variation_data().len(), 2);
+    }
+
+    #[test]
+    fn case_compact_font_format_2_table_blend() {
+        let cff2 = CompactFontFormat2Table::parse(VARIABLE_CFF2).unwrap();
+
+        assert_eq!(outline(&cff2, 1, &[]), vec!["M 0 0", "L 100 0", "L 100 100", "Z"]);
+        assert_eq!(outline(&cff2, 1, &[0x2000]), vec!["M 0 0", "L 125 0", "L 125 100", "Z"]);
+        assert_eq!(outline(&cff2, 1, &[-0x2000]), vec!["M 0 0", "L 90 0", "L 90 100", "Z"]);
+        assert_eq!(outline(&cff2, 1, &[-0x4000]), vec!["M 0 0", "L 80 0", "L 80 100", "Z"]);
+
+        // The second region only applies to the negative side of the axis
+        for glyph_id in 2..4 {
+            assert_eq!(outline(&cff2, glyph_id, &[0x2000]), vec!["M 0 0", "L 100 0", "Z"]);
+            assert_eq!(outline(&cff2, glyph_id, &[-0x2000]), vec!["M 0 0", "L 125 0", "Z"]);
+        }
+
+        // Glyph '.notdef' has an empty charstring
+        assert_eq!(cff2.outline(0, &[], &mut PathRecorder::default()).unwrap(), None);
+        assert!(cff2.outline(4, &[], &mut PathRecorder::default()).is_err());
+    }
+
+    #[test]
+    fn case_compact_font_format_2_table_invalid_version() {
+        let bytes: &[u8]  = &[0x01, 0x00, 0x05, 0x00, 0x00];
+
+        assert!(CompactFontFormat2Table::parse(bytes).is_err());
+    }
+}
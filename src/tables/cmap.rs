@@ -5,9 +5,12 @@ use nom::error::ErrorKind;
 use nom::combinator::{map_res, verify};
 use nom::multi::count;
 use nom::number::complete::{be_u8, be_i16, be_u16, be_u24, be_u32};
+use error::Error;
 use types::Offset32;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::ops::RangeInclusive;
 use super::GlyphId;
 
 /// This table defines mapping of character codes to a default glyph index. Different subtables may
@@ -36,37 +39,98 @@ use super::GlyphId;
 ///
 /// More information on ['cmap'](https://docs.microsoft.com/en-gb/typography/opentype/spec/cmap)
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct CharacterGlyphIndexMappingTable {
+pub struct CharacterGlyphIndexMappingTable<'otf> {
+    buf: &'otf[u8],
     num_tables: u16
 }
 
-impl<'otf> CharacterGlyphIndexMappingTable {
+/// Unicode encodings by order of preference: full repertoire encodings first, then BMP only
+/// encodings and finally the symbol encodings.
+const UNICODE_ENCODINGS: &[(u16, u16)] = &[
+    (PLATFORM_WINDOWS, 10), (PLATFORM_UNICODE, 6), (PLATFORM_UNICODE, 4), (PLATFORM_WINDOWS, 1),
+    (PLATFORM_UNICODE, 3), (PLATFORM_UNICODE, 2), (PLATFORM_UNICODE, 1), (PLATFORM_UNICODE, 0),
+    (PLATFORM_WINDOWS, 0), (PLATFORM_MACINTOSH, 0)
+];
+
+const PLATFORM_UNICODE: u16 = 0;
+const PLATFORM_MACINTOSH: u16 = 1;
+const PLATFORM_WINDOWS: u16 = 3;
+
+/// Last code point of the Unicode codespace.
+const MAX_UNICODE_CODEPOINT: u32 = 0x10FFFF;
+
+impl<'otf> CharacterGlyphIndexMappingTable<'otf> {
+    /// Number of encoding tables that follow.
     pub fn num_tables(&self) -> u16 {
         self.num_tables
     }
-}
 
-impl_parse!(
+    /// Encoding records of the table.
+    pub fn encoding_records(&self) -> EncodingRecords<'otf> {
+        EncodingRecords {
+            buf: &self.buf[4..],
+            num_tables: self.num_tables
+        }
+    }
+
+    /// Subtable referenced by the given encoding record.
+    pub fn subtable(&self, encoding_record: &EncodingRecord) -> Result<CharacterGlyphIndexMappingSubtable<'otf>, Error> {
+        let offset = encoding_record.offset() as usize;
+        let buf = self.buf.get(offset..).ok_or_else(|| Error::new(format!("Invalid cmap subtable offset {}", offset)))?;
+
+        Ok(parse_character_to_glyph_index_mapping_subtable(buf)?.1)
+    }
+
+    /// Subtable to use to map Unicode characters, following the standard preference order:
+    /// (3, 10) > (0, 6) > (0, 4) > (3, 1) > (0, 3) > (0, 2) > (0, 1) > (0, 0) > (3, 0) > (1, 0).
+    ///
+    /// Encoding records whose subtable cannot be parsed are ignored.
+    pub fn best_unicode_subtable(&self) -> Option<CharacterGlyphIndexMappingSubtable<'otf>> {
+        let encoding_records: Vec<EncodingRecord> = self.encoding_records().iter().collect();
+
+        UNICODE_ENCODINGS.iter()
+            .flat_map(|&(platform_id, encoding_id)| encoding_records.iter()
+                .filter(move |encoding_record| encoding_record.platform_id() == platform_id
+                    && encoding_record.encoding_id() == encoding_id))
+            .filter_map(|encoding_record| self.subtable(encoding_record).ok())
+            .find(|subtable| !matches!(subtable, CharacterGlyphIndexMappingSubtable::Format_14(_)))
+    }
+
     /// Parse Character to Glyph Index Mapping Table.
     ///
     /// # Example
     ///
     /// ```
-    /// // TODO
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::tables::TableTag;
+    /// use otf::tables::cmap::CharacterGlyphIndexMappingTable;
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let cmap = CharacterGlyphIndexMappingTable::parse(font.table(TableTag::Cmap).unwrap().as_slice().unwrap()).unwrap();
+    ///
+    /// assert_eq!(cmap.num_tables(), 3);
+    ///
+    /// let subtable = cmap.best_unicode_subtable().unwrap();
+    /// assert_eq!(subtable.get_glyph_id(u32::from('A')), Some(37));
     /// ```
-    CharacterGlyphIndexMappingTable, parse_character_glyph_index_mapping_table
-);
+    pub fn parse(buf: &'otf[u8]) -> Result<CharacterGlyphIndexMappingTable<'otf>, Error> {
+        Ok(parse_character_glyph_index_mapping_table(buf)?.1)
+    }
+}
 
 pub struct EncodingRecords<'otf> {
     buf: &'otf[u8],
-    table: CharacterGlyphIndexMappingTable
+    num_tables: u16
 }
 
 impl<'otf> EncodingRecords<'otf> {
     pub fn iter(&self) -> EncodingRecordsIterator<'otf> {
         EncodingRecordsIterator {
             buf: self.buf,
-            num_tables: self.table.num_tables(),
+            num_tables: self.num_tables,
             pos: 0
         }
     }
@@ -146,14 +210,14 @@ impl EncodingRecord {
 #[allow(non_camel_case_types)]
 pub enum CharacterGlyphIndexMappingSubtable<'otf> {
     Format_0(CharacterGlyphIndexMappingSubtable0<'otf>),
-    Format_2(CharacterGlyphIndexMappingSubtable2),
+    Format_2(CharacterGlyphIndexMappingSubtable2<'otf>),
     Format_4(CharacterGlyphIndexMappingSubtable4<'otf>),
     Format_6(CharacterGlyphIndexMappingSubtable6<'otf>),
     Format_8(CharacterGlyphIndexMappingSubtable8<'otf>),
     Format_10(CharacterGlyphIndexMappingSubtable10<'otf>),
     Format_12(CharacterGlyphIndexMappingSubtable12),
     Format_13(CharacterGlyphIndexMappingSubtable13),
-    Format_14(CharacterGlyphIndexMappingSubtable14)
+    Format_14(CharacterGlyphIndexMappingSubtable14<'otf>)
 }
 
 impl<'otf> CharacterGlyphIndexMappingSubtable<'otf> {
@@ -171,8 +235,13 @@ impl<'otf> CharacterGlyphIndexMappingSubtable<'otf> {
         }
     }
 
+    /// Glyph mapped to the given character code.
+    ///
+    /// Return None if the character code is not mapped by the subtable or is mapped to the
+    /// missing glyph (glyph index 0). Format 14 subtables map variation sequences rather than
+    /// character codes and always return None.
     pub fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
-        match self {
+        let glyph_id = match self {
             CharacterGlyphIndexMappingSubtable::Format_0(subtable) => {
                 if character_code > u32::from(u8::max_value()) {
                     return None;
@@ -180,8 +249,20 @@ impl<'otf> CharacterGlyphIndexMappingSubtable<'otf> {
 
                 Some(subtable.get_glyph_id(character_code as u8))
             },
-            CharacterGlyphIndexMappingSubtable::Format_2(_subtable) => None,
-            CharacterGlyphIndexMappingSubtable::Format_4(_subtable) => None,
+            CharacterGlyphIndexMappingSubtable::Format_2(subtable) => {
+                if character_code > u32::from(u16::max_value()) {
+                    return None;
+                }
+
+                subtable.get_glyph_id(character_code as u16)
+            },
+            CharacterGlyphIndexMappingSubtable::Format_4(subtable) => {
+                if character_code > u32::from(u16::max_value()) {
+                    return None;
+                }
+
+                subtable.get_glyph_id(character_code as u16)
+            },
             CharacterGlyphIndexMappingSubtable::Format_6(subtable) => {
                 if character_code > u32::from(u16::max_value()) {
                     return None;
@@ -189,12 +270,14 @@ impl<'otf> CharacterGlyphIndexMappingSubtable<'otf> {
 
                 subtable.get_glyph_id(character_code as u16)
             },
-            CharacterGlyphIndexMappingSubtable::Format_8(_subtable) => None,
-            CharacterGlyphIndexMappingSubtable::Format_10(_subtable) => None,
-            CharacterGlyphIndexMappingSubtable::Format_12(_subtable) => None,
-            CharacterGlyphIndexMappingSubtable::Format_13(_subtable) => None,
+            CharacterGlyphIndexMappingSubtable::Format_8(subtable) => subtable.get_glyph_id(character_code),
+            CharacterGlyphIndexMappingSubtable::Format_10(subtable) => subtable.get_glyph_id(character_code),
+            CharacterGlyphIndexMappingSubtable::Format_12(subtable) => subtable.get_glyph_id(character_code),
+            CharacterGlyphIndexMappingSubtable::Format_13(subtable) => subtable.get_glyph_id(character_code),
             CharacterGlyphIndexMappingSubtable::Format_14(_subtable) => None
-        }
+        };
+
+        glyph_id.filter(|&glyph_id| glyph_id != 0)
     }
 
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
@@ -210,6 +293,273 @@ impl<'otf> CharacterGlyphIndexMappingSubtable<'otf> {
             CharacterGlyphIndexMappingSubtable::Format_14(subtable) => subtable.mapping(),
         }
     }
+
+    /// Inverse of the mapping: the character codes mapped to each glyph, in increasing order.
+    ///
+    /// The missing glyph (glyph index 0) is not part of the mapping. Formats 4, 8, 12 and 13 are
+    /// inverted from their segments and groups directly.
+    pub fn reverse_mapping(&self) -> HashMap<GlyphId, Vec<u32>> {
+        match self {
+            CharacterGlyphIndexMappingSubtable::Format_4(subtable) => subtable.reverse_mapping(),
+            CharacterGlyphIndexMappingSubtable::Format_8(subtable) => subtable.reverse_mapping(),
+            CharacterGlyphIndexMappingSubtable::Format_12(subtable) => subtable.reverse_mapping(),
+            CharacterGlyphIndexMappingSubtable::Format_13(subtable) => subtable.reverse_mapping(),
+            _ => {
+                let mut reverse_mapping: HashMap<GlyphId, Vec<u32>> = HashMap::new();
+                for (character_code, glyph_id) in self.mapping() {
+                    if glyph_id != 0 {
+                        reverse_mapping.entry(glyph_id).or_default().push(character_code);
+                    }
+                }
+
+                for character_codes in reverse_mapping.values_mut() {
+                    character_codes.sort_unstable();
+                }
+                reverse_mapping
+            }
+        }
+    }
+
+    /// Iterator over the character codes of the subtable and the glyphs they are mapped to,
+    /// read lazily from the segments, groups or arrays of the subtable.
+    ///
+    /// Character codes mapped to the missing glyph (glyph index 0) are skipped. Character codes
+    /// are yielded in increasing order, except for format 2 where single-byte and 2-byte
+    /// characters are interleaved in the order of their first byte.
+    pub fn iter<'a>(&'a self) -> MappingIterator<'a, 'otf> {
+        MappingIterator {
+            subtable: self,
+            range_index: 0,
+            character_code: 1,
+            end_character_code: 0
+        }
+    }
+
+    /// Iterator over the character codes mapped to a glyph by the subtable.
+    pub fn codepoints<'a>(&'a self) -> CodepointIterator<'a, 'otf> {
+        CodepointIterator {
+            mapping: self.iter()
+        }
+    }
+
+    /// Ranges of contiguous character codes mapped to a glyph by the subtable, sorted by
+    /// character code.
+    ///
+    /// The ranges are built from the segments and groups of the subtable, and only the character
+    /// codes of a range that may be mapped to the missing glyph are looked up one by one.
+    /// Character codes of formats 12 and 13 are limited to the Unicode range (up to U+10FFFF).
+    pub fn coverage(&self) -> Vec<RangeInclusive<u32>> {
+        let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+        let mut range_index = 0;
+        while let Some((start_code, end_code)) = self.character_code_range(range_index) {
+            if start_code <= end_code {
+                self.range_coverage(range_index, start_code, end_code, &mut ranges);
+            }
+            range_index += 1;
+        }
+
+        // Format 2 ranges follow the order of the first byte and groups may not be sorted
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        let mut coverage: Vec<RangeInclusive<u32>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            push_range(&mut coverage, *range.start(), *range.end());
+        }
+        coverage
+    }
+
+    /// Append the covered character codes of the range at the given index.
+    fn range_coverage(&self, range_index: usize, start_code: u32, end_code: u32, ranges: &mut Vec<RangeInclusive<u32>>) {
+        match self {
+            CharacterGlyphIndexMappingSubtable::Format_4(subtable) if subtable.id_range_offset()[range_index] == 0 => {
+                // The glyph is the character code plus idDelta, so a single character code of the
+                // segment may be mapped to the missing glyph
+                let missing_code = u32::from(0u16.wrapping_sub(subtable.id_delta()[range_index] as u16));
+                if missing_code < start_code || missing_code > end_code {
+                    ranges.push(start_code..=end_code);
+                } else {
+                    if missing_code > start_code {
+                        ranges.push(start_code..=missing_code - 1);
+                    }
+                    if missing_code < end_code {
+                        ranges.push(missing_code + 1..=end_code);
+                    }
+                }
+            },
+            CharacterGlyphIndexMappingSubtable::Format_6(subtable) if has_no_missing_glyph(subtable.glyph_id_array()) => {
+                ranges.push(start_code..=end_code);
+            },
+            CharacterGlyphIndexMappingSubtable::Format_8(subtable) => {
+                sequential_group_coverage(&subtable.groups()[range_index], end_code, ranges);
+            },
+            CharacterGlyphIndexMappingSubtable::Format_10(subtable) if has_no_missing_glyph(subtable.glyphs()) => {
+                ranges.push(start_code..=end_code);
+            },
+            CharacterGlyphIndexMappingSubtable::Format_12(subtable) => {
+                sequential_group_coverage(&subtable.groups()[range_index], end_code.min(MAX_UNICODE_CODEPOINT), ranges);
+            },
+            CharacterGlyphIndexMappingSubtable::Format_13(subtable) => {
+                let glyph_id = subtable.groups()[range_index].glyph_id();
+                if glyph_id != 0 && GlyphId::try_from(glyph_id).is_ok() && start_code <= MAX_UNICODE_CODEPOINT {
+                    ranges.push(start_code..=end_code.min(MAX_UNICODE_CODEPOINT));
+                }
+            },
+            _ => {
+                // Look up the glyph of each character code, merging contiguous ones on the fly
+                for character_code in start_code..=end_code {
+                    if self.range_glyph_id(range_index, character_code).filter(|&glyph_id| glyph_id != 0).is_some() {
+                        push_range(ranges, character_code, character_code);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Character codes of the range at the given index, that is a segment or a group of the
+    /// subtable. The range is empty if its start is greater than its end.
+    fn character_code_range(&self, range_index: usize) -> Option<(u32, u32)> {
+        match self {
+            CharacterGlyphIndexMappingSubtable::Format_0(_) if range_index == 0 => Some((0, 255)),
+            CharacterGlyphIndexMappingSubtable::Format_0(_) => None,
+            CharacterGlyphIndexMappingSubtable::Format_2(subtable) => {
+                // One range per first byte
+                let sub_header_index = subtable.sub_header_index(u16::try_from(range_index).ok()?)?;
+                let high_byte = range_index as u32;
+
+                if sub_header_index == 0 {
+                    return Some((high_byte, high_byte));
+                }
+
+                match subtable.sub_headers().get(sub_header_index) {
+                    Some(sub_header) if sub_header.entry_count() > 0 => {
+                        let first_code = u32::from(sub_header.first_code());
+                        let last_code = (first_code + u32::from(sub_header.entry_count()) - 1).min(0xFF);
+                        Some((high_byte << 8 | first_code, high_byte << 8 | last_code))
+                    },
+                    _ => Some((1, 0))
+                }
+            },
+            CharacterGlyphIndexMappingSubtable::Format_4(subtable) => {
+                let start_code = *subtable.start_code().get(range_index)?;
+                let end_code = *subtable.end_code().get(range_index)?;
+                Some((u32::from(start_code), u32::from(end_code)))
+            },
+            CharacterGlyphIndexMappingSubtable::Format_6(subtable) if range_index == 0 && subtable.entry_count() > 0 => {
+                let first_code = u32::from(subtable.first_code());
+                Some((first_code, first_code + u32::from(subtable.entry_count()) - 1))
+            },
+            CharacterGlyphIndexMappingSubtable::Format_6(_) => None,
+            CharacterGlyphIndexMappingSubtable::Format_8(subtable) => subtable.groups().get(range_index)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            CharacterGlyphIndexMappingSubtable::Format_10(subtable) if range_index == 0 && subtable.glyphs().len() >= 2 => {
+                let start_char_code = subtable.start_char_code();
+                Some((start_char_code, start_char_code.saturating_add((subtable.glyphs().len() / 2 - 1) as u32)))
+            },
+            CharacterGlyphIndexMappingSubtable::Format_10(_) => None,
+            CharacterGlyphIndexMappingSubtable::Format_12(subtable) => subtable.groups().get(range_index)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            CharacterGlyphIndexMappingSubtable::Format_13(subtable) => subtable.groups().get(range_index)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            CharacterGlyphIndexMappingSubtable::Format_14(_) => None
+        }
+    }
+
+    /// Glyph mapped to a character code of the range at the given index.
+    fn range_glyph_id(&self, range_index: usize, character_code: u32) -> Option<GlyphId> {
+        match self {
+            CharacterGlyphIndexMappingSubtable::Format_4(subtable) => subtable.segment_glyph_id(range_index, character_code as u16),
+            CharacterGlyphIndexMappingSubtable::Format_8(subtable) => subtable.groups()[range_index].get_glyph_id(character_code),
+            CharacterGlyphIndexMappingSubtable::Format_12(subtable) => subtable.groups()[range_index].get_glyph_id(character_code),
+            CharacterGlyphIndexMappingSubtable::Format_13(subtable) => GlyphId::try_from(subtable.groups()[range_index].glyph_id()).ok(),
+            _ => self.get_glyph_id(character_code)
+        }
+    }
+}
+
+/// Append a range of character codes, extending the last range if they are contiguous.
+fn push_range(ranges: &mut Vec<RangeInclusive<u32>>, start_code: u32, end_code: u32) {
+    match ranges.last_mut() {
+        Some(range) if u64::from(*range.end()) + 1 >= u64::from(start_code) => {
+            if end_code > *range.end() {
+                *range = *range.start()..=end_code;
+            }
+        },
+        _ => ranges.push(start_code..=end_code)
+    }
+}
+
+/// Whether an array of 16-bit glyph indices has no missing glyph (glyph index 0).
+fn has_no_missing_glyph(glyph_ids: &[u8]) -> bool {
+    glyph_ids.chunks(2).all(|bytes| bytes.iter().any(|&byte| byte != 0))
+}
+
+/// Append the character codes of a sequential map group up to the given end, leaving out a
+/// first character code mapped to the missing glyph and the ones past the last glyph index.
+fn sequential_group_coverage(group: &SequentialMapGroup, end_code: u32, ranges: &mut Vec<RangeInclusive<u32>>) {
+    let start_code = group.start_char_code();
+    let start_glyph_id = group.start_glyph_id();
+    if start_code > end_code || start_glyph_id > u32::from(GlyphId::MAX) {
+        return;
+    }
+
+    let end_code = end_code.min(start_code.saturating_add(u32::from(GlyphId::MAX) - start_glyph_id));
+    let start_code = if start_glyph_id == 0 {
+        match start_code.checked_add(1) {
+            Some(start_code) => start_code,
+            None => return
+        }
+    } else {
+        start_code
+    };
+
+    if start_code <= end_code {
+        ranges.push(start_code..=end_code);
+    }
+}
+
+/// Iterator over the character codes of a subtable and their glyphs.
+pub struct MappingIterator<'a, 'otf: 'a> {
+    subtable: &'a CharacterGlyphIndexMappingSubtable<'otf>,
+    range_index: usize,
+    character_code: u64,
+    end_character_code: u64
+}
+
+impl<'a, 'otf> Iterator for MappingIterator<'a, 'otf> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<(u32, GlyphId)> {
+        loop {
+            while self.character_code <= self.end_character_code {
+                let character_code = self.character_code as u32;
+                self.character_code += 1;
+
+                // The range index has been incremented when the range was entered
+                match self.subtable.range_glyph_id(self.range_index - 1, character_code) {
+                    Some(glyph_id) if glyph_id != 0 => return Some((character_code, glyph_id)),
+                    _ => {}
+                }
+            }
+
+            let (start, end) = self.subtable.character_code_range(self.range_index)?;
+            self.range_index += 1;
+            self.character_code = u64::from(start);
+            self.end_character_code = u64::from(end);
+        }
+    }
+}
+
+/// Iterator over the character codes mapped to a glyph by a subtable.
+pub struct CodepointIterator<'a, 'otf: 'a> {
+    mapping: MappingIterator<'a, 'otf>
+}
+
+impl<'a, 'otf> Iterator for CodepointIterator<'a, 'otf> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.mapping.next().map(|(character_code, _)| character_code)
+    }
 }
 
 /// This is the Apple standard character to glyph index mapping table.
@@ -257,13 +607,14 @@ impl<'otf> CharacterGlyphIndexMappingSubtable0<'otf> {
 /// SubHeader 0 is used, a second byte is not needed; the single byte value is mapped through
 /// the subArray.
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct CharacterGlyphIndexMappingSubtable2 {
+pub struct CharacterGlyphIndexMappingSubtable2<'otf> {
     language: u16,
-    sub_header_keys: Vec<u16>
-    // TODO: subHeaders and glyphIndexArray
+    sub_header_keys: Vec<u16>,
+    sub_headers: Vec<CharacterGlyphIndexMappingSubtable2SubHeaderRecord>,
+    glyph_index_array: &'otf[u8]
 }
 
-impl CharacterGlyphIndexMappingSubtable2 {
+impl<'otf> CharacterGlyphIndexMappingSubtable2<'otf> {
     /// For requirements on use of the language field.
     pub fn language(&self) -> u16 {
         self.language
@@ -274,8 +625,95 @@ impl CharacterGlyphIndexMappingSubtable2 {
         &self.sub_header_keys
     }
 
+    /// Variable-length array of SubHeader records.
+    pub fn sub_headers(&self) -> &[CharacterGlyphIndexMappingSubtable2SubHeaderRecord] {
+        &self.sub_headers
+    }
+
+    /// Variable-length array containing subarrays used for mapping the low byte of 2-byte
+    /// characters.
+    pub fn glyph_index_array(&self) -> &'otf[u8] {
+        self.glyph_index_array
+    }
+
+    /// Glyph mapped to the given 1-byte or 2-byte character code.
+    ///
+    /// A character code lower than 256 is a single-byte character if its SubHeader is the
+    /// SubHeader 0, otherwise it is the first byte of a 2-byte character and is not mapped.
+    pub fn get_glyph_id(&self, character_code: u16) -> Option<GlyphId> {
+        let high_byte = character_code >> 8;
+
+        let (sub_header_index, low_byte) = if high_byte == 0 {
+            (self.sub_header_index(character_code)?, character_code)
+        }
+        else {
+            (self.sub_header_index(high_byte)?, character_code & 0xFF)
+        };
+
+        // SubHeader 0 is reserved to single-byte characters
+        if (high_byte == 0) != (sub_header_index == 0) {
+            return None;
+        }
+
+        self.sub_header_glyph_id(sub_header_index, low_byte)
+    }
+
+    /// Index of the SubHeader mapping the given byte.
+    fn sub_header_index(&self, byte: u16) -> Option<usize> {
+        self.sub_header_keys.get(usize::from(byte)).map(|&key| usize::from(key / 8))
+    }
+
+    /// Glyph mapped to the given byte by a SubHeader.
+    fn sub_header_glyph_id(&self, sub_header_index: usize, byte: u16) -> Option<GlyphId> {
+        let sub_header = self.sub_headers.get(sub_header_index)?;
+
+        if byte < sub_header.first_code || u32::from(byte) >= u32::from(sub_header.first_code) + u32::from(sub_header.entry_count) {
+            return None;
+        }
+
+        // The idRangeOffset is relative to its own location in the SubHeader, the SubHeaders
+        // being followed by glyphIndexArray
+        let id_range_offset_position = sub_header_index * 8 + 6;
+        let position = (id_range_offset_position + usize::from(sub_header.id_range_offset) + usize::from(byte - sub_header.first_code) * 2)
+            .checked_sub(self.sub_headers.len() * 8)?;
+        let bytes = self.glyph_index_array.get(position..position + 2)?;
+        let glyph_id = u16::from(bytes[0]) << 8 | u16::from(bytes[1]);
+
+        if glyph_id == 0 {
+            return Some(0);
+        }
+
+        Some(glyph_id.wrapping_add(sub_header.id_delta as u16))
+    }
+
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
-        unimplemented!()
+        let mut mapping = HashMap::new();
+        for (high_byte, &key) in self.sub_header_keys.iter().enumerate() {
+            let sub_header_index = usize::from(key / 8);
+
+            let character_codes: Vec<(u16, u32)> = if sub_header_index == 0 {
+                vec![(high_byte as u16, high_byte as u32)]
+            }
+            else {
+                match self.sub_headers.get(sub_header_index) {
+                    Some(sub_header) => (u32::from(sub_header.first_code)..u32::from(sub_header.first_code) + u32::from(sub_header.entry_count))
+                        .filter(|&low_byte| low_byte <= 0xFF)
+                        .map(|low_byte| (low_byte as u16, (high_byte as u32) << 8 | low_byte))
+                        .collect(),
+                    None => continue
+                }
+            };
+
+            for (byte, character_code) in character_codes {
+                match self.sub_header_glyph_id(sub_header_index, byte) {
+                    Some(glyph_id) if glyph_id != 0 => {
+                        mapping.insert(character_code, glyph_id);
+                    },
+                    _ => {}
+                }
+            }
+        }
+        mapping
     }
 }
 
@@ -391,34 +829,80 @@ impl<'otf> CharacterGlyphIndexMappingSubtable4<'otf> {
         &self.id_range_offset
     }
 
-    pub fn get_glyph_id(&self, _character_code: u16) -> Option<GlyphId> {
-        None
+    /// Glyph mapped to the given character code, found with a binary search of the segment
+    /// whose end code is greater than or equal to the character code.
+    pub fn get_glyph_id(&self, character_code: u16) -> Option<GlyphId> {
+        let segment = match self.end_code.binary_search(&character_code) {
+            Ok(segment) | Err(segment) => segment
+        };
+
+        if character_code < *self.start_code.get(segment)? {
+            return None;
+        }
+
+        self.segment_glyph_id(segment, character_code)
+    }
+
+    /// Glyph mapped to a character code of the given segment.
+    ///
+    /// If the idRangeOffset value for the segment is not 0, the mapping of character codes
+    /// relies on glyphIdArray: the idRangeOffset value is the offset in bytes from its own
+    /// location to the glyphIdArray element corresponding to the start code of the segment. The
+    /// glyph index read from the array is then added to idDelta, unless it is 0. Otherwise,
+    /// idDelta is added to the character code. The idDelta arithmetic is modulo 65536.
+    fn segment_glyph_id(&self, segment: usize, character_code: u16) -> Option<GlyphId> {
+        let start_code = self.start_code[segment];
+        let id_delta = self.id_delta[segment] as u16;
+        let id_range_offset = self.id_range_offset[segment];
+
+        if id_range_offset == 0 {
+            return Some(character_code.wrapping_add(id_delta));
+        }
+
+        // Index in glyphIdArray, the idRangeOffset being relative to its own location in the
+        // idRangeOffset array which precedes glyphIdArray
+        let index = (usize::from(id_range_offset / 2) + usize::from(character_code - start_code))
+            .checked_sub(usize::from(self.seg_count) - segment)?;
+        let bytes = self.glyph_id_array.get(index * 2..index * 2 + 2)?;
+        let glyph_id = u16::from(bytes[0]) << 8 | u16::from(bytes[1]);
+
+        if glyph_id == 0 {
+            return Some(0);
+        }
+
+        Some(glyph_id.wrapping_add(id_delta))
     }
 
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
         let mut mapping = HashMap::new();
-        for (i, tuple) in self.start_code.iter()
-            .zip(self.end_code.iter())
-            .zip(self.id_delta.iter())
-            .zip(self.id_range_offset.iter())
-            .enumerate() {
-            let (((&start_code, &end_code), &id_delta), &id_range_offset) = tuple;
-
-            for j in start_code..(end_code + 1) {
-                let glyph_id = if id_range_offset > 0 {
-                    let offset = (id_range_offset / 2 + (j - start_code)) - (self.seg_count - i as u16) as u16;
-                    match self.glyph_id_array.get(offset as usize) {
-                        Some(&glyph_id) => glyph_id as u16,
-                        _ => continue
-                    }
-                } else {
-                    id_delta.wrapping_add(j as i16) as u16
-                };
-                mapping.insert(j as u32, glyph_id);
+        for (segment, (&start_code, &end_code)) in self.start_code.iter().zip(self.end_code.iter()).enumerate() {
+            for character_code in u32::from(start_code)..=u32::from(end_code) {
+                match self.segment_glyph_id(segment, character_code as u16) {
+                    Some(glyph_id) if glyph_id != 0 => {
+                        mapping.insert(character_code, glyph_id);
+                    },
+                    _ => {}
+                }
             }
         }
         mapping
     }
+
+    /// Character codes mapped to each glyph, the segments being sorted by character code.
+    pub fn reverse_mapping(&self) -> HashMap<GlyphId, Vec<u32>> {
+        let mut reverse_mapping: HashMap<GlyphId, Vec<u32>> = HashMap::new();
+        for (segment, (&start_code, &end_code)) in self.start_code.iter().zip(self.end_code.iter()).enumerate() {
+            for character_code in start_code..=end_code {
+                match self.segment_glyph_id(segment, character_code) {
+                    Some(glyph_id) if glyph_id != 0 => {
+                        reverse_mapping.entry(glyph_id).or_default().push(u32::from(character_code));
+                    },
+                    _ => {}
+                }
+            }
+        }
+        reverse_mapping
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -451,18 +935,14 @@ impl<'otf> CharacterGlyphIndexMappingSubtable6<'otf> {
     }
 
     pub fn get_glyph_id(&self, character_code: u16) -> Option<GlyphId> {
-        if character_code < self.first_code || character_code >= self.first_code + self.entry_count {
-            return None;
-        }
-
-        // TODO:
-        None
+        let index = character_code.checked_sub(self.first_code)?;
+        read_glyph_id(self.glyph_id_array, usize::from(index))
     }
 
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
         let mut mapping = HashMap::new();
-        for (i, glyph_id) in self.glyph_id_array.iter().enumerate() {
-            mapping.insert(self.first_code as u32 + i as u32, GlyphId::from(*glyph_id));
+        for (i, glyph_id) in self.glyph_id_array.chunks_exact(2).enumerate() {
+            mapping.insert(u32::from(self.first_code) + i as u32, u16::from(glyph_id[0]) << 8 | u16::from(glyph_id[1]));
         }
         mapping
     }
@@ -505,8 +985,19 @@ impl<'otf> CharacterGlyphIndexMappingSubtable8<'otf> {
         &self.groups
     }
 
+    /// Glyph mapped to the given 16-bit or 32-bit character code, found with a binary search of
+    /// the group containing the character code.
+    pub fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
+        find_sequential_map_group(&self.groups, character_code)?.get_glyph_id(character_code)
+    }
+
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
-        unimplemented!()
+        sequential_map_groups_mapping(&self.groups)
+    }
+
+    /// Character codes mapped to each glyph, the groups being sorted by character code.
+    pub fn reverse_mapping(&self) -> HashMap<GlyphId, Vec<u32>> {
+        sequential_map_groups_reverse_mapping(&self.groups)
     }
 }
 
@@ -560,6 +1051,11 @@ impl SequentialMapGroup {
     pub fn start_glyph_id(&self) -> u32 {
         self.start_glyph_id
     }
+
+    fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
+        let glyph_id = self.start_glyph_id.checked_add(character_code.checked_sub(self.start_char_code)?)?;
+        GlyphId::try_from(glyph_id).ok()
+    }
 }
 
 /// The constant map group record has the same structure as the sequential map group record, with
@@ -618,10 +1114,15 @@ impl<'otf> CharacterGlyphIndexMappingSubtable10<'otf> {
         self.glyphs
     }
 
+    pub fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
+        let index = character_code.checked_sub(self.start_char_code)?;
+        read_glyph_id(self.glyphs, usize::try_from(index).ok()?)
+    }
+
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
         let mut mapping = HashMap::new();
-        for (i, glyph_id) in self.glyphs.iter().enumerate() {
-            mapping.insert(self.start_char_code as u32 + i as u32, GlyphId::from(*glyph_id));
+        for (i, glyph_id) in self.glyphs.chunks_exact(2).enumerate() {
+            mapping.insert(self.start_char_code + i as u32, u16::from(glyph_id[0]) << 8 | u16::from(glyph_id[1]));
         }
         mapping
     }
@@ -651,17 +1152,19 @@ impl CharacterGlyphIndexMappingSubtable12 {
         &self.groups
     }
 
+    /// Glyph mapped to the given character code, found with a binary search of the group
+    /// containing the character code.
+    pub fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
+        find_sequential_map_group(&self.groups, character_code)?.get_glyph_id(character_code)
+    }
+
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
-        let mut mapping = HashMap::new();
-        for group in &self.groups {
-            for i in 0..(group.end_char_code() - group.start_char_code() + 1) {
-                mapping.insert(
-                    group.start_char_code() + i,
-                    group.start_glyph_id() as u16 + i as u16,
-                );
-            }
-        }
-        mapping
+        sequential_map_groups_mapping(&self.groups)
+    }
+
+    /// Character codes mapped to each glyph, the groups being sorted by character code.
+    pub fn reverse_mapping(&self) -> HashMap<GlyphId, Vec<u32>> {
+        sequential_map_groups_reverse_mapping(&self.groups)
     }
 }
 
@@ -689,18 +1192,45 @@ impl CharacterGlyphIndexMappingSubtable13 {
         &self.groups
     }
 
+    /// Glyph mapped to the given character code, found with a binary search of the group
+    /// containing the character code.
+    pub fn get_glyph_id(&self, character_code: u32) -> Option<GlyphId> {
+        let index = self.groups.binary_search_by(|group| compare_group(group.start_char_code, group.end_char_code, character_code)).ok()?;
+        GlyphId::try_from(self.groups[index].glyph_id).ok()
+    }
+
+    /// Glyph mapped to each character code, character codes being limited to the Unicode range
+    /// (up to U+10FFFF).
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
         let mut mapping = HashMap::new();
         for group in &self.groups {
-            for i in 0..(group.end_char_code() - group.start_char_code() + 1) {
-                mapping.insert(
-                    group.start_char_code() + i,
-                    group.glyph_id() as u16,
-                );
+            let glyph_id = match GlyphId::try_from(group.glyph_id) {
+                Ok(glyph_id) => glyph_id,
+                Err(_) => continue
+            };
+
+            for character_code in group.start_char_code..=group.end_char_code.min(MAX_UNICODE_CODEPOINT) {
+                mapping.insert(character_code, glyph_id);
             }
         }
         mapping
     }
+
+    /// Character codes mapped to each glyph, the whole range of each group being mapped to a
+    /// single glyph. Character codes are limited to the Unicode range (up to U+10FFFF).
+    pub fn reverse_mapping(&self) -> HashMap<GlyphId, Vec<u32>> {
+        let mut reverse_mapping: HashMap<GlyphId, Vec<u32>> = HashMap::new();
+        for group in &self.groups {
+            match GlyphId::try_from(group.glyph_id) {
+                Ok(glyph_id) if glyph_id != 0 => {
+                    reverse_mapping.entry(glyph_id).or_default()
+                        .extend(group.start_char_code..=group.end_char_code.min(MAX_UNICODE_CODEPOINT));
+                },
+                _ => {}
+            }
+        }
+        reverse_mapping
+    }
 }
 
 /// Subtable format 14 specifies the Unicode Variation Sequences (UVSes) supported by the font.
@@ -714,21 +1244,83 @@ impl CharacterGlyphIndexMappingSubtable13 {
 /// default UVS; otherwise it is a non-default UVS, and the glyph to use for that sequence is
 /// specified in the format 14 subtable itself.
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct CharacterGlyphIndexMappingSubtable14 {
+pub struct CharacterGlyphIndexMappingSubtable14<'otf> {
+    buf: &'otf[u8],
     var_selector: Vec<VariationSelectorRecord>
 }
 
-impl CharacterGlyphIndexMappingSubtable14 {
+impl<'otf> CharacterGlyphIndexMappingSubtable14<'otf> {
     /// Array of VariationSelector records.
     pub fn var_selector(&self) -> &Vec<VariationSelectorRecord> {
         &self.var_selector
     }
 
+    /// Default UVS Table of the given VariationSelector record, if any.
+    pub fn default_uvs_table(&self, record: &VariationSelectorRecord) -> Option<DefaultUVSTable> {
+        if record.default_uvs_offset() == 0 {
+            return None;
+        }
+
+        let buf = self.buf.get(usize::try_from(record.default_uvs_offset()).ok()?..)?;
+        parse_default_uvs_table(buf).ok().map(|(_, table)| table)
+    }
+
+    /// Non-Default UVS Table of the given VariationSelector record, if any.
+    pub fn non_default_uvs_table(&self, record: &VariationSelectorRecord) -> Option<NonDefaultUVSTable> {
+        if record.non_default_uvs_offset() == 0 {
+            return None;
+        }
+
+        let buf = self.buf.get(usize::try_from(record.non_default_uvs_offset()).ok()?..)?;
+        parse_non_default_uvs_table(buf).ok().map(|(_, table)| table)
+    }
+
+    /// Glyph to use for the variation sequence made of the given base character and variation
+    /// selector.
+    ///
+    /// Return `VariationResult::Default` if the sequence is a default UVS, the glyph then being
+    /// the one the Unicode 'cmap' subtable maps to the base character.
+    pub fn glyph_variant(&self, base: char, selector: char) -> VariationResult {
+        let (base, selector) = (u32::from(base), u32::from(selector));
+
+        let record = match self.var_selector.binary_search_by_key(&selector, VariationSelectorRecord::var_selector) {
+            Ok(index) => &self.var_selector[index],
+            Err(_) => return VariationResult::NotFound
+        };
+
+        if let Some(default_uvs_table) = self.default_uvs_table(record) {
+            if default_uvs_table.contains(base) {
+                return VariationResult::Default;
+            }
+        }
+
+        if let Some(non_default_uvs_table) = self.non_default_uvs_table(record) {
+            if let Some(glyph_id) = non_default_uvs_table.get_glyph_id(base) {
+                return VariationResult::Found(glyph_id);
+            }
+        }
+
+        VariationResult::NotFound
+    }
+
+    /// Format 14 subtables map variation sequences rather than character codes, the mapping is
+    /// always empty. Use [glyph_variant](#method.glyph_variant) instead.
     pub fn mapping(&self) -> HashMap<u32, GlyphId> {
-        unimplemented!()
+        HashMap::new()
     }
 }
 
+/// Result of the lookup of a Unicode Variation Sequence.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VariationResult {
+    /// The sequence is a default UVS, the glyph of the base character must be used.
+    Default,
+    /// The sequence is a non-default UVS mapped to the given glyph.
+    Found(GlyphId),
+    /// The sequence is not supported by the font.
+    NotFound
+}
+
 /// Each variation selector records specifies a variation selector character, and offsets to
 /// default and non-default tables used to map variation sequences using that variation selector.
 ///
@@ -787,6 +1379,21 @@ impl DefaultUVSTable {
     pub fn ranges(&self) -> &Vec<UnicodeRangeRecord> {
         &self.ranges
     }
+
+    /// Whether the given Unicode value is the base character of a default UVS.
+    pub fn contains(&self, unicode_value: u32) -> bool {
+        self.ranges.binary_search_by(|range| {
+            if range.start_unicode_value() > unicode_value {
+                Ordering::Greater
+            }
+            else if range.start_unicode_value() + u32::from(range.additional_count()) < unicode_value {
+                Ordering::Less
+            }
+            else {
+                Ordering::Equal
+            }
+        }).is_ok()
+    }
 }
 
 /// Each Unicode range record specifies a contiguous range of Unicode values.
@@ -825,6 +1432,13 @@ impl NonDefaultUVSTable {
     pub fn uvs_mappings(&self) -> &Vec<UVSMappingRecord> {
         &self.uvs_mappings
     }
+
+    /// Glyph of the non-default UVS whose base character is the given Unicode value.
+    pub fn get_glyph_id(&self, unicode_value: u32) -> Option<GlyphId> {
+        self.uvs_mappings.binary_search_by_key(&unicode_value, UVSMappingRecord::unicode_value)
+            .ok()
+            .map(|index| self.uvs_mappings[index].glyph_id())
+    }
 }
 
 /// Each UVSMapping record provides a glyph ID mapping for one base Unicode character, when that
@@ -847,12 +1461,70 @@ impl UVSMappingRecord {
     }
 }
 
-pub fn parse_character_glyph_index_mapping_table(input: &[u8]) -> IResult<&[u8], CharacterGlyphIndexMappingTable>
+/// Read the big-endian glyph index at the given index of a glyph index array.
+fn read_glyph_id(glyph_id_array: &[u8], index: usize) -> Option<GlyphId> {
+    let bytes = glyph_id_array.get(index.checked_mul(2)?..index.checked_mul(2)? + 2)?;
+    Some(u16::from(bytes[0]) << 8 | u16::from(bytes[1]))
+}
+
+/// Order of a group relative to a character code, groups being sorted by increasing character
+/// codes without overlapping.
+fn compare_group(start_char_code: u32, end_char_code: u32, character_code: u32) -> Ordering {
+    if end_char_code < character_code {
+        Ordering::Less
+    }
+    else if start_char_code > character_code {
+        Ordering::Greater
+    }
+    else {
+        Ordering::Equal
+    }
+}
+
+fn find_sequential_map_group(groups: &[SequentialMapGroup], character_code: u32) -> Option<&SequentialMapGroup> {
+    let index = groups.binary_search_by(|group| compare_group(group.start_char_code, group.end_char_code, character_code)).ok()?;
+    groups.get(index)
+}
+
+fn sequential_map_groups_mapping(groups: &[SequentialMapGroup]) -> HashMap<u32, GlyphId> {
+    let mut mapping = HashMap::new();
+    for group in groups {
+        for character_code in group.start_char_code..=group.end_char_code {
+            match group.get_glyph_id(character_code) {
+                Some(glyph_id) => {
+                    mapping.insert(character_code, glyph_id);
+                },
+                None => break
+            }
+        }
+    }
+    mapping
+}
+
+fn sequential_map_groups_reverse_mapping(groups: &[SequentialMapGroup]) -> HashMap<GlyphId, Vec<u32>> {
+    let mut reverse_mapping: HashMap<GlyphId, Vec<u32>> = HashMap::new();
+    for group in groups {
+        for character_code in group.start_char_code..=group.end_char_code {
+            match group.get_glyph_id(character_code) {
+                Some(0) => {},
+                Some(glyph_id) => reverse_mapping.entry(glyph_id).or_default().push(character_code),
+                None => break
+            }
+        }
+    }
+    reverse_mapping
+}
+
+pub fn parse_character_glyph_index_mapping_table(input: &[u8]) -> IResult<&[u8], CharacterGlyphIndexMappingTable<'_>>
 {
+    let buf = input;
     let (input, _) = verify(be_u16, |version| *version == 0)(input)?;
     let (input, num_tables) = be_u16(input)?;
+    // The encoding records must all be present
+    let (input, _) = take(usize::from(num_tables) * 8)(input)?;
 
     Ok((input, CharacterGlyphIndexMappingTable {
+        buf,
         num_tables
     }))
 }
@@ -906,6 +1578,7 @@ pub fn parse_encoding_record(input: &[u8]) -> IResult<&[u8], EncodingRecord>
 
 pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<&[u8], CharacterGlyphIndexMappingSubtable>
 {
+    let buf = input;
     let (input, format) = be_u16(input)?;
 
     match format {
@@ -920,19 +1593,29 @@ pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<
             })))
         },
         2 => {
-            let (input, _length) = be_u16(input)?;
+            let (input, length) = be_u16(input)?;
             let (input, language) = be_u16(input)?;
             let (input, sub_header_keys) = count(be_u16, 256)(input)?;
+            // The number of SubHeaders is given by the highest subHeader index referenced
+            let num_sub_headers = sub_header_keys.iter().map(|&key| usize::from(key / 8)).max().unwrap_or(0) + 1;
+            let (input, sub_headers) = count(parse_character_to_glyph_index_mapping_subtable_2_sub_header_record, num_sub_headers)(input)?;
+            // The glyphIndexArray fills the remainder of the subtable
+            let glyph_index_array_len = usize::from(length).checked_sub(6 + 512 + num_sub_headers * 8)
+                .ok_or(NomErr::Error(error_position!(input, ErrorKind::Verify)))?;
+            let (input, glyph_index_array) = take(glyph_index_array_len)(input)?;
 
             Ok((input, CharacterGlyphIndexMappingSubtable::Format_2(CharacterGlyphIndexMappingSubtable2 {
                 language,
-                sub_header_keys
+                sub_header_keys,
+                sub_headers,
+                glyph_index_array
             })))
         },
         4 => {
             let (input, _length) = be_u16(input)?;
             let (input, language) = be_u16(input)?;
-            let (input, seg_count) = be_u16(input)?;
+            let (input, seg_count_x2) = be_u16(input)?;
+            let seg_count = seg_count_x2 / 2;
             let (input, search_range) = be_u16(input)?;
             let (input, entry_selector) = be_u16(input)?;
             let (input, range_shift) = be_u16(input)?;
@@ -943,7 +1626,7 @@ pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<
             let (input, id_range_offset) = count(be_u16, usize::from(seg_count))(input)?;
             let glyph_id_count = get_glyph_id_count(seg_count, &start_code, &end_code, &id_range_offset)
                 .ok_or(NomErr::Error(error_position!(input, ErrorKind::Alt)))?;
-            let (input, glyph_id_array) = take(glyph_id_count)(input)?;
+            let (input, glyph_id_array) = take(glyph_id_count * 2)(input)?;
 
             Ok((input, CharacterGlyphIndexMappingSubtable::Format_4(CharacterGlyphIndexMappingSubtable4 {
                 language,
@@ -963,7 +1646,7 @@ pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<
             let (input, language) = be_u16(input)?;
             let (input, first_code) = be_u16(input)?;
             let (input, entry_count) = be_u16(input)?;
-            let (input, glyph_id_array) = take(entry_count * 2)(input)?;
+            let (input, glyph_id_array) = take(usize::from(entry_count) * 2)(input)?;
 
             Ok((input, CharacterGlyphIndexMappingSubtable::Format_6(CharacterGlyphIndexMappingSubtable6 {
                 language,
@@ -993,8 +1676,9 @@ pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<
             let (input, _length) = be_u32(input)?;
             let (input, language) = be_u32(input)?;
             let (input, start_char_code) = be_u32(input)?;
-            let (input, num_chars) = be_u32(input)?;
-            let (input, glyphs) = take(num_chars * 2)(input)?;
+            let (input, num_chars) = map_res(be_u32, |v| usize::try_from(v))(input)?;
+            let glyphs_len = num_chars.checked_mul(2).ok_or(NomErr::Error(error_position!(input, ErrorKind::TooLarge)))?;
+            let (input, glyphs) = take(glyphs_len)(input)?;
 
             Ok((input, CharacterGlyphIndexMappingSubtable::Format_10(CharacterGlyphIndexMappingSubtable10 {
                 language: language as u16,
@@ -1029,11 +1713,14 @@ pub fn parse_character_to_glyph_index_mapping_subtable(input: &[u8]) -> IResult<
             })))
         },
         14 => {
-            let (input, _length) = be_u32(input)?;
+            let (input, length) = map_res(be_u32, |v| usize::try_from(v))(input)?;
             let (input, num_var_selector_records) = map_res(be_u32, |v| usize::try_from(v))(input)?;
             let (input, var_selector) = count(parse_variation_selector_record, num_var_selector_records)(input)?;
+            // The UVS tables are located from the start of the subtable
+            let buf = buf.get(..length).ok_or(NomErr::Error(error_position!(input, ErrorKind::Eof)))?;
 
             Ok((input, CharacterGlyphIndexMappingSubtable::Format_14(CharacterGlyphIndexMappingSubtable14 {
+                buf,
                 var_selector
             })))
         },
@@ -1056,31 +1743,32 @@ fn parse_character_to_glyph_index_mapping_subtable_2_sub_header_record(input: &[
     }))
 }
 
-fn get_glyph_id_count(seg_count: u16, start_code: &Vec<u16>, end_code: &Vec<u16>, id_range_offset: &Vec<u16>) -> Option<usize> {
+/// Number of glyph indices of glyphIdArray, that is the index following the last element
+/// referenced by the segments.
+fn get_glyph_id_count(seg_count: u16, start_code: &[u16], end_code: &[u16], id_range_offset: &[u16]) -> Option<usize> {
     // The final start code and end code values must be 0xFFFF
     if start_code.last() != Some(&0xffff) || end_code.last() != Some(&0xffff) {
         return None
     }
 
-    let mut length: u16 = 0;
+    let mut length = 0;
 
-    for (i, tuple) in start_code.iter()
+    for (i, ((&start_code, &end_code), &id_range_offset)) in start_code.iter()
         .zip(end_code.iter())
         .zip(id_range_offset.iter())
         .enumerate() {
-        let ((&start_code, &end_code), &id_range_offset) = tuple;
-
-        for j in start_code..(end_code + 1) {
-            if id_range_offset > 0 {
-                let end = (id_range_offset / 2 + (j - start_code)) - (seg_count - i as u16) as u16 + 1;
-                if end > length {
-                    length = end;
-                }
-            }
+        if id_range_offset == 0 || end_code < start_code {
+            continue;
         }
+
+        // Index following the element of the end code
+        let end = (usize::from(id_range_offset / 2) + usize::from(end_code - start_code) + 1)
+            .saturating_sub(usize::from(seg_count) - i);
+
+        length = length.max(end);
     }
 
-    Some(length as usize)
+    Some(length)
 }
 
 fn parse_sequential_map_group(input: &[u8]) -> IResult<&[u8], SequentialMapGroup>
@@ -1099,7 +1787,7 @@ fn parse_sequential_map_group(input: &[u8]) -> IResult<&[u8], SequentialMapGroup
 fn parse_constant_map_group(input: &[u8]) -> IResult<&[u8], ConstantMapGroup>
 {
     let (input, start_char_code) = be_u32(input)?;
-    let (input, end_char_code) = be_u32(input)?;
+    let (input, end_char_code) = verify(be_u32, |end_char_code| *end_char_code >= start_char_code)(input)?;
     let (input, glyph_id) = be_u32(input)?;
 
     Ok((input, ConstantMapGroup {
@@ -1162,4 +1850,276 @@ fn parse_uvs_mapping_record(input: &[u8]) -> IResult<&[u8], UVSMappingRecord>
         unicode_value,
         glyph_id
     }))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf");
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    fn parse_subtable(buf: &[u8]) -> CharacterGlyphIndexMappingSubtable<'_> {
+        parse_character_to_glyph_index_mapping_subtable(buf).unwrap().1
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_4() {
+        let subtable = parse_subtable(&ROBOTO_REGULAR[7028..]);
+
+        match subtable {
+            CharacterGlyphIndexMappingSubtable::Format_4(ref subtable_4) => assert_eq!(subtable_4.seg_count(), 122),
+            _ => assert!(false)
+        }
+
+        assert_eq!(subtable.get_glyph_id(0x41), Some(37));
+        // 'À' and 'Á' are mapped through glyphIdArray
+        assert_eq!(subtable.get_glyph_id(0xC0), Some(639));
+        assert_eq!(subtable.get_glyph_id(0xC1), Some(640));
+        assert_eq!(subtable.get_glyph_id(0xE9), Some(675));
+        assert_eq!(subtable.get_glyph_id(0x20AC), Some(413));
+        assert_eq!(subtable.get_glyph_id(0xFFFD), Some(452));
+        assert_eq!(subtable.get_glyph_id(0xFFFF), None);
+        assert_eq!(subtable.get_glyph_id(0x1F600), None);
+
+        let mapping = subtable.mapping();
+        assert_eq!(mapping.len(), 896);
+        assert_eq!(mapping.get(&0xC1), Some(&640));
+
+        let reverse_mapping = subtable.reverse_mapping();
+        assert_eq!(reverse_mapping.len(), 894);
+        assert_eq!(reverse_mapping.get(&37), Some(&vec![0x41]));
+        // 'Δ' and '∆' share the same glyph
+        assert_eq!(reverse_mapping.get(&178), Some(&vec![0x394, 0x2206]));
+        assert_eq!(reverse_mapping.get(&0), None);
+        for (glyph_id, character_codes) in reverse_mapping {
+            for character_code in character_codes {
+                assert_eq!(mapping.get(&character_code), Some(&glyph_id));
+            }
+        }
+
+        assert_eq!(subtable.iter().collect::<HashMap<u32, GlyphId>>(), mapping);
+        assert_eq!(subtable.codepoints().take(4).collect::<Vec<u32>>(), vec![0x00, 0x02, 0x0D, 0x20]);
+
+        let coverage = subtable.coverage();
+        assert_eq!(coverage.len(), 78);
+        assert_eq!(coverage[3], 0x20..=0x7E);
+        assert_eq!(coverage[4], 0xA0..=0x17F);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_6() {
+        let subtable = parse_subtable(&SOURCE_SERIF_PRO_REGULAR[2492..]);
+
+        assert_eq!(subtable.get_glyph_id(0x41), Some(2));
+        assert_eq!(subtable.get_glyph_id(0x08), None);
+        assert_eq!(subtable.get_glyph_id(0x100), None);
+        assert_eq!(subtable.mapping().get(&0x42), Some(&3));
+        assert_eq!(subtable.iter().count(), subtable.mapping().values().filter(|&&glyph_id| glyph_id != 0).count());
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_12() {
+        let subtable = parse_subtable(&ROBOTO_REGULAR[8674..]);
+
+        assert_eq!(subtable.get_glyph_id(0x41), Some(37));
+        assert_eq!(subtable.get_glyph_id(0x20AC), Some(413));
+        assert_eq!(subtable.get_glyph_id(0xFFFD), Some(452));
+        assert_eq!(subtable.get_glyph_id(0x10000), None);
+        assert_eq!(subtable.mapping().len(), 896);
+
+        let reverse_mapping = subtable.reverse_mapping();
+        assert_eq!(reverse_mapping.len(), 894);
+        assert_eq!(reverse_mapping.get(&186), Some(&vec![0x3A9, 0x2126]));
+        assert_eq!(subtable.iter().collect::<HashMap<u32, GlyphId>>(), subtable.mapping());
+        assert_eq!(subtable.coverage().len(), 78);
+
+        let subtable = parse_subtable(&SOURCE_SERIF_PRO_REGULAR[5486..]);
+
+        assert_eq!(subtable.get_glyph_id(0x1F16B), Some(1071));
+        assert_eq!(subtable.get_glyph_id(0x1F16C), None);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_8() {
+        let mut bytes: Vec<u8> = vec![0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x20, 0x3C, 0x00, 0x00, 0x00, 0x00];
+        // is32: U+10000 to U+10010 are encoded as 32-bit character codes
+        bytes.extend(vec![0; 8192]);
+        bytes[12] = 0x80;
+        bytes.extend(&[
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x5A, 0x00, 0x00,
+            0x00, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x64]);
+
+        let subtable = parse_subtable(&bytes);
+
+        assert_eq!(subtable.get_glyph_id(0x41), Some(10));
+        assert_eq!(subtable.get_glyph_id(0x5A), Some(35));
+        assert_eq!(subtable.get_glyph_id(0x10008), Some(108));
+        assert_eq!(subtable.get_glyph_id(0x5B), None);
+        assert_eq!(subtable.mapping().len(), 43);
+        assert_eq!(subtable.coverage(), vec![0x41..=0x5A, 0x10000..=0x10010]);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_10() {
+        let bytes: &[u8]  = &[
+            0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x05, 0x01, 0x06];
+
+        let subtable = parse_subtable(bytes);
+
+        assert_eq!(subtable.get_glyph_id(0x10000), Some(5));
+        assert_eq!(subtable.get_glyph_id(0x10001), Some(262));
+        assert_eq!(subtable.get_glyph_id(0x10002), None);
+        assert_eq!(subtable.get_glyph_id(0xFFFF), None);
+        assert_eq!(subtable.iter().collect::<Vec<(u32, GlyphId)>>(), vec![(0x10000, 5), (0x10001, 262)]);
+        assert_eq!(subtable.coverage(), vec![0x10000..=0x10001]);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_13() {
+        let bytes: &[u8]  = &[
+            0x00, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x7E, 0x00, 0x00, 0x00, 0x03,
+            0x00, 0x00, 0x4E, 0x00, 0x00, 0x00, 0x9F, 0xFF, 0x00, 0x00, 0x00, 0x04];
+
+        let subtable = parse_subtable(bytes);
+
+        assert_eq!(subtable.get_glyph_id(0x20), Some(3));
+        assert_eq!(subtable.get_glyph_id(0x7E), Some(3));
+        assert_eq!(subtable.get_glyph_id(0x6C34), Some(4));
+        assert_eq!(subtable.get_glyph_id(0x7F), None);
+
+        let reverse_mapping = subtable.reverse_mapping();
+        assert_eq!(reverse_mapping.len(), 2);
+        assert_eq!(reverse_mapping.get(&3).map(Vec::len), Some(95));
+        assert_eq!(reverse_mapping.get(&4).map(|character_codes| character_codes[0]), Some(0x4E00));
+        assert_eq!(subtable.iter().next(), Some((0x20, 3)));
+        assert_eq!(subtable.coverage(), vec![0x20..=0x7E, 0x4E00..=0x9FFF]);
+    }
+
+    #[test]
+    fn case_cmap_subtable_coverage_large_groups() {
+        // A single group over every 32-bit character code
+        let bytes: &[u8]  = &[
+            0x00, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01];
+
+        assert_eq!(parse_subtable(bytes).coverage(), vec![0..=0x10FFFF]);
+
+        // A single group from U+10FFF0 to the last 32-bit character code
+        let bytes: &[u8]  = &[
+            0x00, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x10, 0xFF, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01];
+
+        let subtable = parse_subtable(bytes);
+        assert_eq!(subtable.mapping().len(), 16);
+        assert_eq!(subtable.reverse_mapping().get(&1).map(Vec::len), Some(16));
+
+        // A group ending before its start
+        let bytes: &[u8]  = &[
+            0x00, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x01];
+
+        assert!(parse_character_to_glyph_index_mapping_subtable(bytes).is_err());
+
+        // A group starting with the missing glyph and running past the last glyph index
+        let bytes: &[u8]  = &[
+            0x00, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x41, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00];
+
+        assert_eq!(parse_subtable(bytes).coverage(), vec![0x42..=0x10040]);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_2() {
+        let mut bytes: Vec<u8> = vec![0x00, 0x02, 0x04, 0x1A, 0x00, 0x00];
+        // The high byte 0x81 uses the SubHeader 1, the other bytes are single-byte characters
+        let mut sub_header_keys = [0u8; 512];
+        sub_header_keys[0x81 * 2 + 1] = 8;
+        bytes.extend(&sub_header_keys[..]);
+        bytes.extend(&[
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x40, 0x00, 0x02, 0x00, 0x64,
+            0x02, 0x02]);
+        let mut glyph_index_array = [0u8; 512];
+        glyph_index_array[0x41 * 2 + 1] = 5;
+        bytes.extend(&glyph_index_array[..]);
+        bytes.extend(&[0x00, 0x07, 0x00, 0x00]);
+
+        let subtable = parse_subtable(&bytes);
+
+        match subtable {
+            CharacterGlyphIndexMappingSubtable::Format_2(ref subtable_2) => {
+                assert_eq!(subtable_2.sub_headers().len(), 2);
+                assert_eq!(subtable_2.sub_headers()[1].first_code(), 0x40);
+                assert_eq!(subtable_2.glyph_index_array().len(), 516);
+            },
+            _ => assert!(false)
+        }
+
+        assert_eq!(subtable.get_glyph_id(0x41), Some(5));
+        assert_eq!(subtable.get_glyph_id(0x42), None);
+        // First byte of 2-byte characters
+        assert_eq!(subtable.get_glyph_id(0x81), None);
+        assert_eq!(subtable.get_glyph_id(0x8140), Some(107));
+        assert_eq!(subtable.get_glyph_id(0x8141), None);
+        assert_eq!(subtable.get_glyph_id(0x8142), None);
+        assert_eq!(subtable.get_glyph_id(0x8240), None);
+
+        let mapping = subtable.mapping();
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping.get(&0x41), Some(&5));
+        assert_eq!(mapping.get(&0x8140), Some(&107));
+
+        let reverse_mapping = subtable.reverse_mapping();
+        assert_eq!(reverse_mapping.get(&5), Some(&vec![0x41]));
+        assert_eq!(reverse_mapping.get(&107), Some(&vec![0x8140]));
+        assert_eq!(subtable.iter().collect::<Vec<(u32, GlyphId)>>(), vec![(0x41, 5), (0x8140, 107)]);
+        assert_eq!(subtable.coverage(), vec![0x41..=0x41, 0x8140..=0x8140]);
+    }
+
+    #[test]
+    fn case_cmap_subtable_format_14() {
+        let bytes: &[u8] = &[
+            0x00, 0x0E, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x02,
+            0x00, 0xFE, 0x0E, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x26, 0x00, 0x03,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x23, 0x1A, 0x00, 0x0A, 0x01, 0xF6, 0x00, 0x00, 0x14];
+
+        let subtable_14 = match parse_subtable(bytes) {
+            CharacterGlyphIndexMappingSubtable::Format_14(subtable_14) => subtable_14,
+            _ => panic!()
+        };
+
+        assert_eq!(subtable_14.var_selector().len(), 2);
+        assert!(subtable_14.non_default_uvs_table(&subtable_14.var_selector()[0]).is_none());
+        assert_eq!(subtable_14.default_uvs_table(&subtable_14.var_selector()[0]).unwrap().ranges().len(), 1);
+
+        assert_eq!(subtable_14.glyph_variant('\u{2600}', '\u{FE0E}'), VariationResult::Default);
+        assert_eq!(subtable_14.glyph_variant('\u{2603}', '\u{FE0E}'), VariationResult::Default);
+        assert_eq!(subtable_14.glyph_variant('\u{2604}', '\u{FE0E}'), VariationResult::NotFound);
+        assert_eq!(subtable_14.glyph_variant('\u{231A}', '\u{FE0F}'), VariationResult::Found(10));
+        assert_eq!(subtable_14.glyph_variant('\u{1F600}', '\u{FE0F}'), VariationResult::Found(20));
+        assert_eq!(subtable_14.glyph_variant('\u{2600}', '\u{FE0F}'), VariationResult::NotFound);
+        assert_eq!(subtable_14.glyph_variant('A', '\u{FE00}'), VariationResult::NotFound);
+        assert!(subtable_14.mapping().is_empty());
+        assert_eq!(parse_subtable(bytes).iter().next(), None);
+    }
+
+    #[test]
+    fn case_cmap_best_unicode_subtable() {
+        // 'cmap' table of SourceSerifPro-Regular.otf
+        let cmap = CharacterGlyphIndexMappingTable::parse(&SOURCE_SERIF_PRO_REGULAR[2448..]).unwrap();
+
+        let encodings: Vec<(u16, u16)> = cmap.encoding_records().iter()
+            .map(|encoding_record| (encoding_record.platform_id(), encoding_record.encoding_id()))
+            .collect();
+        assert_eq!(encodings, vec![(0, 3), (0, 4), (1, 0), (3, 1), (3, 10)]);
+
+        match cmap.best_unicode_subtable() {
+            Some(CharacterGlyphIndexMappingSubtable::Format_12(_)) => {},
+            _ => assert!(false)
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_i16, be_u16, be_u32};
+use tables::GlyphId;
+use tables::layout::{ClassDef, Coverage, Device, offset_slice, parse_array16, parse_class_def, parse_coverage,
+                     parse_device, parse_nullable, parse_offsets, verify_format};
+use tables::variation::{ItemVariationStore, parse_item_variation_store};
+
+/// Glyph Definition Table
+///
+/// The Glyph Definition (GDEF) table contains six types of information in six independent
+/// subtables:
+///
+/// * The GlyphClassDef table classifies the different types of glyphs in the font.
+/// * The AttachmentList table identifies all attachment points on the glyphs, which streamlines
+///   data access and bitmap caching.
+/// * The LigatureCaretList table contains positioning data for ligature carets, which the text-
+///   processing client uses on screen to select and highlight the individual components of a
+///   ligature glyph.
+/// * The MarkAttachClassDef table classifies mark glyphs, to help group together marks that are
+///   positioned similarly.
+/// * The MarkGlyphSetsTable allows the enumeration of an arbitrary number of glyph sets that can
+///   be used as an extension of the mark attachment class definition to allow lookups to filter
+///   mark glyphs by arbitrary sets of marks.
+/// * The ItemVariationStore table is used in variable fonts to contain variation data used for
+///   adjustment of values in the GDEF, GPOS or JSTF tables.
+///
+/// More information on ['GDEF'](https://docs.microsoft.com/en-gb/typography/opentype/spec/gdef)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GlyphDefinitionTable {
+    major_version: u16,
+    minor_version: u16,
+    glyph_class_def: Option<ClassDef>,
+    attach_list: Option<AttachList>,
+    lig_caret_list: Option<LigCaretList>,
+    mark_attach_class_def: Option<ClassDef>,
+    mark_glyph_sets_def: Option<Vec<Coverage>>,
+    item_var_store: Option<ItemVariationStore>
+}
+
+impl GlyphDefinitionTable {
+    /// Major version of the GDEF table, = 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Minor version of the GDEF table, = 0, 2 or 3.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Class definition table for glyph type.
+    pub fn glyph_class_def(&self) -> Option<&ClassDef> {
+        self.glyph_class_def.as_ref()
+    }
+
+    /// Attachment point list table.
+    pub fn attach_list(&self) -> Option<&AttachList> {
+        self.attach_list.as_ref()
+    }
+
+    /// Ligature caret list table.
+    pub fn lig_caret_list(&self) -> Option<&LigCaretList> {
+        self.lig_caret_list.as_ref()
+    }
+
+    /// Class definition table for mark attachment type.
+    pub fn mark_attach_class_def(&self) -> Option<&ClassDef> {
+        self.mark_attach_class_def.as_ref()
+    }
+
+    /// Coverage tables of the mark glyph sets, only present since version 1.2.
+    pub fn mark_glyph_sets_def(&self) -> Option<&[Coverage]> {
+        self.mark_glyph_sets_def.as_deref()
+    }
+
+    /// Item variation store table, only present in version 1.3.
+    pub fn item_var_store(&self) -> Option<&ItemVariationStore> {
+        self.item_var_store.as_ref()
+    }
+
+    /// Class of the given glyph, None if the glyph is not assigned a class.
+    pub fn glyph_class(&self, glyph_id: GlyphId) -> Option<GlyphClass> {
+        match self.glyph_class_def.as_ref()?.class(glyph_id) {
+            1 => Some(GlyphClass::Base),
+            2 => Some(GlyphClass::Ligature),
+            3 => Some(GlyphClass::Mark),
+            4 => Some(GlyphClass::Component),
+            _ => None
+        }
+    }
+
+    /// Mark attachment class of the given glyph, 0 if the glyph is not assigned a class.
+    pub fn mark_attachment_class(&self, glyph_id: GlyphId) -> u16 {
+        self.mark_attach_class_def.as_ref().map_or(0, |mark_attach_class_def| mark_attach_class_def.class(glyph_id))
+    }
+
+    /// Whether the given glyph belongs to the mark glyph set at the given index.
+    pub fn is_mark_glyph_set_member(&self, mark_glyph_set_index: u16, glyph_id: GlyphId) -> bool {
+        self.mark_glyph_sets_def.as_ref()
+            .and_then(|mark_glyph_sets_def| mark_glyph_sets_def.get(usize::from(mark_glyph_set_index)))
+            .is_some_and(|coverage| coverage.contains(glyph_id))
+    }
+
+    /// Contour point indices of the attachment points of the given glyph.
+    pub fn attach_points(&self, glyph_id: GlyphId) -> Option<&[u16]> {
+        self.attach_list.as_ref()?.attach_points(glyph_id)
+    }
+
+    /// Caret values of the given ligature glyph, one for each caret position between its
+    /// components.
+    pub fn ligature_carets(&self, glyph_id: GlyphId) -> Option<&[CaretValue]> {
+        self.lig_caret_list.as_ref()?.caret_values(glyph_id)
+    }
+}
+
+impl_parse!(
+    /// Parse Glyph Definition Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::tables::TableTag;
+    /// use otf::tables::gdef::{GlyphClass, GlyphDefinitionTable};
+    /// use otf::parser::Parse;
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let gdef = GlyphDefinitionTable::parse(font.table(TableTag::Gdef).unwrap().as_slice().unwrap()).unwrap().1;
+    ///
+    /// assert_eq!(gdef.major_version(), 1);
+    /// assert_eq!(gdef.minor_version(), 2);
+    ///
+    /// assert_eq!(gdef.glyph_class(font.glyph_index('a').unwrap()), Some(GlyphClass::Base));
+    /// assert_eq!(gdef.glyph_class(font.glyph_index('\u{301}').unwrap()), Some(GlyphClass::Mark));
+    /// assert_eq!(gdef.glyph_class(font.glyph_index('\u{FB01}').unwrap()), Some(GlyphClass::Ligature));
+    /// ```
+    GlyphDefinitionTable, parse_glyph_definition_table
+);
+
+/// Glyph class definitions of the GlyphClassDef table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GlyphClass {
+    /// Base glyph (single character, spacing glyph)
+    Base,
+    /// Ligature glyph (multiple character, spacing glyph)
+    Ligature,
+    /// Mark glyph (non-spacing combining glyph)
+    Mark,
+    /// Component glyph (part of single character, spacing glyph)
+    Component
+}
+
+/// Attachment Point List Table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AttachList {
+    coverage: Coverage,
+    attach_points: Vec<Vec<u16>>
+}
+
+impl AttachList {
+    /// Coverage of the glyphs with attachment points.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of AttachPoint tables, in Coverage index order, each one being an array of contour
+    /// point indices in increasing numerical order.
+    pub fn attach_point_tables(&self) -> &[Vec<u16>] {
+        &self.attach_points
+    }
+
+    /// Contour point indices of the attachment points of the given glyph.
+    pub fn attach_points(&self, glyph_id: GlyphId) -> Option<&[u16]> {
+        let coverage_index = self.coverage.coverage_index(glyph_id)?;
+        self.attach_points.get(usize::from(coverage_index)).map(Vec::as_slice)
+    }
+}
+
+/// Ligature Caret List Table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LigCaretList {
+    coverage: Coverage,
+    lig_glyphs: Vec<Vec<CaretValue>>
+}
+
+impl LigCaretList {
+    /// Coverage of the ligature glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of LigGlyph tables, in Coverage index order, each one being an array of caret
+    /// values in increasing coordinate order.
+    pub fn lig_glyphs(&self) -> &[Vec<CaretValue>] {
+        &self.lig_glyphs
+    }
+
+    /// Caret values of the given ligature glyph.
+    pub fn caret_values(&self, glyph_id: GlyphId) -> Option<&[CaretValue]> {
+        let coverage_index = self.coverage.coverage_index(glyph_id)?;
+        self.lig_glyphs.get(usize::from(coverage_index)).map(Vec::as_slice)
+    }
+}
+
+/// Caret Value Table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CaretValue {
+    /// X or Y value, in design units.
+    Format1(i16),
+    /// Contour point index on ligature glyph.
+    Format2(u16),
+    /// X or Y value, in design units, with a Device table (non-variable font) / VariationIndex
+    /// table (variable font).
+    Format3(i16, Device)
+}
+
+pub fn parse_glyph_definition_table(input: &[u8]) -> IResult<&[u8], GlyphDefinitionTable>
+{
+    let (remainder, major_version) = be_u16(input)?;
+    let (remainder, minor_version) = be_u16(remainder)?;
+
+    if major_version != 1 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (remainder, glyph_class_def_offset) = be_u16(remainder)?;
+    let (remainder, attach_list_offset) = be_u16(remainder)?;
+    let (remainder, lig_caret_list_offset) = be_u16(remainder)?;
+    let (remainder, mark_attach_class_def_offset) = be_u16(remainder)?;
+
+    let (remainder, mark_glyph_sets_def_offset) = if minor_version >= 2 {
+        be_u16(remainder)?
+    }
+    else {
+        (remainder, 0)
+    };
+
+    let (remainder, item_var_store_offset) = if minor_version >= 3 {
+        be_u32(remainder)?
+    }
+    else {
+        (remainder, 0)
+    };
+
+    Ok((remainder, GlyphDefinitionTable {
+        major_version,
+        minor_version,
+        glyph_class_def: parse_nullable(input, usize::from(glyph_class_def_offset), parse_class_def)?,
+        attach_list: parse_nullable(input, usize::from(attach_list_offset), parse_attach_list)?,
+        lig_caret_list: parse_nullable(input, usize::from(lig_caret_list_offset), parse_lig_caret_list)?,
+        mark_attach_class_def: parse_nullable(input, usize::from(mark_attach_class_def_offset), parse_class_def)?,
+        mark_glyph_sets_def: parse_nullable(input, usize::from(mark_glyph_sets_def_offset), parse_mark_glyph_sets_def)?,
+        item_var_store: parse_nullable(input, item_var_store_offset as usize, parse_item_variation_store)?
+    }))
+}
+
+fn parse_attach_list(input: &[u8]) -> IResult<&[u8], AttachList>
+{
+    let (remainder, coverage_offset) = be_u16(input)?;
+    let (remainder, attach_point_offsets) = parse_array16(remainder)?;
+
+    Ok((remainder, AttachList {
+        coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+        attach_points: parse_offsets(input, &attach_point_offsets, parse_array16)?
+    }))
+}
+
+fn parse_lig_caret_list(input: &[u8]) -> IResult<&[u8], LigCaretList>
+{
+    let (remainder, coverage_offset) = be_u16(input)?;
+    let (remainder, lig_glyph_offsets) = parse_array16(remainder)?;
+
+    Ok((remainder, LigCaretList {
+        coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+        lig_glyphs: parse_offsets(input, &lig_glyph_offsets, parse_lig_glyph)?
+    }))
+}
+
+fn parse_lig_glyph(input: &[u8]) -> IResult<&[u8], Vec<CaretValue>>
+{
+    let (remainder, caret_value_offsets) = parse_array16(input)?;
+
+    Ok((remainder, parse_offsets(input, &caret_value_offsets, parse_caret_value)?))
+}
+
+pub fn parse_caret_value(input: &[u8]) -> IResult<&[u8], CaretValue>
+{
+    let (remainder, caret_value_format) = be_u16(input)?;
+
+    match caret_value_format {
+        1 => {
+            let (remainder, coordinate) = be_i16(remainder)?;
+            Ok((remainder, CaretValue::Format1(coordinate)))
+        },
+        2 => {
+            let (remainder, caret_value_point_index) = be_u16(remainder)?;
+            Ok((remainder, CaretValue::Format2(caret_value_point_index)))
+        },
+        3 => {
+            let (remainder, coordinate) = be_i16(remainder)?;
+            let (remainder, device_offset) = be_u16(remainder)?;
+            let (_, device) = parse_device(offset_slice(input, usize::from(device_offset))?)?;
+
+            Ok((remainder, CaretValue::Format3(coordinate, device)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_mark_glyph_sets_def(input: &[u8]) -> IResult<&[u8], Vec<Coverage>>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, mark_glyph_set_count) = be_u16(remainder)?;
+    let (remainder, coverage_offsets) = count(be_u32, usize::from(mark_glyph_set_count))(remainder)?;
+
+    let coverages = coverage_offsets.iter()
+        .map(|&coverage_offset| Ok(parse_coverage(offset_slice(input, coverage_offset as usize)?)?.1))
+        .collect::<Result<Vec<Coverage>, _>>()?;
+
+    Ok((remainder, coverages))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otff::OpenTypeFontFile;
+    use tables::TableTag;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf");
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    fn glyph_definition_table(buf: &[u8]) -> GlyphDefinitionTable {
+        let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+        parse_glyph_definition_table(font.table(TableTag::Gdef).unwrap().as_slice().unwrap()).unwrap().1
+    }
+
+    #[test]
+    fn case_glyph_definition_table_mark_glyph_sets() {
+        let gdef = glyph_definition_table(ROBOTO_REGULAR);
+
+        assert!(gdef.attach_list().is_none());
+        assert!(gdef.lig_caret_list().is_none());
+        assert!(gdef.mark_attach_class_def().is_none());
+        assert!(gdef.item_var_store().is_none());
+        assert_eq!(gdef.mark_glyph_sets_def().map(|mark_glyph_sets_def| mark_glyph_sets_def.len()), Some(2));
+
+        // U+0301 COMBINING ACUTE ACCENT and 'a'
+        assert!(gdef.is_mark_glyph_set_member(0, 169));
+        assert!(!gdef.is_mark_glyph_set_member(0, 69));
+        assert!(!gdef.is_mark_glyph_set_member(2, 169));
+
+        assert_eq!(gdef.mark_attachment_class(169), 0);
+        assert_eq!(gdef.ligature_carets(444), None);
+    }
+
+    #[test]
+    fn case_glyph_definition_table_mark_attach_class_def() {
+        let gdef = glyph_definition_table(SOURCE_SERIF_PRO_REGULAR);
+
+        assert_eq!(gdef.minor_version(), 0);
+        assert!(gdef.mark_glyph_sets_def().is_none());
+
+        // U+0301 COMBINING ACUTE ACCENT and 'a'
+        assert_eq!(gdef.glyph_class(775), Some(GlyphClass::Mark));
+        assert_eq!(gdef.mark_attachment_class(775), 1);
+        assert_eq!(gdef.glyph_class(28), Some(GlyphClass::Base));
+        assert_eq!(gdef.mark_attachment_class(28), 0);
+    }
+
+    #[test]
+    fn case_glyph_definition_table_lig_carets() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x06,
+            0x00, 0x01, 0x00, 0x0C, 0x00, 0x01, 0x00, 0x01, 0x00, 0x05, 0x00, 0x01, 0x00, 0x06,
+            0x00, 0x06, 0x00, 0x01, 0x00, 0x0C, 0x00, 0x01, 0x00, 0x01, 0x00, 0x10, 0x00, 0x02,
+            0x00, 0x06, 0x00, 0x0A, 0x00, 0x01, 0x02, 0x58, 0x00, 0x02, 0x00, 0x05];
+
+        let gdef = parse_glyph_definition_table(bytes).unwrap().1;
+
+        assert_eq!(gdef.glyph_class_def(), None);
+        assert_eq!(gdef.glyph_class(16), None);
+
+        assert_eq!(gdef.attach_points(5), Some(&[6][..]));
+        assert_eq!(gdef.attach_points(16), None);
+
+        assert_eq!(gdef.ligature_carets(16), Some(&[CaretValue::Format1(600), CaretValue::Format2(5)][..]));
+        assert_eq!(gdef.ligature_carets(5), None);
+    }
+
+    #[test]
+    fn case_glyph_definition_table_invalid_version() {
+        let bytes: &[u8]  = &[0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        let expected = Err(NomErr::Error(error_position!(bytes, ErrorKind::Verify)));
+        assert_eq!(parse_glyph_definition_table(bytes), expected);
+    }
+}
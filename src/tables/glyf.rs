@@ -0,0 +1,987 @@
+use error::Error;
+use nom::IResult;
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{be_i8, be_u8, be_i16, be_u16};
+use outline::{BoundingBoxBuilder, OutlineBuilder};
+use tables::GlyphId;
+use tables::loca::IndexToLocationTable;
+use types::{F2Dot14, Rect};
+
+/// Glyph Data
+///
+/// This table contains information that describes the glyphs in the font in the TrueType outline
+/// format. Information regarding the rasterizer (scaler) refers to the TrueType rasterizer.
+///
+/// The 'glyf' table is comprised of a list of glyph data blocks, each of which provides the
+/// description for a single glyph. Glyphs are referenced by identifiers (glyph IDs), which are
+/// sequential integers beginning at zero. The total number of glyphs is specified by the
+/// numGlyphs field in the 'maxp' table. The 'glyf' table does not include any overall table
+/// header or records providing offsets to glyph data blocks. Rather, the 'loca' table provides an
+/// array of offsets, indexed by glyph IDs, which provide the location of each glyph data block
+/// within the 'glyf' table. Note that the 'glyf' table must always be used in conjunction with
+/// the 'loca' and 'maxp' tables. The size of each glyph data block is inferred from the
+/// difference between two consecutive offsets in the 'loca' table (with one extra offset provided
+/// to give the size of the last glyph data block). As a result of the 'loca' format, glyph data
+/// blocks within the 'glyf' table must be in glyph ID order.
+///
+/// More information on ['glyf'](https://docs.microsoft.com/en-gb/typography/opentype/spec/glyf)
+pub struct GlyphDataTable<'otf> {
+    buf: &'otf[u8],
+    loca: IndexToLocationTable
+}
+
+impl<'otf> GlyphDataTable<'otf> {
+    /// The 'loca' table used to locate the glyph data blocks.
+    pub fn loca(&self) -> &IndexToLocationTable {
+        &self.loca
+    }
+
+    /// Number of glyphs that can be located in this table.
+    pub fn num_glyphs(&self) -> usize {
+        self.loca.len().saturating_sub(1)
+    }
+
+    /// Parse the glyph data block of the given glyph.
+    ///
+    /// Return None if the glyph has no outline (e.g. the space character), or an error if the
+    /// glyph ID is out of bounds or the glyph data block is malformed.
+    pub fn glyph(&self, glyph_id: GlyphId) -> Result<Option<Glyph<'otf>>, Error> {
+        let range = self.loca.get_glyf_range(u32::from(glyph_id))
+            .ok_or_else(|| Error::new(format!("Glyph {} out of bounds", glyph_id)))?;
+
+        if range.start == range.end {
+            return Ok(None);
+        }
+
+        let glyph_buf = self.buf.get(range.start as usize..range.end as usize)
+            .ok_or_else(|| Error::new(format!("Glyph {} data out of bounds", glyph_id)))?;
+
+        Ok(Some(parse_glyph(glyph_buf)?.1))
+    }
+
+    /// Send the outline of the given glyph to the builder, the quadratic contours of the glyph
+    /// being converted to quad_to commands and the components of a composite glyph being
+    /// transformed and positioned.
+    ///
+    /// Return the bounding box of the outline, None if the glyph has no outline (e.g. the space
+    /// character), or an error if the glyph ID is out of bounds or the glyph is malformed.
+    pub fn outline<B: OutlineBuilder>(&self, glyph_id: GlyphId, builder: &mut B) -> Result<Option<Rect<i16>>, Error> {
+        let mut bounding_box_builder = BoundingBoxBuilder::new(builder);
+
+        for contour in self.contours(glyph_id, &mut Vec::new(), &mut 0)? {
+            outline_contour(&contour, &mut bounding_box_builder);
+        }
+
+        Ok(bounding_box_builder.bounding_box())
+    }
+
+    /// Contours of the given glyph, the components of a composite glyph being flattened.
+    ///
+    /// * `path` - The composite glyphs being flattened, from the outermost one.
+    /// * `component_count` - The number of components flattened so far for the outline.
+    fn contours(&self, glyph_id: GlyphId, path: &mut Vec<GlyphId>, component_count: &mut usize)
+        -> Result<Vec<Vec<OutlinePoint>>, Error> {
+        if path.len() > MAX_COMPONENT_DEPTH {
+            return Err(Error::new(format!("Glyph {} components nested too deeply", glyph_id)));
+        }
+
+        if path.contains(&glyph_id) {
+            return Err(Error::new(format!("Glyph {} is a component of itself", glyph_id)));
+        }
+
+        let glyph = match self.glyph(glyph_id)? {
+            Some(glyph) => glyph,
+            None => return Ok(Vec::new())
+        };
+
+        match glyph.description {
+            GlyphDescription::Simple(ref simple_glyph) => Ok(simple_glyph.contours()
+                .map(|contour| contour.iter().map(|point| OutlinePoint {
+                    x: f32::from(point.x),
+                    y: f32::from(point.y),
+                    on_curve: point.on_curve
+                }).collect())
+                .collect()),
+            GlyphDescription::Composite(ref composite_glyph) => {
+                let mut contours: Vec<Vec<OutlinePoint>> = Vec::new();
+
+                path.push(glyph_id);
+
+                for component in &composite_glyph.components {
+                    *component_count += 1;
+                    if *component_count > MAX_COMPONENTS {
+                        return Err(Error::new(format!("Glyph {} has too many components", path[0])));
+                    }
+
+                    let mut component_contours = self.contours(component.glyph_index, path, component_count)?;
+
+                    for point in component_contours.iter_mut().flat_map(|contour| contour.iter_mut()) {
+                        component.transform.apply(point);
+                    }
+
+                    let (dx, dy) = match component.arguments {
+                        ComponentArguments::Offset(x, y) => {
+                            let mut offset = OutlinePoint { x: f32::from(x), y: f32::from(y), on_curve: true };
+
+                            if component.flags.contains(CompositeGlyphFlags::SCALED_COMPONENT_OFFSET) {
+                                component.transform.apply(&mut offset);
+                            }
+
+                            (offset.x, offset.y)
+                        },
+                        ComponentArguments::PointNumbers(parent_point, child_point) => {
+                            let parent_point = contours.iter().flatten().nth(usize::from(parent_point));
+                            let child_point = component_contours.iter().flatten().nth(usize::from(child_point));
+
+                            match (parent_point, child_point) {
+                                (Some(parent_point), Some(child_point)) =>
+                                    (parent_point.x - child_point.x, parent_point.y - child_point.y),
+                                _ => return Err(Error::new(format!("Glyph {} matches points out of bounds", glyph_id)))
+                            }
+                        }
+                    };
+
+                    for point in component_contours.iter_mut().flat_map(|contour| contour.iter_mut()) {
+                        point.x += dx;
+                        point.y += dy;
+                    }
+
+                    contours.append(&mut component_contours);
+
+                    if contours.iter().map(Vec::len).sum::<usize>() > MAX_COMPOSITE_POINTS {
+                        return Err(Error::new(format!("Glyph {} has too many points", glyph_id)));
+                    }
+                }
+
+                path.pop();
+
+                Ok(contours)
+            }
+        }
+    }
+
+    /// Parse Glyph Data Table.
+    ///
+    /// * `loca` - The location of each glyph data block is determined by the
+    ///   [loca](../loca/enum.IndexToLocationTable.html) table, which is itself parsed according
+    ///   to the [indexToLocFormat](../head/struct.FontHeaderTable.html#method.index_to_loc_format)
+    ///   field of the 'head' table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::glyf::{GlyphDataTable, GlyphDescription, GlyphPoint};
+    /// use otf::tables::loca::IndexToLocationTable;
+    /// use otf::types::Rect;
+    ///
+    /// let glyf: &[u8]  = &[
+    ///     0x00, 0x01, 0x00, 0xB7, 0x00, 0x00, 0x01, 0x77, 0x05, 0xB0, 0x00, 0x03, 0x00, 0x00,
+    ///     0x21, 0x23, 0x11, 0x33, 0x01, 0x77, 0xC0, 0xC0, 0x05, 0xB0];
+    ///
+    /// let loca = IndexToLocationTable::Short(vec![0, 0, 12]);
+    /// let glyph_data_table = GlyphDataTable::parse(glyf, loca).unwrap();
+    ///
+    /// assert!(glyph_data_table.glyph(0).unwrap().is_none());
+    ///
+    /// let glyph = glyph_data_table.glyph(1).unwrap().unwrap();
+    /// assert_eq!(glyph.bounding_box(), Rect::new(183, 0, 375, 1456));
+    ///
+    /// match glyph.description() {
+    ///     GlyphDescription::Simple(simple_glyph) => {
+    ///         assert_eq!(simple_glyph.end_pts_of_contours(), &[3]);
+    ///         assert_eq!(simple_glyph.points(), &[
+    ///             GlyphPoint::new(375, 0, true),
+    ///             GlyphPoint::new(183, 0, true),
+    ///             GlyphPoint::new(183, 1456, true),
+    ///             GlyphPoint::new(375, 1456, true)]);
+    ///     },
+    ///     _ => assert!(false)
+    /// }
+    /// ```
+    pub fn parse(buf: &'otf[u8], loca: IndexToLocationTable) -> Result<GlyphDataTable<'otf>, Error> {
+        Ok(GlyphDataTable {
+            buf,
+            loca
+        })
+    }
+}
+
+/// Maximum nesting of composite glyphs.
+const MAX_COMPONENT_DEPTH: usize = 16;
+
+/// Maximum number of components flattened into the outline of a glyph, components used several
+/// times being counted each time.
+const MAX_COMPONENTS: usize = 1024;
+
+/// Maximum number of points of a composite glyph, point numbers being 16-bit (see the
+/// maxCompositePoints field of the 'maxp' table).
+const MAX_COMPOSITE_POINTS: usize = 0xFFFF;
+
+/// A point of a transformed glyph outline.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct OutlinePoint {
+    x: f32,
+    y: f32,
+    on_curve: bool
+}
+
+/// Send a quadratic contour to the builder.
+///
+/// Two consecutive off-curve points imply an on-curve point at their midpoint. The contour
+/// starts at its first on-curve point, or at the implied point between its last and first points
+/// if they are both off-curve.
+fn outline_contour(contour: &[OutlinePoint], builder: &mut dyn OutlineBuilder) {
+    let (first, last) = match (contour.first(), contour.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return
+    };
+
+    let (start, points) = if first.on_curve {
+        (first, &contour[1..])
+    }
+    else if last.on_curve {
+        (last, &contour[..contour.len() - 1])
+    }
+    else {
+        (midpoint(last, first), contour)
+    };
+
+    builder.move_to(start.x, start.y);
+
+    let mut control: Option<OutlinePoint> = None;
+
+    for &point in points {
+        match (point.on_curve, control) {
+            (true, Some(control_point)) => {
+                builder.quad_to(control_point.x, control_point.y, point.x, point.y);
+                control = None;
+            },
+            (true, None) => builder.line_to(point.x, point.y),
+            (false, Some(control_point)) => {
+                let implied = midpoint(control_point, point);
+                builder.quad_to(control_point.x, control_point.y, implied.x, implied.y);
+                control = Some(point);
+            },
+            (false, None) => control = Some(point)
+        }
+    }
+
+    if let Some(control_point) = control {
+        builder.quad_to(control_point.x, control_point.y, start.x, start.y);
+    }
+
+    builder.close();
+}
+
+fn midpoint(a: OutlinePoint, b: OutlinePoint) -> OutlinePoint {
+    OutlinePoint {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+        on_curve: true
+    }
+}
+
+/// Each glyph description begins with a header.
+///
+/// If the number of contours is greater than or equal to zero, this is a simple glyph. If
+/// negative, this is a composite glyph — the value -1 should be used for composite glyphs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Glyph<'otf> {
+    number_of_contours: i16,
+    x_min: i16,
+    y_min: i16,
+    x_max: i16,
+    y_max: i16,
+    description: GlyphDescription<'otf>
+}
+
+impl<'otf> Glyph<'otf> {
+    /// If the number of contours is greater than or equal to zero, this is a simple glyph. If
+    /// negative, this is a composite glyph.
+    pub fn number_of_contours(&self) -> i16 {
+        self.number_of_contours
+    }
+
+    /// Bounding box of the glyph, in font design units.
+    pub fn bounding_box(&self) -> Rect<i16> {
+        Rect::new(self.x_min, self.y_min, self.x_max, self.y_max)
+    }
+
+    /// Simple or composite glyph description.
+    pub fn description(&self) -> &GlyphDescription<'otf> {
+        &self.description
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GlyphDescription<'otf> {
+    Simple(SimpleGlyph<'otf>),
+    Composite(CompositeGlyph<'otf>)
+}
+
+/// Simple glyph description.
+///
+/// This is the table information needed if numberOfContours is greater than or equal to zero,
+/// that is, a glyph is not a composite. Note that point numbers are base-zero indices that are
+/// numbered sequentially across all of the contours for a glyph; that is, the first point number
+/// of each contour (except the first) is one greater than the last point number of the preceding
+/// contour.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SimpleGlyph<'otf> {
+    end_pts_of_contours: Vec<u16>,
+    instructions: &'otf[u8],
+    flags: Vec<SimpleGlyphFlags>,
+    points: Vec<GlyphPoint>
+}
+
+impl<'otf> SimpleGlyph<'otf> {
+    /// Array of point indices for the last point of each contour, in increasing numeric order.
+    pub fn end_pts_of_contours(&self) -> &[u16] {
+        &self.end_pts_of_contours
+    }
+
+    /// Array of instruction byte code for the glyph.
+    pub fn instructions(&self) -> &'otf[u8] {
+        self.instructions
+    }
+
+    /// Array of flag elements, one per point.
+    pub fn flags(&self) -> &[SimpleGlyphFlags] {
+        &self.flags
+    }
+
+    /// Array of points with their absolute coordinates.
+    pub fn points(&self) -> &[GlyphPoint] {
+        &self.points
+    }
+
+    /// Iterator over the points of each contour.
+    pub fn contours(&self) -> Contours<'_> {
+        Contours {
+            end_pts_of_contours: &self.end_pts_of_contours,
+            points: &self.points,
+            start: 0
+        }
+    }
+}
+
+pub struct Contours<'a> {
+    end_pts_of_contours: &'a[u16],
+    points: &'a[GlyphPoint],
+    start: usize
+}
+
+impl<'a> Iterator for Contours<'a> {
+    type Item = &'a[GlyphPoint];
+
+    fn next(&mut self) -> Option<&'a[GlyphPoint]> {
+        let (&end_pt, end_pts_of_contours) = self.end_pts_of_contours.split_first()?;
+        let end = usize::from(end_pt) + 1;
+        let contour = self.points.get(self.start..end)?;
+
+        self.end_pts_of_contours = end_pts_of_contours;
+        self.start = end;
+
+        Some(contour)
+    }
+}
+
+/// A point of a simple glyph outline, in font design units.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GlyphPoint {
+    x: i16,
+    y: i16,
+    on_curve: bool
+}
+
+impl GlyphPoint {
+    pub fn new(x: i16, y: i16, on_curve: bool) -> GlyphPoint {
+        GlyphPoint {
+            x,
+            y,
+            on_curve
+        }
+    }
+
+    /// Absolute x coordinate.
+    pub fn x(&self) -> i16 {
+        self.x
+    }
+
+    /// Absolute y coordinate.
+    pub fn y(&self) -> i16 {
+        self.y
+    }
+
+    /// The point is on the curve, otherwise it is an off-curve (quadratic control) point.
+    pub fn on_curve(&self) -> bool {
+        self.on_curve
+    }
+}
+
+bitflags! {
+    #[doc="Simple glyph flags."]
+    pub struct SimpleGlyphFlags: u8 {
+        /// If set, the point is on the curve; otherwise, it is off the curve.
+        const ON_CURVE_POINT                        = 0b00000001;
+        /// If set, the corresponding x-coordinate is 1 byte long. If not set, it is two bytes
+        /// long.
+        const X_SHORT_VECTOR                        = 0b00000010;
+        /// If set, the corresponding y-coordinate is 1 byte long. If not set, it is two bytes
+        /// long.
+        const Y_SHORT_VECTOR                        = 0b00000100;
+        /// If set, the next byte (read as unsigned) specifies the number of additional times
+        /// this flag byte is to be repeated in the logical flags array.
+        const REPEAT_FLAG                           = 0b00001000;
+        /// If X_SHORT_VECTOR is set, this bit describes the sign of the value, with 1 equalling
+        /// positive and 0 negative. If X_SHORT_VECTOR is not set and this bit is set, then the
+        /// current x-coordinate is the same as the previous x-coordinate.
+        const X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR  = 0b00010000;
+        /// If Y_SHORT_VECTOR is set, this bit describes the sign of the value, with 1 equalling
+        /// positive and 0 negative. If Y_SHORT_VECTOR is not set and this bit is set, then the
+        /// current y-coordinate is the same as the previous y-coordinate.
+        const Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR  = 0b00100000;
+        /// If set, contours in the glyph description may overlap.
+        const OVERLAP_SIMPLE                        = 0b01000000;
+
+        // Bit 7 reserved
+    }
+}
+
+/// Composite glyph description.
+///
+/// Composite glyphs are described by a list of components, each one referencing another glyph
+/// with an offset or a point-matching alignment and an optional scale or 2x2 transformation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CompositeGlyph<'otf> {
+    components: Vec<GlyphComponent>,
+    instructions: &'otf[u8]
+}
+
+impl<'otf> CompositeGlyph<'otf> {
+    /// The components of the composite glyph.
+    pub fn components(&self) -> &[GlyphComponent] {
+        &self.components
+    }
+
+    /// Array of instruction byte code for the composite glyph, empty if the WE_HAVE_INSTRUCTIONS
+    /// flag is not set.
+    pub fn instructions(&self) -> &'otf[u8] {
+        self.instructions
+    }
+}
+
+/// Component glyph record of a composite glyph.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GlyphComponent {
+    flags: CompositeGlyphFlags,
+    glyph_index: GlyphId,
+    arguments: ComponentArguments,
+    transform: ComponentTransform
+}
+
+impl GlyphComponent {
+    /// Component flags.
+    pub fn flags(&self) -> CompositeGlyphFlags {
+        self.flags
+    }
+
+    /// Glyph index of component.
+    pub fn glyph_index(&self) -> GlyphId {
+        self.glyph_index
+    }
+
+    /// Offset or point numbers used to position the component.
+    pub fn arguments(&self) -> ComponentArguments {
+        self.arguments
+    }
+
+    /// Scale or 2x2 transformation applied to the component.
+    pub fn transform(&self) -> ComponentTransform {
+        self.transform
+    }
+}
+
+/// The arguments of a component, interpreted according to the ARGS_ARE_XY_VALUES flag.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ComponentArguments {
+    /// Signed x and y offsets of the component.
+    Offset(i16, i16),
+    /// The first argument is the point number of the parent glyph (the points of the components
+    /// already processed), the second argument is the point number of the child component. The
+    /// component is positioned so that both points match.
+    PointNumbers(u16, u16)
+}
+
+/// The transformation of a component.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ComponentTransform {
+    /// No transformation.
+    Identity,
+    /// The same scale is used for x and y.
+    Scale(F2Dot14),
+    /// Different scales are used for x and y.
+    XYScale(F2Dot14, F2Dot14),
+    /// 2 by 2 transformation (xscale, scale01, scale10, yscale) that will be used to scale the
+    /// component.
+    TwoByTwo(F2Dot14, F2Dot14, F2Dot14, F2Dot14)
+}
+
+impl ComponentTransform {
+    /// Apply the linear transformation to a point.
+    fn apply(&self, point: &mut OutlinePoint) {
+        let f2dot14 = |value: F2Dot14| f32::from(value) / 16384.0;
+
+        let (x_scale, scale01, scale10, y_scale) = match *self {
+            ComponentTransform::Identity => return,
+            ComponentTransform::Scale(scale) => (f2dot14(scale), 0.0, 0.0, f2dot14(scale)),
+            ComponentTransform::XYScale(x_scale, y_scale) => (f2dot14(x_scale), 0.0, 0.0, f2dot14(y_scale)),
+            ComponentTransform::TwoByTwo(x_scale, scale01, scale10, y_scale) =>
+                (f2dot14(x_scale), f2dot14(scale01), f2dot14(scale10), f2dot14(y_scale))
+        };
+
+        let (x, y) = (point.x, point.y);
+        point.x = x_scale * x + scale10 * y;
+        point.y = scale01 * x + y_scale * y;
+    }
+}
+
+bitflags! {
+    #[doc="Component flags."]
+    pub struct CompositeGlyphFlags: u16 {
+        /// If this is set, the arguments are 16-bit (uint16 or int16); otherwise, they are bytes
+        /// (uint8 or int8).
+        const ARG_1_AND_2_ARE_WORDS     = 0b0000000000000001;
+        /// If this is set, the arguments are signed xy values; otherwise, they are unsigned
+        /// point numbers.
+        const ARGS_ARE_XY_VALUES        = 0b0000000000000010;
+        /// For the xy values if the preceding is true.
+        const ROUND_XY_TO_GRID          = 0b0000000000000100;
+        /// This indicates that there is a simple scale for the component. Otherwise, scale = 1.0.
+        const WE_HAVE_A_SCALE           = 0b0000000000001000;
+        /// Indicates at least one more glyph after this one.
+        const MORE_COMPONENTS           = 0b0000000000100000;
+        /// The x direction will use a different scale from the y direction.
+        const WE_HAVE_AN_X_AND_Y_SCALE  = 0b0000000001000000;
+        /// There is a 2 by 2 transformation that will be used to scale the component.
+        const WE_HAVE_A_TWO_BY_TWO      = 0b0000000010000000;
+        /// Following the last component are instructions for the composite character.
+        const WE_HAVE_INSTRUCTIONS      = 0b0000000100000000;
+        /// If set, this forces the aw and lsb (and rsb) for the composite to be equal to those
+        /// from this original glyph.
+        const USE_MY_METRICS            = 0b0000001000000000;
+        /// If set, the components of the compound glyph overlap.
+        const OVERLAP_COMPOUND          = 0b0000010000000000;
+        /// The composite is designed to have the component offset scaled.
+        const SCALED_COMPONENT_OFFSET   = 0b0000100000000000;
+        /// The composite is designed not to have the component offset scaled.
+        const UNSCALED_COMPONENT_OFFSET = 0b0001000000000000;
+
+        // Bits 4, 13, 14 and 15 reserved
+    }
+}
+
+pub fn parse_glyph(input: &[u8]) -> IResult<&[u8], Glyph<'_>>
+{
+    let (input, number_of_contours) = be_i16(input)?;
+    let (input, x_min) = be_i16(input)?;
+    let (input, y_min) = be_i16(input)?;
+    let (input, x_max) = be_i16(input)?;
+    let (input, y_max) = be_i16(input)?;
+
+    let (input, description) = if number_of_contours >= 0 {
+        let (input, simple_glyph) = parse_simple_glyph(input, number_of_contours as u16)?;
+        (input, GlyphDescription::Simple(simple_glyph))
+    }
+    else {
+        let (input, composite_glyph) = parse_composite_glyph(input)?;
+        (input, GlyphDescription::Composite(composite_glyph))
+    };
+
+    Ok((input, Glyph {
+        number_of_contours,
+        x_min,
+        y_min,
+        x_max,
+        y_max,
+        description
+    }))
+}
+
+fn parse_simple_glyph(input: &[u8], number_of_contours: u16) -> IResult<&[u8], SimpleGlyph<'_>>
+{
+    let (input, end_pts_of_contours) = count(be_u16, usize::from(number_of_contours))(input)?;
+    let (input, instruction_length) = be_u16(input)?;
+    let (input, instructions) = take(instruction_length)(input)?;
+
+    let num_points = end_pts_of_contours.last().map_or(0, |&end_pt| usize::from(end_pt) + 1);
+
+    let (input, flags) = parse_simple_glyph_flags(input, num_points)?;
+    let (input, x_coordinates) = parse_simple_glyph_coordinates(input, &flags,
+        SimpleGlyphFlags::X_SHORT_VECTOR, SimpleGlyphFlags::X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR)?;
+    let (input, y_coordinates) = parse_simple_glyph_coordinates(input, &flags,
+        SimpleGlyphFlags::Y_SHORT_VECTOR, SimpleGlyphFlags::Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR)?;
+
+    let points = flags.iter()
+        .zip(x_coordinates.iter().zip(y_coordinates.iter()))
+        .map(|(flag, (&x, &y))| GlyphPoint::new(x, y, flag.contains(SimpleGlyphFlags::ON_CURVE_POINT)))
+        .collect();
+
+    Ok((input, SimpleGlyph {
+        end_pts_of_contours,
+        instructions,
+        flags,
+        points
+    }))
+}
+
+fn parse_simple_glyph_flags(input: &[u8], num_points: usize) -> IResult<&[u8], Vec<SimpleGlyphFlags>>
+{
+    let mut input = input;
+    let mut flags = Vec::with_capacity(num_points);
+
+    while flags.len() < num_points {
+        let (remainder, flag) = be_u8(input)?;
+        let flag = SimpleGlyphFlags::from_bits_truncate(flag);
+        input = remainder;
+        flags.push(flag);
+
+        if flag.contains(SimpleGlyphFlags::REPEAT_FLAG) {
+            let (remainder, repeat_count) = be_u8(input)?;
+            input = remainder;
+
+            for _ in 0..repeat_count {
+                flags.push(flag);
+            }
+        }
+    }
+
+    // A malformed repeat count may produce more flags than points
+    flags.truncate(num_points);
+
+    Ok((input, flags))
+}
+
+fn parse_simple_glyph_coordinates<'a>(input: &'a[u8], flags: &[SimpleGlyphFlags], short_vector: SimpleGlyphFlags,
+                                      is_same_or_positive: SimpleGlyphFlags) -> IResult<&'a[u8], Vec<i16>>
+{
+    let mut input = input;
+    let mut coordinates = Vec::with_capacity(flags.len());
+    let mut coordinate: i16 = 0;
+
+    // Coordinate values are relative to the previous point, the first point is relative to (0,0)
+    for flag in flags {
+        let delta = if flag.contains(short_vector) {
+            let (remainder, delta) = be_u8(input)?;
+            input = remainder;
+
+            if flag.contains(is_same_or_positive) { i16::from(delta) } else { -i16::from(delta) }
+        }
+        else if flag.contains(is_same_or_positive) {
+            0
+        }
+        else {
+            let (remainder, delta) = be_i16(input)?;
+            input = remainder;
+            delta
+        };
+
+        coordinate = coordinate.wrapping_add(delta);
+        coordinates.push(coordinate);
+    }
+
+    Ok((input, coordinates))
+}
+
+fn parse_composite_glyph(input: &[u8]) -> IResult<&[u8], CompositeGlyph<'_>>
+{
+    let mut input = input;
+    let mut components = Vec::new();
+    let mut have_instructions = false;
+
+    loop {
+        let (remainder, component) = parse_glyph_component(input)?;
+        input = remainder;
+        components.push(component);
+
+        if component.flags.contains(CompositeGlyphFlags::WE_HAVE_INSTRUCTIONS) {
+            have_instructions = true;
+        }
+
+        if !component.flags.contains(CompositeGlyphFlags::MORE_COMPONENTS) {
+            break;
+        }
+    }
+
+    let (input, instructions) = if have_instructions {
+        let (input, num_instructions) = be_u16(input)?;
+        take(num_instructions)(input)?
+    }
+    else {
+        (input, &input[..0])
+    };
+
+    Ok((input, CompositeGlyph {
+        components,
+        instructions
+    }))
+}
+
+fn parse_glyph_component(input: &[u8]) -> IResult<&[u8], GlyphComponent>
+{
+    let (input, flags) = be_u16(input)?;
+    let flags = CompositeGlyphFlags::from_bits_truncate(flags);
+    let (input, glyph_index) = be_u16(input)?;
+
+    let (input, arguments) = match (flags.contains(CompositeGlyphFlags::ARG_1_AND_2_ARE_WORDS),
+                                    flags.contains(CompositeGlyphFlags::ARGS_ARE_XY_VALUES)) {
+        (true, true) => {
+            let (input, argument1) = be_i16(input)?;
+            let (input, argument2) = be_i16(input)?;
+            (input, ComponentArguments::Offset(argument1, argument2))
+        },
+        (true, false) => {
+            let (input, argument1) = be_u16(input)?;
+            let (input, argument2) = be_u16(input)?;
+            (input, ComponentArguments::PointNumbers(argument1, argument2))
+        },
+        (false, true) => {
+            let (input, argument1) = be_i8(input)?;
+            let (input, argument2) = be_i8(input)?;
+            (input, ComponentArguments::Offset(i16::from(argument1), i16::from(argument2)))
+        },
+        (false, false) => {
+            let (input, argument1) = be_u8(input)?;
+            let (input, argument2) = be_u8(input)?;
+            (input, ComponentArguments::PointNumbers(u16::from(argument1), u16::from(argument2)))
+        }
+    };
+
+    let (input, transform) = if flags.contains(CompositeGlyphFlags::WE_HAVE_A_SCALE) {
+        let (input, scale) = be_i16(input)?;
+        (input, ComponentTransform::Scale(scale))
+    }
+    else if flags.contains(CompositeGlyphFlags::WE_HAVE_AN_X_AND_Y_SCALE) {
+        let (input, x_scale) = be_i16(input)?;
+        let (input, y_scale) = be_i16(input)?;
+        (input, ComponentTransform::XYScale(x_scale, y_scale))
+    }
+    else if flags.contains(CompositeGlyphFlags::WE_HAVE_A_TWO_BY_TWO) {
+        let (input, x_scale) = be_i16(input)?;
+        let (input, scale01) = be_i16(input)?;
+        let (input, scale10) = be_i16(input)?;
+        let (input, y_scale) = be_i16(input)?;
+        (input, ComponentTransform::TwoByTwo(x_scale, scale01, scale10, y_scale))
+    }
+    else {
+        (input, ComponentTransform::Identity)
+    };
+
+    Ok((input, GlyphComponent {
+        flags,
+        glyph_index,
+        arguments,
+        transform
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Err;
+    use nom::error::ErrorKind;
+    use outline::tests::PathRecorder;
+    use parser::Parse;
+    use tables::head::FontHeaderTable;
+    use tables::maxp::MaximumProfileTable;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf");
+
+    fn roboto_glyph_data_table() -> GlyphDataTable<'static> {
+        let font_header_table = FontHeaderTable::parse(&ROBOTO_REGULAR[300..354]).unwrap().1;
+        let maximum_profile_table = MaximumProfileTable::parse(&ROBOTO_REGULAR[392..424]).unwrap().1;
+        let loca = IndexToLocationTable::parse(&ROBOTO_REGULAR[12540..15130],
+            font_header_table.index_to_loc_format(), maximum_profile_table.num_glyphs()).unwrap();
+
+        GlyphDataTable::parse(&ROBOTO_REGULAR[15132..140424], loca).unwrap()
+    }
+
+    #[test]
+    fn case_glyph_data_table_simple_glyph() {
+        let glyph_data_table = roboto_glyph_data_table();
+        assert_eq!(glyph_data_table.num_glyphs(), 1294);
+
+        // Glyph 'o'
+        let glyph = glyph_data_table.glyph(83).unwrap().unwrap();
+
+        assert_eq!(glyph.number_of_contours(), 2);
+        assert_eq!(glyph.bounding_box(), Rect::new(91, -20, 1076, 1102));
+
+        match glyph.description() {
+            GlyphDescription::Simple(simple_glyph) => {
+                assert_eq!(simple_glyph.end_pts_of_contours(), &[15, 27]);
+                assert_eq!(simple_glyph.instructions().len(), 69);
+                assert_eq!(simple_glyph.points().len(), 28);
+                assert_eq!(&simple_glyph.points()[..6], &[
+                    GlyphPoint::new(91, 551, true),
+                    GlyphPoint::new(91, 710, false),
+                    GlyphPoint::new(216, 964, false),
+                    GlyphPoint::new(439, 1102, false),
+                    GlyphPoint::new(582, 1102, true),
+                    GlyphPoint::new(803, 1102, false)]);
+
+                let contours: Vec<usize> = simple_glyph.contours().map(|contour| contour.len()).collect();
+                assert_eq!(contours, vec![16, 12]);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_data_table_composite_glyph() {
+        let glyph_data_table = roboto_glyph_data_table();
+
+        // Glyph 'Á'
+        let glyph = glyph_data_table.glyph(640).unwrap().unwrap();
+
+        assert_eq!(glyph.number_of_contours(), -1);
+        assert_eq!(glyph.bounding_box(), Rect::new(28, 0, 1309, 1846));
+
+        match glyph.description() {
+            GlyphDescription::Composite(composite_glyph) => {
+                let components = composite_glyph.components();
+
+                assert_eq!(components.len(), 2);
+                assert_eq!(components[0].glyph_index(), 37);
+                assert_eq!(components[0].arguments(), ComponentArguments::Offset(0, 0));
+                assert_eq!(components[0].transform(), ComponentTransform::Identity);
+                assert!(components[0].flags().contains(CompositeGlyphFlags::USE_MY_METRICS));
+                assert_eq!(components[1].glyph_index(), 117);
+                assert_eq!(components[1].arguments(), ComponentArguments::Offset(447, 310));
+                assert!(!components[1].flags().contains(CompositeGlyphFlags::MORE_COMPONENTS));
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_data_table_empty_glyph() {
+        let glyph_data_table = roboto_glyph_data_table();
+
+        // Glyph ' '
+        assert!(glyph_data_table.glyph(4).unwrap().is_none());
+        assert!(glyph_data_table.glyph(1294).is_err());
+    }
+
+    #[test]
+    fn case_glyph_composite_scale() {
+        let bytes: &[u8]  = &[
+            0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64, 0x00, 0x64, 0x00, 0x2A, 0x00, 0x05,
+            0x0A, 0xF6, 0x20, 0x00, 0x00, 0x80, 0x00, 0x06, 0x01, 0x02, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xC0, 0x00];
+
+        let glyph = parse_glyph(bytes).unwrap().1;
+
+        match glyph.description() {
+            GlyphDescription::Composite(composite_glyph) => {
+                let components = composite_glyph.components();
+
+                assert_eq!(components.len(), 2);
+                assert_eq!(components[0].glyph_index(), 5);
+                assert_eq!(components[0].arguments(), ComponentArguments::Offset(10, -10));
+                assert_eq!(components[0].transform(), ComponentTransform::Scale(0x2000));
+                assert_eq!(components[1].glyph_index(), 6);
+                assert_eq!(components[1].arguments(), ComponentArguments::PointNumbers(1, 2));
+                assert_eq!(components[1].transform(), ComponentTransform::TwoByTwo(0x4000, 0, 0, -0x4000));
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_invalid_empty_slice() {
+        let bytes: &[u8] = &[];
+
+        let expected = Err(Err::Error(error_position!(bytes, ErrorKind::Eof)));
+        assert_eq!(parse_glyph(bytes), expected);
+    }
+
+    #[test]
+    fn case_glyph_data_table_outline() {
+        let glyph_data_table = roboto_glyph_data_table();
+
+        // Glyph 'o'
+        let mut recorder = PathRecorder::default();
+        assert_eq!(glyph_data_table.outline(83, &mut recorder).unwrap(), Some(Rect::new(91, -20, 1076, 1102)));
+        assert_eq!(&recorder.commands[..4], &[
+            "M 91 551", "Q 91 710 153.5 837", "Q 216 964 327.5 1033", "Q 439 1102 582 1102"]);
+        assert_eq!(recorder.commands.iter().filter(|command| *command == "Z").count(), 2);
+
+        // Glyph 'Á' made of 'A' and 'acute' moved by (447, 310)
+        assert_eq!(glyph_data_table.outline(640, &mut PathRecorder::default()).unwrap(),
+                   Some(Rect::new(28, 0, 1309, 1846)));
+
+        // Glyph 'space'
+        assert_eq!(glyph_data_table.outline(4, &mut PathRecorder::default()).unwrap(), None);
+        assert!(glyph_data_table.outline(1294, &mut PathRecorder::default()).is_err());
+    }
+
+    #[test]
+    fn case_glyph_data_table_outline_matching_points() {
+        // Glyph 2 is made of glyph 1, and of glyph 1 scaled by 0.5 whose point 0 matches the
+        // point 2 of the first component
+        let glyf: &[u8]  = &[
+            0x00, 0x01, 0x00, 0xB7, 0x00, 0x00, 0x01, 0x77, 0x05, 0xB0, 0x00, 0x03, 0x00, 0x00,
+            0x21, 0x23, 0x11, 0x33, 0x01, 0x77, 0xC0, 0xC0, 0x05, 0xB0, 0xFF, 0xFF, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08,
+            0x00, 0x01, 0x02, 0x00, 0x20, 0x00];
+
+        let glyph_data_table = GlyphDataTable::parse(glyf, IndexToLocationTable::Short(vec![0, 0, 12, 24])).unwrap();
+
+        let mut recorder = PathRecorder::default();
+        assert_eq!(glyph_data_table.outline(2, &mut recorder).unwrap(), Some(Rect::new(87, 0, 375, 2184)));
+        assert_eq!(recorder.commands, vec![
+            "M 375 0", "L 183 0", "L 183 1456", "L 375 1456", "Z",
+            "M 183 1456", "L 87 1456", "L 87 2184", "L 183 2184", "Z"]);
+    }
+
+    #[test]
+    fn case_glyph_data_table_outline_recursive_components() {
+        // Glyph 0 is made of itself
+        let glyf: &[u8]  = &[
+            0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0x00, 0x00];
+
+        let glyph_data_table = GlyphDataTable::parse(glyf, IndexToLocationTable::Short(vec![0, 8])).unwrap();
+        assert!(glyph_data_table.outline(0, &mut PathRecorder::default()).is_err());
+
+        // Each glyph is made of the next one twice, the last one being empty
+        let mut glyf: Vec<u8> = Vec::new();
+        for glyph_id in 1..17u8 {
+            glyf.extend(&[0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+            glyf.extend(&[0x00, 0x22, 0x00, glyph_id, 0x00, 0x00, 0x00, 0x02, 0x00, glyph_id, 0x00, 0x00]);
+        }
+
+        let loca = IndexToLocationTable::Short((0..18).map(|glyph_id| 11 * glyph_id.min(16)).collect());
+        let glyph_data_table = GlyphDataTable::parse(&glyf, loca).unwrap();
+
+        assert_eq!(glyph_data_table.outline(15, &mut PathRecorder::default()).unwrap(), None);
+        assert!(glyph_data_table.outline(0, &mut PathRecorder::default()).is_err());
+    }
+
+    #[test]
+    fn case_outline_contour_off_curve_points() {
+        let contour: Vec<OutlinePoint> = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)].iter()
+            .map(|&(x, y)| OutlinePoint { x, y, on_curve: false })
+            .collect();
+
+        let mut recorder = PathRecorder::default();
+        outline_contour(&contour, &mut recorder);
+
+        assert_eq!(recorder.commands, vec![
+            "M 0 50", "Q 0 0 50 0", "Q 100 0 100 50", "Q 100 100 50 100", "Q 0 100 0 50", "Z"]);
+    }
+}
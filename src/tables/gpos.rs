@@ -0,0 +1,1253 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_i16, be_u16, be_u32};
+use tables::GlyphId;
+use tables::layout::{ChainedSequenceContext, ClassDef, Coverage, Device, FeatureList, LookupList, ScriptList,
+                     SequenceContext, offset_slice, parse_array16, parse_chained_sequence_context, parse_class_def,
+                     parse_coverage, parse_device, parse_feature_list, parse_lookup_list, parse_nullable,
+                     parse_offsets, parse_script_list, parse_sequence_context, verify_format};
+
+/// Glyph Positioning Table
+///
+/// The Glyph Positioning table (GPOS) provides precise control over glyph placement for
+/// sophisticated text layout and rendering in each script and language system that a font
+/// supports.
+///
+/// The scripts and language systems of the ScriptList select the features of the FeatureList,
+/// which reference the lookups of the LookupList applied to the glyph sequence.
+///
+/// More information on ['GPOS'](https://docs.microsoft.com/en-gb/typography/opentype/spec/gpos)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GlyphPositioningTable {
+    major_version: u16,
+    minor_version: u16,
+    script_list: ScriptList,
+    feature_list: FeatureList,
+    lookup_list: LookupList<PositioningSubtable>,
+    feature_variations_offset: Option<u32>
+}
+
+impl GlyphPositioningTable {
+    /// Major version of the GPOS table, = 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Minor version of the GPOS table, = 0 or 1.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Scripts and language systems supported by the font.
+    pub fn script_list(&self) -> &ScriptList {
+        &self.script_list
+    }
+
+    /// Features of the font.
+    pub fn feature_list(&self) -> &FeatureList {
+        &self.feature_list
+    }
+
+    /// Lookups of the font.
+    pub fn lookup_list(&self) -> &LookupList<PositioningSubtable> {
+        &self.lookup_list
+    }
+
+    /// Offset to FeatureVariations table, from beginning of the GPOS table (may be NULL), only
+    /// present in version 1.1.
+    pub fn feature_variations_offset(&self) -> Option<u32> {
+        self.feature_variations_offset
+    }
+}
+
+impl_parse!(
+    /// Parse Glyph Positioning Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::tables::{Tag, TableTag};
+    /// use otf::tables::gpos::{GlyphPositioningTable, PositioningSubtable};
+    /// use otf::parser::Parse;
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let gpos = GlyphPositioningTable::parse(font.table(TableTag::Gpos).unwrap().as_slice().unwrap()).unwrap().1;
+    ///
+    /// let lang_sys = gpos.script_list().script(Tag::new(b"latn")).unwrap().lang_sys(None).unwrap();
+    /// let lookup_indices = gpos.feature_list().lookup_indices(lang_sys, Tag::new(b"kern"));
+    ///
+    /// let (first_glyph, second_glyph) = (font.glyph_index('A').unwrap(), font.glyph_index('V').unwrap());
+    ///
+    /// let (value_record1, _) = lookup_indices.iter()
+    ///     .filter_map(|&lookup_index| gpos.lookup_list().lookup(lookup_index))
+    ///     .flat_map(|lookup| lookup.subtables())
+    ///     .filter_map(|subtable| match subtable {
+    ///         PositioningSubtable::Pair(pair_positioning) => pair_positioning.pair(first_glyph, second_glyph),
+    ///         _ => None
+    ///     })
+    ///     .next()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value_record1.x_advance(), -87);
+    /// ```
+    GlyphPositioningTable, parse_glyph_positioning_table
+);
+
+bitflags! {
+    /// Specifies the fields present in a ValueRecord.
+    pub struct ValueFormat: u16 {
+        #[doc="Includes horizontal adjustment for placement"]
+        const X_PLACEMENT = 0x0001;
+        #[doc="Includes vertical adjustment for placement"]
+        const Y_PLACEMENT = 0x0002;
+        #[doc="Includes horizontal adjustment for advance"]
+        const X_ADVANCE = 0x0004;
+        #[doc="Includes vertical adjustment for advance"]
+        const Y_ADVANCE = 0x0008;
+        #[doc="Includes Device table (non-variable font) / VariationIndex table (variable font) for horizontal placement"]
+        const X_PLACEMENT_DEVICE = 0x0010;
+        #[doc="Includes Device table (non-variable font) / VariationIndex table (variable font) for vertical placement"]
+        const Y_PLACEMENT_DEVICE = 0x0020;
+        #[doc="Includes Device table (non-variable font) / VariationIndex table (variable font) for horizontal advance"]
+        const X_ADVANCE_DEVICE = 0x0040;
+        #[doc="Includes Device table (non-variable font) / VariationIndex table (variable font) for vertical advance"]
+        const Y_ADVANCE_DEVICE = 0x0080;
+    }
+}
+
+/// Value Record
+///
+/// Adjustments to the position of a glyph. Fields missing from the ValueFormat are zero and
+/// missing Device tables are None.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ValueRecord {
+    x_placement: i16,
+    y_placement: i16,
+    x_advance: i16,
+    y_advance: i16,
+    x_placement_device: Option<Device>,
+    y_placement_device: Option<Device>,
+    x_advance_device: Option<Device>,
+    y_advance_device: Option<Device>
+}
+
+impl ValueRecord {
+    /// Horizontal adjustment for placement, in design units.
+    pub fn x_placement(&self) -> i16 {
+        self.x_placement
+    }
+
+    /// Vertical adjustment for placement, in design units.
+    pub fn y_placement(&self) -> i16 {
+        self.y_placement
+    }
+
+    /// Horizontal adjustment for advance, in design units — only used for horizontal layout.
+    pub fn x_advance(&self) -> i16 {
+        self.x_advance
+    }
+
+    /// Vertical adjustment for advance, in design units — only used for vertical layout.
+    pub fn y_advance(&self) -> i16 {
+        self.y_advance
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for horizontal
+    /// placement.
+    pub fn x_placement_device(&self) -> Option<&Device> {
+        self.x_placement_device.as_ref()
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for vertical
+    /// placement.
+    pub fn y_placement_device(&self) -> Option<&Device> {
+        self.y_placement_device.as_ref()
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for horizontal
+    /// advance.
+    pub fn x_advance_device(&self) -> Option<&Device> {
+        self.x_advance_device.as_ref()
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for vertical
+    /// advance.
+    pub fn y_advance_device(&self) -> Option<&Device> {
+        self.y_advance_device.as_ref()
+    }
+}
+
+/// Anchor Table
+///
+/// A position, in design units, used to attach a glyph to another one. Format 2 anchors give a
+/// contour point of the glyph outline and format 3 anchors give Device tables.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Anchor {
+    x_coordinate: i16,
+    y_coordinate: i16,
+    anchor_point: Option<u16>,
+    x_device: Option<Device>,
+    y_device: Option<Device>
+}
+
+impl Anchor {
+    /// Horizontal value, in design units.
+    pub fn x_coordinate(&self) -> i16 {
+        self.x_coordinate
+    }
+
+    /// Vertical value, in design units.
+    pub fn y_coordinate(&self) -> i16 {
+        self.y_coordinate
+    }
+
+    /// Index to glyph contour point (format 2).
+    pub fn anchor_point(&self) -> Option<u16> {
+        self.anchor_point
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for X coordinate
+    /// (format 3).
+    pub fn x_device(&self) -> Option<&Device> {
+        self.x_device.as_ref()
+    }
+
+    /// Device table (non-variable font) / VariationIndex table (variable font) for Y coordinate
+    /// (format 3).
+    pub fn y_device(&self) -> Option<&Device> {
+        self.y_device.as_ref()
+    }
+}
+
+/// A subtable of a GPOS lookup.
+///
+/// Extension subtables (lookup type 9) are resolved to the subtable they point to, the lookup
+/// type of the lookup still being 9.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PositioningSubtable {
+    /// Lookup type 1: adjust position of a single glyph.
+    Single(SinglePositioning),
+    /// Lookup type 2: adjust position of a pair of glyphs.
+    Pair(PairPositioning),
+    /// Lookup type 3: attach cursive glyphs.
+    Cursive(CursivePositioning),
+    /// Lookup type 4: attach a combining mark to a base glyph.
+    MarkToBase(MarkBasePositioning),
+    /// Lookup type 5: attach a combining mark to a ligature.
+    MarkToLigature(MarkLigaturePositioning),
+    /// Lookup type 6: attach a combining mark to another mark.
+    MarkToMark(MarkMarkPositioning),
+    /// Lookup type 7: position one or more glyphs in context.
+    Context(SequenceContext),
+    /// Lookup type 8: position one or more glyphs in chained context.
+    ChainedContext(ChainedSequenceContext)
+}
+
+/// Single Adjustment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SinglePositioning {
+    /// A single value record applied to all the covered glyphs.
+    Format1(SinglePositioningFormat1),
+    /// Value records listed in coverage index order.
+    Format2(SinglePositioningFormat2)
+}
+
+impl SinglePositioning {
+    /// Coverage of the glyphs to adjust.
+    pub fn coverage(&self) -> &Coverage {
+        match self {
+            SinglePositioning::Format1(single_positioning) => &single_positioning.coverage,
+            SinglePositioning::Format2(single_positioning) => &single_positioning.coverage
+        }
+    }
+
+    /// Adjustment of the given glyph, None if the glyph is not covered.
+    pub fn value_record(&self, glyph_id: GlyphId) -> Option<&ValueRecord> {
+        let coverage_index = self.coverage().coverage_index(glyph_id)?;
+
+        match self {
+            SinglePositioning::Format1(single_positioning) => Some(&single_positioning.value_record),
+            SinglePositioning::Format2(single_positioning) =>
+                single_positioning.value_records.get(usize::from(coverage_index))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SinglePositioningFormat1 {
+    coverage: Coverage,
+    value_record: ValueRecord
+}
+
+impl SinglePositioningFormat1 {
+    /// Positioning value applied to all the covered glyphs.
+    pub fn value_record(&self) -> &ValueRecord {
+        &self.value_record
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SinglePositioningFormat2 {
+    coverage: Coverage,
+    value_records: Vec<ValueRecord>
+}
+
+impl SinglePositioningFormat2 {
+    /// Array of ValueRecords — positioning values applied to glyphs, ordered by Coverage index.
+    pub fn value_records(&self) -> &[ValueRecord] {
+        &self.value_records
+    }
+}
+
+/// Adjustments of a pair whose Class2 record has empty value formats.
+static EMPTY_VALUE_RECORD: ValueRecord = ValueRecord {
+    x_placement: 0,
+    y_placement: 0,
+    x_advance: 0,
+    y_advance: 0,
+    x_placement_device: None,
+    y_placement_device: None,
+    x_advance_device: None,
+    y_advance_device: None
+};
+
+/// Pair Adjustment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PairPositioning {
+    /// Adjustments for pairs of individual glyphs.
+    Format1(PairPositioningFormat1),
+    /// Adjustments for pairs of glyph classes.
+    Format2(PairPositioningFormat2)
+}
+
+impl PairPositioning {
+    /// Coverage of the first glyphs of the pairs.
+    pub fn coverage(&self) -> &Coverage {
+        match self {
+            PairPositioning::Format1(pair_positioning) => &pair_positioning.coverage,
+            PairPositioning::Format2(pair_positioning) => &pair_positioning.coverage
+        }
+    }
+
+    /// Adjustments of the first and second glyphs of the given pair, None if the subtable does
+    /// not apply to the pair.
+    pub fn pair(&self, first_glyph_id: GlyphId, second_glyph_id: GlyphId) -> Option<(&ValueRecord, &ValueRecord)> {
+        let coverage_index = self.coverage().coverage_index(first_glyph_id)?;
+
+        match self {
+            PairPositioning::Format1(pair_positioning) => {
+                let pair_set = pair_positioning.pair_sets.get(usize::from(coverage_index))?;
+                let index = pair_set.binary_search_by_key(&second_glyph_id, PairValueRecord::second_glyph).ok()?;
+                let pair_value_record = &pair_set[index];
+
+                Some((&pair_value_record.value_record1, &pair_value_record.value_record2))
+            },
+            PairPositioning::Format2(pair_positioning) => {
+                let class1 = pair_positioning.class_def1.class(first_glyph_id);
+                let class2 = pair_positioning.class_def2.class(second_glyph_id);
+
+                if class1 >= pair_positioning.class1_count || class2 >= pair_positioning.class2_count {
+                    return None;
+                }
+
+                // Both value formats are empty, no Class1 record is stored
+                if pair_positioning.class1_records.is_empty() {
+                    return Some((&EMPTY_VALUE_RECORD, &EMPTY_VALUE_RECORD));
+                }
+
+                let class2_record = &pair_positioning.class1_records[usize::from(class1)][usize::from(class2)];
+
+                Some((&class2_record.value_record1, &class2_record.value_record2))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PairPositioningFormat1 {
+    coverage: Coverage,
+    pair_sets: Vec<Vec<PairValueRecord>>
+}
+
+impl PairPositioningFormat1 {
+    /// Array of PairSet tables, ordered by Coverage index. The PairValueRecords of a PairSet are
+    /// ordered by the glyph ID of the second glyph.
+    pub fn pair_sets(&self) -> &[Vec<PairValueRecord>] {
+        &self.pair_sets
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PairValueRecord {
+    second_glyph: GlyphId,
+    value_record1: ValueRecord,
+    value_record2: ValueRecord
+}
+
+impl PairValueRecord {
+    /// Glyph ID of second glyph in the pair (first glyph is listed in the Coverage table).
+    pub fn second_glyph(&self) -> GlyphId {
+        self.second_glyph
+    }
+
+    /// Positioning data for the first glyph in the pair.
+    pub fn value_record1(&self) -> &ValueRecord {
+        &self.value_record1
+    }
+
+    /// Positioning data for the second glyph in the pair.
+    pub fn value_record2(&self) -> &ValueRecord {
+        &self.value_record2
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PairPositioningFormat2 {
+    coverage: Coverage,
+    class_def1: ClassDef,
+    class_def2: ClassDef,
+    class1_count: u16,
+    class2_count: u16,
+    class1_records: Vec<Vec<Class2Record>>
+}
+
+impl PairPositioningFormat2 {
+    /// Classes of the first glyphs of the pairs.
+    pub fn class_def1(&self) -> &ClassDef {
+        &self.class_def1
+    }
+
+    /// Classes of the second glyphs of the pairs.
+    pub fn class_def2(&self) -> &ClassDef {
+        &self.class_def2
+    }
+
+    /// Number of classes in classDef1 table — includes Class 0.
+    pub fn class1_count(&self) -> u16 {
+        self.class1_count
+    }
+
+    /// Number of classes in classDef2 table — includes Class 0.
+    pub fn class2_count(&self) -> u16 {
+        self.class2_count
+    }
+
+    /// Array of Class1 records, ordered by classes in classDef1, each one being an array of
+    /// Class2 records ordered by classes in classDef2. Empty if both value formats are 0, the
+    /// records then holding no adjustment.
+    pub fn class1_records(&self) -> &[Vec<Class2Record>] {
+        &self.class1_records
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Class2Record {
+    value_record1: ValueRecord,
+    value_record2: ValueRecord
+}
+
+impl Class2Record {
+    /// Positioning for first glyph — empty if valueFormat1 = 0.
+    pub fn value_record1(&self) -> &ValueRecord {
+        &self.value_record1
+    }
+
+    /// Positioning for second glyph — empty if valueFormat2 = 0.
+    pub fn value_record2(&self) -> &ValueRecord {
+        &self.value_record2
+    }
+}
+
+/// Cursive Attachment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CursivePositioning {
+    coverage: Coverage,
+    entry_exit_records: Vec<EntryExitRecord>
+}
+
+impl CursivePositioning {
+    /// Coverage of the cursive glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of EntryExit records, in Coverage index order.
+    pub fn entry_exit_records(&self) -> &[EntryExitRecord] {
+        &self.entry_exit_records
+    }
+
+    /// Entry and exit anchors of the given glyph, None if the glyph is not covered.
+    pub fn entry_exit_record(&self, glyph_id: GlyphId) -> Option<&EntryExitRecord> {
+        let coverage_index = self.coverage.coverage_index(glyph_id)?;
+        self.entry_exit_records.get(usize::from(coverage_index))
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct EntryExitRecord {
+    entry_anchor: Option<Anchor>,
+    exit_anchor: Option<Anchor>
+}
+
+impl EntryExitRecord {
+    /// Entry anchor of the glyph, if any.
+    pub fn entry_anchor(&self) -> Option<&Anchor> {
+        self.entry_anchor.as_ref()
+    }
+
+    /// Exit anchor of the glyph, if any.
+    pub fn exit_anchor(&self) -> Option<&Anchor> {
+        self.exit_anchor.as_ref()
+    }
+}
+
+/// The class and the anchor of a mark glyph.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MarkRecord {
+    mark_class: u16,
+    mark_anchor: Anchor
+}
+
+impl MarkRecord {
+    /// Class defined for the associated mark.
+    pub fn mark_class(&self) -> u16 {
+        self.mark_class
+    }
+
+    /// Anchor of the mark glyph.
+    pub fn mark_anchor(&self) -> &Anchor {
+        &self.mark_anchor
+    }
+}
+
+/// Mark-to-Base Attachment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MarkBasePositioning {
+    mark_coverage: Coverage,
+    base_coverage: Coverage,
+    mark_array: Vec<MarkRecord>,
+    base_array: Vec<Vec<Option<Anchor>>>
+}
+
+impl MarkBasePositioning {
+    /// Coverage of the mark glyphs.
+    pub fn mark_coverage(&self) -> &Coverage {
+        &self.mark_coverage
+    }
+
+    /// Coverage of the base glyphs.
+    pub fn base_coverage(&self) -> &Coverage {
+        &self.base_coverage
+    }
+
+    /// Mark records, in mark coverage index order.
+    pub fn mark_array(&self) -> &[MarkRecord] {
+        &self.mark_array
+    }
+
+    /// Base anchors, in base coverage index order, each one being an array of anchors ordered
+    /// by mark class.
+    pub fn base_array(&self) -> &[Vec<Option<Anchor>>] {
+        &self.base_array
+    }
+
+    /// Anchors of the given mark and of the given base glyph the mark attaches to, None if the
+    /// subtable does not apply to the glyphs.
+    pub fn attachment(&self, mark_glyph_id: GlyphId, base_glyph_id: GlyphId) -> Option<(&Anchor, &Anchor)> {
+        let mark_record = self.mark_array.get(usize::from(self.mark_coverage.coverage_index(mark_glyph_id)?))?;
+        let base_record = self.base_array.get(usize::from(self.base_coverage.coverage_index(base_glyph_id)?))?;
+        let base_anchor = base_record.get(usize::from(mark_record.mark_class))?.as_ref()?;
+
+        Some((&mark_record.mark_anchor, base_anchor))
+    }
+}
+
+/// Mark-to-Ligature Attachment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MarkLigaturePositioning {
+    mark_coverage: Coverage,
+    ligature_coverage: Coverage,
+    mark_array: Vec<MarkRecord>,
+    ligature_array: Vec<Vec<Vec<Option<Anchor>>>>
+}
+
+impl MarkLigaturePositioning {
+    /// Coverage of the mark glyphs.
+    pub fn mark_coverage(&self) -> &Coverage {
+        &self.mark_coverage
+    }
+
+    /// Coverage of the ligature glyphs.
+    pub fn ligature_coverage(&self) -> &Coverage {
+        &self.ligature_coverage
+    }
+
+    /// Mark records, in mark coverage index order.
+    pub fn mark_array(&self) -> &[MarkRecord] {
+        &self.mark_array
+    }
+
+    /// LigatureAttach tables, in ligature coverage index order. Each one is an array of
+    /// component records, in writing order, holding anchors ordered by mark class.
+    pub fn ligature_array(&self) -> &[Vec<Vec<Option<Anchor>>>] {
+        &self.ligature_array
+    }
+
+    /// Anchors of the given mark and of the given component of the ligature the mark attaches
+    /// to, None if the subtable does not apply to the glyphs.
+    pub fn attachment(&self, mark_glyph_id: GlyphId, ligature_glyph_id: GlyphId, component_index: u16) -> Option<(&Anchor, &Anchor)> {
+        let mark_record = self.mark_array.get(usize::from(self.mark_coverage.coverage_index(mark_glyph_id)?))?;
+        let ligature_attach = self.ligature_array.get(usize::from(self.ligature_coverage.coverage_index(ligature_glyph_id)?))?;
+        let ligature_anchor = ligature_attach.get(usize::from(component_index))?
+            .get(usize::from(mark_record.mark_class))?
+            .as_ref()?;
+
+        Some((&mark_record.mark_anchor, ligature_anchor))
+    }
+}
+
+/// Mark-to-Mark Attachment Positioning Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MarkMarkPositioning {
+    mark1_coverage: Coverage,
+    mark2_coverage: Coverage,
+    mark1_array: Vec<MarkRecord>,
+    mark2_array: Vec<Vec<Option<Anchor>>>
+}
+
+impl MarkMarkPositioning {
+    /// Coverage of the combining marks being attached.
+    pub fn mark1_coverage(&self) -> &Coverage {
+        &self.mark1_coverage
+    }
+
+    /// Coverage of the marks the combining marks attach to.
+    pub fn mark2_coverage(&self) -> &Coverage {
+        &self.mark2_coverage
+    }
+
+    /// Mark records of the combining marks, in mark1 coverage index order.
+    pub fn mark1_array(&self) -> &[MarkRecord] {
+        &self.mark1_array
+    }
+
+    /// Anchors of the base marks, in mark2 coverage index order, each one being an array of
+    /// anchors ordered by mark class.
+    pub fn mark2_array(&self) -> &[Vec<Option<Anchor>>] {
+        &self.mark2_array
+    }
+
+    /// Anchors of the given combining mark and of the given mark it attaches to, None if the
+    /// subtable does not apply to the glyphs.
+    pub fn attachment(&self, mark1_glyph_id: GlyphId, mark2_glyph_id: GlyphId) -> Option<(&Anchor, &Anchor)> {
+        let mark1_record = self.mark1_array.get(usize::from(self.mark1_coverage.coverage_index(mark1_glyph_id)?))?;
+        let mark2_record = self.mark2_array.get(usize::from(self.mark2_coverage.coverage_index(mark2_glyph_id)?))?;
+        let mark2_anchor = mark2_record.get(usize::from(mark1_record.mark_class))?.as_ref()?;
+
+        Some((&mark1_record.mark_anchor, mark2_anchor))
+    }
+}
+
+pub fn parse_glyph_positioning_table(input: &[u8]) -> IResult<&[u8], GlyphPositioningTable>
+{
+    let (remainder, major_version) = be_u16(input)?;
+    let (remainder, minor_version) = be_u16(remainder)?;
+
+    if major_version != 1 || minor_version > 1 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (remainder, script_list_offset) = be_u16(remainder)?;
+    let (remainder, feature_list_offset) = be_u16(remainder)?;
+    let (remainder, lookup_list_offset) = be_u16(remainder)?;
+
+    let (remainder, feature_variations_offset) = if minor_version == 1 {
+        let (remainder, feature_variations_offset) = be_u32(remainder)?;
+        (remainder, Some(feature_variations_offset))
+    }
+    else {
+        (remainder, None)
+    };
+
+    Ok((remainder, GlyphPositioningTable {
+        major_version,
+        minor_version,
+        script_list: parse_script_list(offset_slice(input, usize::from(script_list_offset))?)?.1,
+        feature_list: parse_feature_list(offset_slice(input, usize::from(feature_list_offset))?)?.1,
+        lookup_list: parse_lookup_list(offset_slice(input, usize::from(lookup_list_offset))?, parse_positioning_subtable)?.1,
+        feature_variations_offset
+    }))
+}
+
+/// Parse a GPOS lookup subtable of the given lookup type.
+pub fn parse_positioning_subtable(input: &[u8], lookup_type: u16) -> IResult<&[u8], PositioningSubtable>
+{
+    match lookup_type {
+        1 => {
+            let (input, single_positioning) = parse_single_positioning(input)?;
+            Ok((input, PositioningSubtable::Single(single_positioning)))
+        },
+        2 => {
+            let (input, pair_positioning) = parse_pair_positioning(input)?;
+            Ok((input, PositioningSubtable::Pair(pair_positioning)))
+        },
+        3 => {
+            let (input, cursive_positioning) = parse_cursive_positioning(input)?;
+            Ok((input, PositioningSubtable::Cursive(cursive_positioning)))
+        },
+        4 => {
+            let (input, mark_base_positioning) = parse_mark_base_positioning(input)?;
+            Ok((input, PositioningSubtable::MarkToBase(mark_base_positioning)))
+        },
+        5 => {
+            let (input, mark_ligature_positioning) = parse_mark_ligature_positioning(input)?;
+            Ok((input, PositioningSubtable::MarkToLigature(mark_ligature_positioning)))
+        },
+        6 => {
+            // Mark-to-Mark subtables have the same layout as Mark-to-Base subtables
+            let (input, mark_base_positioning) = parse_mark_base_positioning(input)?;
+
+            Ok((input, PositioningSubtable::MarkToMark(MarkMarkPositioning {
+                mark1_coverage: mark_base_positioning.mark_coverage,
+                mark2_coverage: mark_base_positioning.base_coverage,
+                mark1_array: mark_base_positioning.mark_array,
+                mark2_array: mark_base_positioning.base_array
+            })))
+        },
+        7 => {
+            let (input, sequence_context) = parse_sequence_context(input)?;
+            Ok((input, PositioningSubtable::Context(sequence_context)))
+        },
+        8 => {
+            let (input, chained_sequence_context) = parse_chained_sequence_context(input)?;
+            Ok((input, PositioningSubtable::ChainedContext(chained_sequence_context)))
+        },
+        9 => parse_extension_positioning(input),
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+/// Parse the subtable an Extension Positioning subtable points to.
+fn parse_extension_positioning(input: &[u8]) -> IResult<&[u8], PositioningSubtable>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, extension_lookup_type) = be_u16(remainder)?;
+    let (remainder, extension_offset) = be_u32(remainder)?;
+
+    // An extension subtable cannot point to another extension subtable
+    if extension_lookup_type == 9 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (_, subtable) = parse_positioning_subtable(offset_slice(input, extension_offset as usize)?, extension_lookup_type)?;
+
+    Ok((remainder, subtable))
+}
+
+/// Parse a ValueRecord with the fields given by the ValueFormat, the offsets to the Device
+/// tables being relative to the beginning of the parent table.
+pub fn parse_value_record<'a>(input: &'a [u8], parent: &'a [u8], value_format: ValueFormat) -> IResult<&'a [u8], ValueRecord>
+{
+    let parse_value = |input: &'a [u8], flag: ValueFormat| -> IResult<&'a [u8], i16> {
+        if value_format.contains(flag) { be_i16(input) } else { Ok((input, 0)) }
+    };
+
+    let parse_device_offset = |input: &'a [u8], flag: ValueFormat| -> IResult<&'a [u8], Option<Device>> {
+        if value_format.contains(flag) {
+            let (input, device_offset) = be_u16(input)?;
+            Ok((input, parse_nullable(parent, usize::from(device_offset), parse_device)?))
+        }
+        else {
+            Ok((input, None))
+        }
+    };
+
+    let (input, x_placement) = parse_value(input, ValueFormat::X_PLACEMENT)?;
+    let (input, y_placement) = parse_value(input, ValueFormat::Y_PLACEMENT)?;
+    let (input, x_advance) = parse_value(input, ValueFormat::X_ADVANCE)?;
+    let (input, y_advance) = parse_value(input, ValueFormat::Y_ADVANCE)?;
+    let (input, x_placement_device) = parse_device_offset(input, ValueFormat::X_PLACEMENT_DEVICE)?;
+    let (input, y_placement_device) = parse_device_offset(input, ValueFormat::Y_PLACEMENT_DEVICE)?;
+    let (input, x_advance_device) = parse_device_offset(input, ValueFormat::X_ADVANCE_DEVICE)?;
+    let (input, y_advance_device) = parse_device_offset(input, ValueFormat::Y_ADVANCE_DEVICE)?;
+
+    Ok((input, ValueRecord {
+        x_placement,
+        y_placement,
+        x_advance,
+        y_advance,
+        x_placement_device,
+        y_placement_device,
+        x_advance_device,
+        y_advance_device
+    }))
+}
+
+fn parse_value_format(input: &[u8]) -> IResult<&[u8], ValueFormat>
+{
+    let (input, value_format) = be_u16(input)?;
+    Ok((input, ValueFormat::from_bits_truncate(value_format)))
+}
+
+pub fn parse_anchor(input: &[u8]) -> IResult<&[u8], Anchor>
+{
+    let (remainder, anchor_format) = be_u16(input)?;
+    let (remainder, x_coordinate) = be_i16(remainder)?;
+    let (remainder, y_coordinate) = be_i16(remainder)?;
+
+    match anchor_format {
+        1 => Ok((remainder, Anchor {
+            x_coordinate,
+            y_coordinate,
+            anchor_point: None,
+            x_device: None,
+            y_device: None
+        })),
+        2 => {
+            let (remainder, anchor_point) = be_u16(remainder)?;
+
+            Ok((remainder, Anchor {
+                x_coordinate,
+                y_coordinate,
+                anchor_point: Some(anchor_point),
+                x_device: None,
+                y_device: None
+            }))
+        },
+        3 => {
+            let (remainder, x_device_offset) = be_u16(remainder)?;
+            let (remainder, y_device_offset) = be_u16(remainder)?;
+
+            Ok((remainder, Anchor {
+                x_coordinate,
+                y_coordinate,
+                anchor_point: None,
+                x_device: parse_nullable(input, usize::from(x_device_offset), parse_device)?,
+                y_device: parse_nullable(input, usize::from(y_device_offset), parse_device)?
+            }))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_single_positioning(input: &[u8]) -> IResult<&[u8], SinglePositioning>
+{
+    let (remainder, pos_format) = be_u16(input)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (remainder, value_format) = parse_value_format(remainder)?;
+    let (_, coverage) = parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?;
+
+    match pos_format {
+        1 => {
+            let (remainder, value_record) = parse_value_record(remainder, input, value_format)?;
+
+            Ok((remainder, SinglePositioning::Format1(SinglePositioningFormat1 {
+                coverage,
+                value_record
+            })))
+        },
+        2 => {
+            let (remainder, value_count) = be_u16(remainder)?;
+            let (remainder, value_records) = count(|record| parse_value_record(record, input, value_format),
+                usize::from(value_count))(remainder)?;
+
+            Ok((remainder, SinglePositioning::Format2(SinglePositioningFormat2 {
+                coverage,
+                value_records
+            })))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_pair_positioning(input: &[u8]) -> IResult<&[u8], PairPositioning>
+{
+    let (remainder, pos_format) = be_u16(input)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (remainder, value_format1) = parse_value_format(remainder)?;
+    let (remainder, value_format2) = parse_value_format(remainder)?;
+    let (_, coverage) = parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?;
+
+    match pos_format {
+        1 => {
+            let (remainder, pair_set_offsets) = parse_array16(remainder)?;
+
+            // Device offsets of the value records are relative to the beginning of the PairSet
+            let pair_sets = parse_offsets(input, &pair_set_offsets, |pair_set| {
+                let (remainder, pair_value_count) = be_u16(pair_set)?;
+
+                count(|record| {
+                    let (record, second_glyph) = be_u16(record)?;
+                    let (record, value_record1) = parse_value_record(record, pair_set, value_format1)?;
+                    let (record, value_record2) = parse_value_record(record, pair_set, value_format2)?;
+
+                    Ok((record, PairValueRecord {
+                        second_glyph,
+                        value_record1,
+                        value_record2
+                    }))
+                }, usize::from(pair_value_count))(remainder)
+            })?;
+
+            Ok((remainder, PairPositioning::Format1(PairPositioningFormat1 {
+                coverage,
+                pair_sets
+            })))
+        },
+        2 => {
+            let (remainder, class_def1_offset) = be_u16(remainder)?;
+            let (remainder, class_def2_offset) = be_u16(remainder)?;
+            let (remainder, class1_count) = be_u16(remainder)?;
+            let (remainder, class2_count) = be_u16(remainder)?;
+
+            // Each value of a ValueRecord is 2 bytes long
+            let record_size = 2 * (value_format1.bits().count_ones() + value_format2.bits().count_ones()) as usize;
+            if usize::from(class1_count) * usize::from(class2_count) * record_size > remainder.len() {
+                return Err(NomErr::Error(error_position!(remainder, ErrorKind::Eof)));
+            }
+
+            // Empty records are not materialised, there may be up to 0xFFFF × 0xFFFF of them
+            let class1_count_to_parse = if record_size == 0 { 0 } else { usize::from(class1_count) };
+            let (remainder, class1_records) = count(|class1_record| {
+                count(|class2_record| {
+                    let (class2_record, value_record1) = parse_value_record(class2_record, input, value_format1)?;
+                    let (class2_record, value_record2) = parse_value_record(class2_record, input, value_format2)?;
+
+                    Ok((class2_record, Class2Record {
+                        value_record1,
+                        value_record2
+                    }))
+                }, usize::from(class2_count))(class1_record)
+            }, class1_count_to_parse)(remainder)?;
+
+            Ok((remainder, PairPositioning::Format2(PairPositioningFormat2 {
+                coverage,
+                class_def1: parse_class_def(offset_slice(input, usize::from(class_def1_offset))?)?.1,
+                class_def2: parse_class_def(offset_slice(input, usize::from(class_def2_offset))?)?.1,
+                class1_count,
+                class2_count,
+                class1_records
+            })))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+fn parse_cursive_positioning(input: &[u8]) -> IResult<&[u8], CursivePositioning>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (remainder, entry_exit_count) = be_u16(remainder)?;
+    let (remainder, entry_exit_offsets) = count(be_u16, 2 * usize::from(entry_exit_count))(remainder)?;
+
+    let entry_exit_records = entry_exit_offsets.iter()
+        .map(|&offset| parse_nullable(input, usize::from(offset), parse_anchor))
+        .collect::<Result<Vec<_>, _>>()?
+        .chunks(2)
+        .map(|anchors| EntryExitRecord {
+            entry_anchor: anchors[0].clone(),
+            exit_anchor: anchors[1].clone()
+        })
+        .collect();
+
+    Ok((remainder, CursivePositioning {
+        coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+        entry_exit_records
+    }))
+}
+
+/// Parse a MarkArray table.
+fn parse_mark_array(input: &[u8]) -> IResult<&[u8], Vec<MarkRecord>>
+{
+    let (remainder, mark_count) = be_u16(input)?;
+
+    count(|record| {
+        let (record, mark_class) = be_u16(record)?;
+        let (record, mark_anchor_offset) = be_u16(record)?;
+
+        Ok((record, MarkRecord {
+            mark_class,
+            mark_anchor: parse_anchor(offset_slice(input, usize::from(mark_anchor_offset))?)?.1
+        }))
+    }, usize::from(mark_count))(remainder)
+}
+
+/// Parse an array of records holding an anchor offset per mark class, the offsets being relative
+/// to the beginning of the input (BaseArray, Mark2Array or LigatureAttach tables).
+fn parse_anchor_records(input: &[u8], mark_class_count: u16) -> IResult<&[u8], Vec<Vec<Option<Anchor>>>>
+{
+    let (remainder, record_count) = be_u16(input)?;
+
+    count(|record| {
+        let (record, anchor_offsets) = count(be_u16, usize::from(mark_class_count))(record)?;
+        let anchors = anchor_offsets.iter()
+            .map(|&offset| parse_nullable(input, usize::from(offset), parse_anchor))
+            .collect::<Result<_, _>>()?;
+
+        Ok((record, anchors))
+    }, usize::from(record_count))(remainder)
+}
+
+fn parse_mark_base_positioning(input: &[u8]) -> IResult<&[u8], MarkBasePositioning>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, mark_coverage_offset) = be_u16(remainder)?;
+    let (remainder, base_coverage_offset) = be_u16(remainder)?;
+    let (remainder, mark_class_count) = be_u16(remainder)?;
+    let (remainder, mark_array_offset) = be_u16(remainder)?;
+    let (remainder, base_array_offset) = be_u16(remainder)?;
+
+    Ok((remainder, MarkBasePositioning {
+        mark_coverage: parse_coverage(offset_slice(input, usize::from(mark_coverage_offset))?)?.1,
+        base_coverage: parse_coverage(offset_slice(input, usize::from(base_coverage_offset))?)?.1,
+        mark_array: parse_mark_array(offset_slice(input, usize::from(mark_array_offset))?)?.1,
+        base_array: parse_anchor_records(offset_slice(input, usize::from(base_array_offset))?, mark_class_count)?.1
+    }))
+}
+
+fn parse_mark_ligature_positioning(input: &[u8]) -> IResult<&[u8], MarkLigaturePositioning>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, mark_coverage_offset) = be_u16(remainder)?;
+    let (remainder, ligature_coverage_offset) = be_u16(remainder)?;
+    let (remainder, mark_class_count) = be_u16(remainder)?;
+    let (remainder, mark_array_offset) = be_u16(remainder)?;
+    let (remainder, ligature_array_offset) = be_u16(remainder)?;
+
+    let ligature_array = offset_slice(input, usize::from(ligature_array_offset))?;
+    let (_, ligature_attach_offsets) = parse_array16(ligature_array)?;
+
+    Ok((remainder, MarkLigaturePositioning {
+        mark_coverage: parse_coverage(offset_slice(input, usize::from(mark_coverage_offset))?)?.1,
+        ligature_coverage: parse_coverage(offset_slice(input, usize::from(ligature_coverage_offset))?)?.1,
+        mark_array: parse_mark_array(offset_slice(input, usize::from(mark_array_offset))?)?.1,
+        ligature_array: parse_offsets(ligature_array, &ligature_attach_offsets,
+            |ligature_attach| parse_anchor_records(ligature_attach, mark_class_count))?
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otff::OpenTypeFontFile;
+    use tables::{Tag, TableTag};
+    use tables::layout::DeviceTable;
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf");
+    static SOURCE_SERIF_PRO_REGULAR: &[u8] = include_bytes!("../../fonts/source-serif-pro/SourceSerifPro-Regular.otf");
+
+    fn glyph_positioning_table(buf: &[u8]) -> GlyphPositioningTable {
+        let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+        parse_glyph_positioning_table(font.table(TableTag::Gpos).unwrap().as_slice().unwrap()).unwrap().1
+    }
+
+    #[test]
+    fn case_value_record() {
+        let bytes: &[u8]  = &[0x00, 0x05, 0xFF, 0xB5, 0x00, 0x06, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x0C, 0x00, 0x01, 0x80, 0x00];
+
+        let value_format = ValueFormat::X_PLACEMENT | ValueFormat::X_ADVANCE | ValueFormat::X_PLACEMENT_DEVICE | ValueFormat::X_ADVANCE_DEVICE;
+        let (remainder, value_record) = parse_value_record(&bytes[2..], bytes, value_format).unwrap();
+
+        assert_eq!(remainder, &bytes[10..]);
+        assert_eq!(value_record.x_placement(), -75);
+        assert_eq!(value_record.y_placement(), 0);
+        assert_eq!(value_record.x_advance(), 6);
+        assert_eq!(value_record.x_placement_device(), None);
+        assert_eq!(value_record.y_advance_device(), None);
+
+        match value_record.x_advance_device() {
+            Some(Device::VariationIndex(variation_index)) => {
+                assert_eq!(variation_index.delta_set_outer_index(), 12);
+                assert_eq!(variation_index.delta_set_inner_index(), 1);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_anchor() {
+        let format_2: &[u8]  = &[0x00, 0x02, 0x01, 0x42, 0x03, 0x84, 0x00, 0x0D];
+
+        let anchor = parse_anchor(format_2).unwrap().1;
+        assert_eq!((anchor.x_coordinate(), anchor.y_coordinate()), (322, 900));
+        assert_eq!(anchor.anchor_point(), Some(13));
+
+        let format_3: &[u8]  = &[
+            0x00, 0x03, 0x01, 0x17, 0x05, 0x15, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x11,
+            0x00, 0x01, 0x8C, 0x04];
+
+        let anchor = parse_anchor(format_3).unwrap().1;
+        assert_eq!((anchor.x_coordinate(), anchor.y_coordinate()), (279, 1301));
+        assert_eq!(anchor.y_device(), None);
+
+        match anchor.x_device() {
+            Some(Device::Device(DeviceTable { .. })) => (),
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_extension_positioning() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x08, 0x00, 0x04,
+            0x00, 0x64, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A];
+
+        match parse_positioning_subtable(bytes, 9).unwrap().1 {
+            PositioningSubtable::Single(single_positioning) => {
+                assert_eq!(single_positioning.value_record(10).map(ValueRecord::x_advance), Some(100));
+                assert_eq!(single_positioning.value_record(11), None);
+            },
+            _ => assert!(false)
+        }
+
+        // Extension subtables cannot be nested
+        let nested: &[u8]  = &[0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00];
+        assert!(parse_positioning_subtable(nested, 9).is_err());
+    }
+
+    #[test]
+    fn case_pair_positioning_empty_value_formats() {
+        let mut bytes: Vec<u8> = vec![
+            0x00, 0x02, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x16, 0xFF, 0xFF,
+            0xFF, 0xFF, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A, 0x00, 0x01, 0x00, 0x0A, 0x00, 0x01,
+            0x00, 0x01];
+
+        // 0xFFFF × 0xFFFF empty Class2 records
+        match parse_positioning_subtable(&bytes, 2).unwrap().1 {
+            PositioningSubtable::Pair(pair_positioning) => {
+                assert_eq!(pair_positioning.pair(10, 10), Some((&ValueRecord::default(), &ValueRecord::default())));
+                assert_eq!(pair_positioning.pair(11, 10), None);
+
+                if let PairPositioning::Format2(pair_positioning) = pair_positioning {
+                    assert_eq!(pair_positioning.class1_count(), 0xFFFF);
+                    assert!(pair_positioning.class1_records().is_empty());
+                }
+            },
+            _ => assert!(false)
+        }
+
+        // With an X_ADVANCE value, the records do not fit in the subtable
+        bytes[5] = 0x04;
+        let expected = Err(NomErr::Error(error_position!(&bytes[16..], ErrorKind::Eof)));
+        assert_eq!(parse_positioning_subtable(&bytes, 2).map(|_| ()), expected);
+    }
+
+    #[test]
+    fn case_cursive_positioning() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x0A, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01,
+            0x00, 0x0A, 0x00, 0x01, 0x05, 0xDC, 0x00, 0x2C];
+
+        match parse_positioning_subtable(bytes, 3).unwrap().1 {
+            PositioningSubtable::Cursive(cursive_positioning) => {
+                let entry_exit_record = cursive_positioning.entry_exit_record(10).unwrap();
+                assert_eq!(entry_exit_record.entry_anchor().map(|anchor| (anchor.x_coordinate(), anchor.y_coordinate())), Some((1500, 44)));
+                assert_eq!(entry_exit_record.exit_anchor(), None);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_mark_ligature_positioning() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x0C, 0x00, 0x12, 0x00, 0x01, 0x00, 0x18, 0x00, 0x24, 0x00, 0x01,
+            0x00, 0x01, 0x00, 0x14, 0x00, 0x01, 0x00, 0x01, 0x00, 0x1E, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x01, 0x01, 0x00, 0x02, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0xC8, 0x02, 0x00];
+
+        match parse_positioning_subtable(bytes, 5).unwrap().1 {
+            PositioningSubtable::MarkToLigature(mark_ligature_positioning) => {
+                assert_eq!(mark_ligature_positioning.ligature_array()[0].len(), 2);
+
+                // The first component has no anchor
+                assert_eq!(mark_ligature_positioning.attachment(20, 30, 0), None);
+
+                let (mark_anchor, ligature_anchor) = mark_ligature_positioning.attachment(20, 30, 1).unwrap();
+                assert_eq!((mark_anchor.x_coordinate(), mark_anchor.y_coordinate()), (256, 512));
+                assert_eq!((ligature_anchor.x_coordinate(), ligature_anchor.y_coordinate()), (200, 512));
+
+                assert_eq!(mark_ligature_positioning.attachment(20, 30, 2), None);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_positioning_table_pair_positioning() {
+        let gpos = glyph_positioning_table(ROBOTO_REGULAR);
+
+        assert_eq!(gpos.major_version(), 1);
+        assert_eq!(gpos.minor_version(), 0);
+        assert_eq!(gpos.lookup_list().lookups().len(), 2);
+
+        let kern = gpos.lookup_list().lookup(1).unwrap();
+        assert_eq!(kern.lookup_type(), 2);
+
+        let pair = |first_glyph_id, second_glyph_id| kern.subtables().iter()
+            .filter_map(|subtable| match subtable {
+                PositioningSubtable::Pair(pair_positioning) => pair_positioning.pair(first_glyph_id, second_glyph_id),
+                _ => None
+            })
+            .map(|(value_record1, value_record2)| (value_record1.x_advance(), value_record2.clone()))
+            .next();
+
+        // 'F' 'A' from a format 1 subtable
+        assert_eq!(pair(42, 37), Some((-170, ValueRecord::default())));
+        // 'A' 'V' and 'T' 'o' from a format 2 subtable
+        assert_eq!(pair(37, 58), Some((-87, ValueRecord::default())));
+        assert_eq!(pair(56, 83), Some((-99, ValueRecord::default())));
+
+        match &kern.subtables()[1] {
+            PositioningSubtable::Pair(PairPositioning::Format2(pair_positioning)) => {
+                assert_eq!(pair_positioning.class1_count(), 38);
+                assert_eq!(pair_positioning.class2_count(), 37);
+                assert_eq!(pair_positioning.class1_records().len(), 38);
+                assert_eq!(pair_positioning.class1_records()[0].len(), 37);
+            },
+            _ => assert!(false)
+        }
+
+        match &gpos.lookup_list().lookup(0).unwrap().subtables()[0] {
+            PositioningSubtable::Single(single_positioning) => {
+                let value_record = single_positioning.value_record(8).unwrap();
+                assert_eq!((value_record.x_placement(), value_record.x_advance()), (36, 72));
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_positioning_table_mark_positioning() {
+        let gpos = glyph_positioning_table(SOURCE_SERIF_PRO_REGULAR);
+
+        let lang_sys = gpos.script_list().script(Tag::new(b"latn")).unwrap().lang_sys(None).unwrap();
+        assert_eq!(gpos.feature_list().lookup_indices(lang_sys, Tag::new(b"mark")), vec![0, 1, 2, 3, 4]);
+
+        // 'a' and U+0301 COMBINING ACUTE ACCENT
+        match &gpos.lookup_list().lookup(0).unwrap().subtables()[0] {
+            PositioningSubtable::MarkToBase(mark_base_positioning) => {
+                let (mark_anchor, base_anchor) = mark_base_positioning.attachment(775, 28).unwrap();
+                assert_eq!((mark_anchor.x_coordinate(), mark_anchor.y_coordinate()), (0, 507));
+                assert_eq!((base_anchor.x_coordinate(), base_anchor.y_coordinate()), (245, 507));
+            },
+            _ => assert!(false)
+        }
+
+        match &gpos.lookup_list().lookup(5).unwrap().subtables()[0] {
+            PositioningSubtable::MarkToMark(_) => (),
+            _ => assert!(false)
+        }
+
+        match &gpos.lookup_list().lookup(7).unwrap().subtables()[0] {
+            PositioningSubtable::ChainedContext(ChainedSequenceContext::Format3(_)) => (),
+            _ => assert!(false)
+        }
+    }
+}
@@ -0,0 +1,594 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_i16, be_u16, be_u32};
+use tables::GlyphId;
+use tables::layout::{ChainedSequenceContext, Coverage, FeatureList, LookupList, ScriptList, SequenceContext,
+                     offset_slice, parse_array16, parse_chained_sequence_context, parse_coverage,
+                     parse_feature_list, parse_lookup_list, parse_offsets, parse_script_list,
+                     parse_sequence_context, verify_format};
+
+/// Glyph Substitution Table
+///
+/// The Glyph Substitution (GSUB) table provides data for substitution of glyphs for appropriate
+/// rendering of scripts, such as cursively-connecting forms in Arabic script, or for advanced
+/// typographic effects, such as ligatures.
+///
+/// The scripts and language systems of the ScriptList select the features of the FeatureList,
+/// which reference the lookups of the LookupList applied to the glyph sequence.
+///
+/// More information on ['GSUB'](https://docs.microsoft.com/en-gb/typography/opentype/spec/gsub)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GlyphSubstitutionTable {
+    major_version: u16,
+    minor_version: u16,
+    script_list: ScriptList,
+    feature_list: FeatureList,
+    lookup_list: LookupList<SubstitutionSubtable>,
+    feature_variations_offset: Option<u32>
+}
+
+impl GlyphSubstitutionTable {
+    /// Major version of the GSUB table, = 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Minor version of the GSUB table, = 0 or 1.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Scripts and language systems supported by the font.
+    pub fn script_list(&self) -> &ScriptList {
+        &self.script_list
+    }
+
+    /// Features of the font.
+    pub fn feature_list(&self) -> &FeatureList {
+        &self.feature_list
+    }
+
+    /// Lookups of the font.
+    pub fn lookup_list(&self) -> &LookupList<SubstitutionSubtable> {
+        &self.lookup_list
+    }
+
+    /// Offset to FeatureVariations table, from beginning of the GSUB table (may be NULL), only
+    /// present in version 1.1.
+    pub fn feature_variations_offset(&self) -> Option<u32> {
+        self.feature_variations_offset
+    }
+}
+
+impl_parse!(
+    /// Parse Glyph Substitution Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::tables::{Tag, TableTag};
+    /// use otf::tables::gsub::{GlyphSubstitutionTable, SubstitutionSubtable};
+    /// use otf::parser::Parse;
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let gsub = GlyphSubstitutionTable::parse(font.table(TableTag::Gsub).unwrap().as_slice().unwrap()).unwrap().1;
+    ///
+    /// let lang_sys = gsub.script_list().script(Tag::new(b"latn")).unwrap().lang_sys(None).unwrap();
+    /// let lookup_indices = gsub.feature_list().lookup_indices(lang_sys, Tag::new(b"liga"));
+    ///
+    /// // Glyphs 'f', 'f' and 'i'
+    /// let glyphs = [font.glyph_index('f').unwrap(), font.glyph_index('f').unwrap(), font.glyph_index('i').unwrap()];
+    ///
+    /// let ligature = lookup_indices.iter()
+    ///     .filter_map(|&lookup_index| gsub.lookup_list().lookup(lookup_index))
+    ///     .flat_map(|lookup| lookup.subtables())
+    ///     .filter_map(|subtable| match subtable {
+    ///         SubstitutionSubtable::Ligature(ligature_substitution) => ligature_substitution.ligature(&glyphs),
+    ///         _ => None
+    ///     })
+    ///     .next()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(ligature.component_glyph_ids().len(), 2);
+    /// ```
+    GlyphSubstitutionTable, parse_glyph_substitution_table
+);
+
+/// A subtable of a GSUB lookup.
+///
+/// Extension subtables (lookup type 7) are resolved to the subtable they point to, the lookup
+/// type of the lookup still being 7.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SubstitutionSubtable {
+    /// Lookup type 1: replace one glyph with one glyph.
+    Single(SingleSubstitution),
+    /// Lookup type 2: replace one glyph with more than one glyph.
+    Multiple(MultipleSubstitution),
+    /// Lookup type 3: replace one glyph with one of many glyphs.
+    Alternate(AlternateSubstitution),
+    /// Lookup type 4: replace multiple glyphs with one glyph.
+    Ligature(LigatureSubstitution),
+    /// Lookup type 5: replace one or more glyphs in context.
+    Context(SequenceContext),
+    /// Lookup type 6: replace one or more glyphs in chained context.
+    ChainedContext(ChainedSequenceContext),
+    /// Lookup type 8: applied in reverse order, replace single glyph in chaining context.
+    ReverseChainSingle(ReverseChainSingleSubstitution)
+}
+
+/// Single Substitution Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SingleSubstitution {
+    /// The substitute glyph is the covered glyph plus a delta.
+    Format1(SingleSubstitutionFormat1),
+    /// The substitute glyphs are listed in coverage index order.
+    Format2(SingleSubstitutionFormat2)
+}
+
+impl SingleSubstitution {
+    /// Coverage of the input glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        match self {
+            SingleSubstitution::Format1(single_substitution) => &single_substitution.coverage,
+            SingleSubstitution::Format2(single_substitution) => &single_substitution.coverage
+        }
+    }
+
+    /// Substitute of the given glyph, None if the glyph is not covered.
+    pub fn substitute(&self, glyph_id: GlyphId) -> Option<GlyphId> {
+        let coverage_index = self.coverage().coverage_index(glyph_id)?;
+
+        match self {
+            // Addition of deltaGlyphID is modulo 65536
+            SingleSubstitution::Format1(single_substitution) =>
+                Some((i32::from(glyph_id) + i32::from(single_substitution.delta_glyph_id)) as u16),
+            SingleSubstitution::Format2(single_substitution) =>
+                single_substitution.substitute_glyph_ids.get(usize::from(coverage_index)).cloned()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SingleSubstitutionFormat1 {
+    coverage: Coverage,
+    delta_glyph_id: i16
+}
+
+impl SingleSubstitutionFormat1 {
+    /// Add to original glyph ID to get substitute glyph ID.
+    pub fn delta_glyph_id(&self) -> i16 {
+        self.delta_glyph_id
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SingleSubstitutionFormat2 {
+    coverage: Coverage,
+    substitute_glyph_ids: Vec<GlyphId>
+}
+
+impl SingleSubstitutionFormat2 {
+    /// Array of substitute glyph IDs — ordered by Coverage index.
+    pub fn substitute_glyph_ids(&self) -> &[GlyphId] {
+        &self.substitute_glyph_ids
+    }
+}
+
+/// Multiple Substitution Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultipleSubstitution {
+    coverage: Coverage,
+    sequences: Vec<Vec<GlyphId>>
+}
+
+impl MultipleSubstitution {
+    /// Coverage of the input glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of Sequence tables, ordered by Coverage index.
+    pub fn sequences(&self) -> &[Vec<GlyphId>] {
+        &self.sequences
+    }
+
+    /// Sequence of glyphs replacing the given glyph, None if the glyph is not covered.
+    pub fn substitute(&self, glyph_id: GlyphId) -> Option<&[GlyphId]> {
+        let coverage_index = self.coverage.coverage_index(glyph_id)?;
+        self.sequences.get(usize::from(coverage_index)).map(Vec::as_slice)
+    }
+}
+
+/// Alternate Substitution Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AlternateSubstitution {
+    coverage: Coverage,
+    alternate_sets: Vec<Vec<GlyphId>>
+}
+
+impl AlternateSubstitution {
+    /// Coverage of the input glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of AlternateSet tables, ordered by Coverage index.
+    pub fn alternate_sets(&self) -> &[Vec<GlyphId>] {
+        &self.alternate_sets
+    }
+
+    /// Alternates of the given glyph, in arbitrary order. None if the glyph is not covered.
+    pub fn alternates(&self, glyph_id: GlyphId) -> Option<&[GlyphId]> {
+        let coverage_index = self.coverage.coverage_index(glyph_id)?;
+        self.alternate_sets.get(usize::from(coverage_index)).map(Vec::as_slice)
+    }
+}
+
+/// Ligature Substitution Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LigatureSubstitution {
+    coverage: Coverage,
+    ligature_sets: Vec<Vec<Ligature>>
+}
+
+impl LigatureSubstitution {
+    /// Coverage of the first component of the ligatures.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of LigatureSet tables, ordered by Coverage index.
+    pub fn ligature_sets(&self) -> &[Vec<Ligature>] {
+        &self.ligature_sets
+    }
+
+    /// First ligature, in order of preference, whose components match the beginning of the given
+    /// glyph sequence.
+    pub fn ligature(&self, glyph_ids: &[GlyphId]) -> Option<&Ligature> {
+        let (first_glyph_id, glyph_ids) = glyph_ids.split_first()?;
+        let coverage_index = self.coverage.coverage_index(*first_glyph_id)?;
+
+        self.ligature_sets.get(usize::from(coverage_index))?.iter()
+            .find(|ligature| glyph_ids.starts_with(&ligature.component_glyph_ids))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Ligature {
+    ligature_glyph: GlyphId,
+    component_glyph_ids: Vec<GlyphId>
+}
+
+impl Ligature {
+    /// Glyph ID of ligature to substitute.
+    pub fn ligature_glyph(&self) -> GlyphId {
+        self.ligature_glyph
+    }
+
+    /// Array of component glyph IDs — start with the second component, ordered in writing
+    /// direction.
+    pub fn component_glyph_ids(&self) -> &[GlyphId] {
+        &self.component_glyph_ids
+    }
+}
+
+/// Reverse Chaining Contextual Single Substitution Subtable
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReverseChainSingleSubstitution {
+    coverage: Coverage,
+    backtrack_coverages: Vec<Coverage>,
+    lookahead_coverages: Vec<Coverage>,
+    substitute_glyph_ids: Vec<GlyphId>
+}
+
+impl ReverseChainSingleSubstitution {
+    /// Coverage of the input glyphs.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Coverage tables of the backtrack sequence, in reverse glyph sequence order.
+    pub fn backtrack_coverages(&self) -> &[Coverage] {
+        &self.backtrack_coverages
+    }
+
+    /// Coverage tables of the lookahead sequence, in glyph sequence order.
+    pub fn lookahead_coverages(&self) -> &[Coverage] {
+        &self.lookahead_coverages
+    }
+
+    /// Array of substitute glyph IDs — ordered by Coverage index.
+    pub fn substitute_glyph_ids(&self) -> &[GlyphId] {
+        &self.substitute_glyph_ids
+    }
+}
+
+pub fn parse_glyph_substitution_table(input: &[u8]) -> IResult<&[u8], GlyphSubstitutionTable>
+{
+    let (remainder, major_version) = be_u16(input)?;
+    let (remainder, minor_version) = be_u16(remainder)?;
+
+    if major_version != 1 || minor_version > 1 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (remainder, script_list_offset) = be_u16(remainder)?;
+    let (remainder, feature_list_offset) = be_u16(remainder)?;
+    let (remainder, lookup_list_offset) = be_u16(remainder)?;
+
+    let (remainder, feature_variations_offset) = if minor_version == 1 {
+        let (remainder, feature_variations_offset) = be_u32(remainder)?;
+        (remainder, Some(feature_variations_offset))
+    }
+    else {
+        (remainder, None)
+    };
+
+    Ok((remainder, GlyphSubstitutionTable {
+        major_version,
+        minor_version,
+        script_list: parse_script_list(offset_slice(input, usize::from(script_list_offset))?)?.1,
+        feature_list: parse_feature_list(offset_slice(input, usize::from(feature_list_offset))?)?.1,
+        lookup_list: parse_lookup_list(offset_slice(input, usize::from(lookup_list_offset))?, parse_substitution_subtable)?.1,
+        feature_variations_offset
+    }))
+}
+
+/// Parse a GSUB lookup subtable of the given lookup type.
+pub fn parse_substitution_subtable(input: &[u8], lookup_type: u16) -> IResult<&[u8], SubstitutionSubtable>
+{
+    match lookup_type {
+        1 => {
+            let (input, single_substitution) = parse_single_substitution(input)?;
+            Ok((input, SubstitutionSubtable::Single(single_substitution)))
+        },
+        2 => {
+            let (input, multiple_substitution) = parse_multiple_substitution(input)?;
+            Ok((input, SubstitutionSubtable::Multiple(multiple_substitution)))
+        },
+        3 => {
+            let (input, alternate_substitution) = parse_alternate_substitution(input)?;
+            Ok((input, SubstitutionSubtable::Alternate(alternate_substitution)))
+        },
+        4 => {
+            let (input, ligature_substitution) = parse_ligature_substitution(input)?;
+            Ok((input, SubstitutionSubtable::Ligature(ligature_substitution)))
+        },
+        5 => {
+            let (input, sequence_context) = parse_sequence_context(input)?;
+            Ok((input, SubstitutionSubtable::Context(sequence_context)))
+        },
+        6 => {
+            let (input, chained_sequence_context) = parse_chained_sequence_context(input)?;
+            Ok((input, SubstitutionSubtable::ChainedContext(chained_sequence_context)))
+        },
+        7 => parse_extension_substitution(input),
+        8 => {
+            let (input, reverse_chain_single_substitution) = parse_reverse_chain_single_substitution(input)?;
+            Ok((input, SubstitutionSubtable::ReverseChainSingle(reverse_chain_single_substitution)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+/// Parse the subtable an Extension Substitution subtable points to.
+fn parse_extension_substitution(input: &[u8]) -> IResult<&[u8], SubstitutionSubtable>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, extension_lookup_type) = be_u16(remainder)?;
+    let (remainder, extension_offset) = be_u32(remainder)?;
+
+    // An extension subtable cannot point to another extension subtable
+    if extension_lookup_type == 7 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (_, subtable) = parse_substitution_subtable(offset_slice(input, extension_offset as usize)?, extension_lookup_type)?;
+
+    Ok((remainder, subtable))
+}
+
+fn parse_single_substitution(input: &[u8]) -> IResult<&[u8], SingleSubstitution>
+{
+    let (remainder, subst_format) = be_u16(input)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (_, coverage) = parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?;
+
+    match subst_format {
+        1 => {
+            let (remainder, delta_glyph_id) = be_i16(remainder)?;
+
+            Ok((remainder, SingleSubstitution::Format1(SingleSubstitutionFormat1 {
+                coverage,
+                delta_glyph_id
+            })))
+        },
+        2 => {
+            let (remainder, substitute_glyph_ids) = parse_array16(remainder)?;
+
+            Ok((remainder, SingleSubstitution::Format2(SingleSubstitutionFormat2 {
+                coverage,
+                substitute_glyph_ids
+            })))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+/// Parse the coverage and the offsets of the per-coverage index tables of a lookup subtable.
+fn parse_coverage_and_offsets(input: &[u8]) -> IResult<&[u8], (Coverage, Vec<u16>)>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (remainder, offsets) = parse_array16(remainder)?;
+    let (_, coverage) = parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?;
+
+    Ok((remainder, (coverage, offsets)))
+}
+
+fn parse_multiple_substitution(input: &[u8]) -> IResult<&[u8], MultipleSubstitution>
+{
+    let (remainder, (coverage, sequence_offsets)) = parse_coverage_and_offsets(input)?;
+
+    Ok((remainder, MultipleSubstitution {
+        coverage,
+        sequences: parse_offsets(input, &sequence_offsets, parse_array16)?
+    }))
+}
+
+fn parse_alternate_substitution(input: &[u8]) -> IResult<&[u8], AlternateSubstitution>
+{
+    let (remainder, (coverage, alternate_set_offsets)) = parse_coverage_and_offsets(input)?;
+
+    Ok((remainder, AlternateSubstitution {
+        coverage,
+        alternate_sets: parse_offsets(input, &alternate_set_offsets, parse_array16)?
+    }))
+}
+
+fn parse_ligature_substitution(input: &[u8]) -> IResult<&[u8], LigatureSubstitution>
+{
+    let (remainder, (coverage, ligature_set_offsets)) = parse_coverage_and_offsets(input)?;
+
+    Ok((remainder, LigatureSubstitution {
+        coverage,
+        ligature_sets: parse_offsets(input, &ligature_set_offsets, parse_ligature_set)?
+    }))
+}
+
+fn parse_ligature_set(input: &[u8]) -> IResult<&[u8], Vec<Ligature>>
+{
+    let (remainder, ligature_offsets) = parse_array16(input)?;
+
+    Ok((remainder, parse_offsets(input, &ligature_offsets, parse_ligature)?))
+}
+
+fn parse_ligature(input: &[u8]) -> IResult<&[u8], Ligature>
+{
+    let (input, ligature_glyph) = be_u16(input)?;
+    let (input, component_count) = be_u16(input)?;
+    let (input, component_glyph_ids) = count(be_u16, usize::from(component_count.saturating_sub(1)))(input)?;
+
+    Ok((input, Ligature {
+        ligature_glyph,
+        component_glyph_ids
+    }))
+}
+
+fn parse_reverse_chain_single_substitution(input: &[u8]) -> IResult<&[u8], ReverseChainSingleSubstitution>
+{
+    let (remainder, _) = verify_format(input, 1)?;
+    let (remainder, coverage_offset) = be_u16(remainder)?;
+    let (remainder, backtrack_coverage_offsets) = parse_array16(remainder)?;
+    let (remainder, lookahead_coverage_offsets) = parse_array16(remainder)?;
+    let (remainder, substitute_glyph_ids) = parse_array16(remainder)?;
+
+    Ok((remainder, ReverseChainSingleSubstitution {
+        coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+        backtrack_coverages: parse_offsets(input, &backtrack_coverage_offsets, parse_coverage)?,
+        lookahead_coverages: parse_offsets(input, &lookahead_coverage_offsets, parse_coverage)?,
+        substitute_glyph_ids
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otff::OpenTypeFontFile;
+    use tables::{Tag, TableTag};
+
+    static ROBOTO_REGULAR: &[u8] = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf");
+
+    #[test]
+    fn case_single_substitution() {
+        let format_1: &[u8]  = &[0x00, 0x01, 0x00, 0x06, 0xFF, 0xFE, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A];
+
+        match parse_substitution_subtable(format_1, 1).unwrap().1 {
+            SubstitutionSubtable::Single(single_substitution) => {
+                assert_eq!(single_substitution.substitute(10), Some(8));
+                assert_eq!(single_substitution.substitute(11), None);
+            },
+            _ => assert!(false)
+        }
+
+        let format_2: &[u8]  = &[
+            0x00, 0x02, 0x00, 0x0A, 0x00, 0x02, 0x00, 0x14, 0x00, 0x15, 0x00, 0x01, 0x00, 0x02,
+            0x00, 0x0A, 0x00, 0x0B];
+
+        match parse_substitution_subtable(format_2, 1).unwrap().1 {
+            SubstitutionSubtable::Single(single_substitution) => {
+                assert_eq!(single_substitution.substitute(10), Some(20));
+                assert_eq!(single_substitution.substitute(11), Some(21));
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_multiple_substitution() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x08, 0x00, 0x01, 0x00, 0x0E, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A,
+            0x00, 0x02, 0x00, 0x14, 0x00, 0x15];
+
+        match parse_substitution_subtable(bytes, 2).unwrap().1 {
+            SubstitutionSubtable::Multiple(multiple_substitution) => {
+                assert_eq!(multiple_substitution.substitute(10), Some(&[20, 21][..]));
+                assert_eq!(multiple_substitution.substitute(11), None);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_extension_substitution() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x08, 0x00, 0x01,
+            0x00, 0x0E, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A, 0x00, 0x02, 0x00, 0x14, 0x00, 0x15];
+
+        match parse_substitution_subtable(bytes, 7).unwrap().1 {
+            SubstitutionSubtable::Alternate(alternate_substitution) => {
+                assert_eq!(alternate_substitution.alternates(10), Some(&[20, 21][..]));
+            },
+            _ => assert!(false)
+        }
+
+        // Extension subtables cannot be nested
+        let nested: &[u8]  = &[0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00];
+        assert!(parse_substitution_subtable(nested, 7).is_err());
+    }
+
+    #[test]
+    fn case_reverse_chain_single_substitution() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x10, 0x00, 0x01, 0x00, 0x16, 0x00, 0x00, 0x00, 0x01, 0x00, 0x1E,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0A, 0x00, 0x01, 0x00, 0x01, 0x00, 0x05];
+
+        match parse_substitution_subtable(bytes, 8).unwrap().1 {
+            SubstitutionSubtable::ReverseChainSingle(reverse_chain_single_substitution) => {
+                assert_eq!(reverse_chain_single_substitution.coverage().coverage_index(10), Some(0));
+                assert_eq!(reverse_chain_single_substitution.backtrack_coverages(), &[Coverage::Format1(vec![5])]);
+                assert!(reverse_chain_single_substitution.lookahead_coverages().is_empty());
+                assert_eq!(reverse_chain_single_substitution.substitute_glyph_ids(), &[30]);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_glyph_substitution_table() {
+        let font = OpenTypeFontFile::parse(ROBOTO_REGULAR).unwrap().into_iter().next().unwrap();
+        let table = font.table(TableTag::Gsub).unwrap();
+        let gsub = parse_glyph_substitution_table(table.as_slice().unwrap()).unwrap().1;
+
+        assert_eq!(gsub.major_version(), 1);
+        assert_eq!(gsub.feature_variations_offset(), None);
+        assert!(gsub.script_list().script(Tag::new(b"latn")).is_some());
+        assert!(!gsub.lookup_list().lookups().is_empty());
+    }
+}
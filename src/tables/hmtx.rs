@@ -2,6 +2,8 @@ use error::Error;
 use nom::IResult;
 use nom::number::complete::{be_i16, be_u16};
 use nom::multi::count;
+use tables::GlyphId;
+use types::Rect;
 
 /// Horizontal Metrics Table
 ///
@@ -70,6 +72,53 @@ impl<'otf> HorizontalMetricsTable {
         &self.left_side_bearings
     }
 
+    /// Advance width of the given glyph.
+    ///
+    /// Glyphs past the last longHorMetric record use the advance width of the last record.
+    /// Return None if the glyph ID is greater or equal to the number of glyphs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::hmtx::HorizontalMetricsTable;
+    ///
+    /// let bytes: &[u8]  = &[0x03, 0x8C, 0x00, 0x64, 0x01, 0xFB, 0x00, 0x00, 0xFF, 0xF6];
+    ///
+    /// let horizontal_metrics_table = HorizontalMetricsTable::parse(bytes, 2, 3).unwrap();
+    ///
+    /// assert_eq!(horizontal_metrics_table.advance_width(0), Some(908));
+    /// assert_eq!(horizontal_metrics_table.advance_width(2), Some(507));
+    /// assert_eq!(horizontal_metrics_table.left_side_bearing(2), Some(-10));
+    /// assert_eq!(horizontal_metrics_table.advance_width(3), None);
+    /// ```
+    pub fn advance_width(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self.h_metrics.get(usize::from(glyph_id)) {
+            Some(h_metric) => Some(h_metric.advance_width()),
+            None if self.left_side_bearing(glyph_id).is_some() => self.h_metrics.last().map(LongHorMetricRecord::advance_width),
+            None => None
+        }
+    }
+
+    /// Left side bearing of the given glyph, taken from the longHorMetric records or from the
+    /// left side bearings of the remaining glyphs.
+    pub fn left_side_bearing(&self, glyph_id: GlyphId) -> Option<i16> {
+        match self.h_metrics.get(usize::from(glyph_id)) {
+            Some(h_metric) => Some(h_metric.lsb()),
+            None => self.left_side_bearings.get(usize::from(glyph_id) - self.h_metrics.len()).cloned()
+        }
+    }
+
+    /// Right side bearing of the given glyph, computed from its bounding box as
+    /// `rsb = aw - (lsb + xMax - xMin)` (see [LongHorMetricRecord](struct.LongHorMetricRecord.html)).
+    pub fn right_side_bearing(&self, glyph_id: GlyphId, bounding_box: Rect<i16>) -> Option<i32> {
+        let advance_width = i32::from(self.advance_width(glyph_id)?);
+        let lsb = i32::from(self.left_side_bearing(glyph_id)?);
+
+        Some(advance_width - (lsb + i32::from(bounding_box.x_max()) - i32::from(bounding_box.x_min())))
+    }
+
     /// Parse Horizontal Metrics Table.
     ///
     /// * `number_of_hmetrics` - The number of longHorMetric records is determined by the
@@ -182,6 +231,27 @@ mod tests {
         assert_eq!(res,  expected);
     }
 
+    #[test]
+    fn case_horizontal_metrics_table_glyph_metrics() {
+        let bytes: &[u8] = &[0x03, 0x8C, 0x00, 0x64, 0x04, 0x90, 0x00, 0x5B, 0x00, 0x14,
+            0xFF, 0xEC];
+
+        let horizontal_metrics_table = parse_horizontal_metrics_table(bytes, 2, 4).unwrap().1;
+
+        assert_eq!(horizontal_metrics_table.advance_width(1), Some(1168));
+        assert_eq!(horizontal_metrics_table.left_side_bearing(1), Some(91));
+        assert_eq!(horizontal_metrics_table.right_side_bearing(1, Rect::new(91, -20, 1076, 1102)), Some(92));
+
+        // Trailing glyphs reuse the last advance width
+        assert_eq!(horizontal_metrics_table.advance_width(3), Some(1168));
+        assert_eq!(horizontal_metrics_table.left_side_bearing(3), Some(-20));
+        assert_eq!(horizontal_metrics_table.right_side_bearing(3, Rect::new(-20, 0, 1000, 700)), Some(168));
+
+        assert_eq!(horizontal_metrics_table.advance_width(4), None);
+        assert_eq!(horizontal_metrics_table.left_side_bearing(4), None);
+        assert_eq!(horizontal_metrics_table.right_side_bearing(4, Rect::new(0, 0, 0, 0)), None);
+    }
+
     #[test]
     fn case_horizontal_metrics_table_invalid_empty_slice() {
         let bytes: &[u8] = &[];
@@ -0,0 +1,491 @@
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_i16, be_u16, be_u32};
+use tables::GlyphId;
+use tables::layout::offset_slice;
+use types::Fixed;
+
+/// Kerning Table
+///
+/// The kerning table contains the values that control the inter-character spacing for the glyphs
+/// in a font. OpenType fonts containing CFF outlines are not supported by the 'kern' table and
+/// must use the GPOS OpenType Layout table.
+///
+/// Two versions of the table header exist: the Microsoft version 0 with 16-bit fields and the
+/// Apple version 1.0 with 32-bit fields. The coverage flags of both subtable headers are
+/// normalized to [KerningCoverage](struct.KerningCoverage.html).
+///
+/// More information on ['kern'](https://docs.microsoft.com/en-gb/typography/opentype/spec/kern)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KerningTable {
+    version: Fixed,
+    subtables: Vec<KerningSubtable>
+}
+
+impl KerningTable {
+    /// Table version number; 0 for Microsoft tables and 0x00010000 for Apple tables.
+    pub fn version(&self) -> Fixed {
+        self.version
+    }
+
+    /// Kerning subtables, in the order they are applied.
+    pub fn subtables(&self) -> &[KerningSubtable] {
+        &self.subtables
+    }
+
+    /// Horizontal kerning value, in design units, of the given pair of glyphs.
+    ///
+    /// The values of the horizontal subtables are accumulated, a subtable with the override flag
+    /// replacing the accumulated value. Cross-stream, minimum and variation subtables are not
+    /// taken into account.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> i16 {
+        self.subtables.iter()
+            .filter(|subtable| subtable.coverage() & !KerningCoverage::OVERRIDE == KerningCoverage::HORIZONTAL)
+            .fold(0i16, |kerning, subtable| match subtable.kerning(left, right) {
+                Some(value) if subtable.coverage().contains(KerningCoverage::OVERRIDE) => value,
+                Some(value) => kerning.saturating_add(value),
+                None => kerning
+            })
+    }
+}
+
+impl_parse!(
+    /// Parse Kerning Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::kern::{KerningCoverage, KerningTable};
+    /// use otf::parser::Parse;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x00, 0x03, 0x00, 0x0C,
+    ///     0x00, 0x01, 0x00, 0x06, 0x00, 0x0A, 0x00, 0x0B, 0xFF, 0xCE, 0x00, 0x0A, 0x00, 0x2A,
+    ///     0xFF, 0xEC, 0x00, 0x24, 0x00, 0x0A, 0x00, 0x14];
+    ///
+    /// let kerning_table = KerningTable::parse(bytes).unwrap().1;
+    ///
+    /// assert_eq!(kerning_table.version(), 0);
+    /// assert_eq!(kerning_table.subtables().len(), 1);
+    /// assert_eq!(kerning_table.subtables()[0].coverage(), KerningCoverage::HORIZONTAL);
+    ///
+    /// assert_eq!(kerning_table.kerning(10, 11), -50);
+    /// assert_eq!(kerning_table.kerning(10, 42), -20);
+    /// assert_eq!(kerning_table.kerning(36, 10), 20);
+    /// assert_eq!(kerning_table.kerning(11, 10), 0);
+    /// ```
+    KerningTable, parse_kerning_table
+);
+
+bitflags! {
+    /// Coverage of a kerning subtable, normalized from the Microsoft and Apple subtable headers.
+    pub struct KerningCoverage: u8 {
+        #[doc="Table has horizontal data, otherwise vertical"]
+        const HORIZONTAL = 0x01;
+        #[doc="Table has minimum values, otherwise kerning values (Microsoft only)"]
+        const MINIMUM = 0x02;
+        #[doc="Kerning is perpendicular to the flow of the text"]
+        const CROSS_STREAM = 0x04;
+        #[doc="Value in this table should replace the value currently being accumulated (Microsoft only)"]
+        const OVERRIDE = 0x08;
+        #[doc="Table has variation kerning values (Apple only)"]
+        const VARIATION = 0x10;
+    }
+}
+
+/// A kerning subtable, with its normalized coverage.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KerningSubtable {
+    coverage: KerningCoverage,
+    tuple_index: u16,
+    format: KerningSubtableFormat
+}
+
+impl KerningSubtable {
+    /// What type of information is contained in this table.
+    pub fn coverage(&self) -> KerningCoverage {
+        self.coverage
+    }
+
+    /// The tuple index of variation subtables (Apple only), 0 otherwise.
+    pub fn tuple_index(&self) -> u16 {
+        self.tuple_index
+    }
+
+    pub fn format(&self) -> &KerningSubtableFormat {
+        &self.format
+    }
+
+    /// Kerning value of the given pair of glyphs, None if the subtable does not contain the pair.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
+        match self.format {
+            KerningSubtableFormat::Format0(ref kerning_pairs) => {
+                let key = kerning_pair_key(left, right);
+                kerning_pairs.binary_search_by_key(&key, |kerning_pair| kerning_pair_key(kerning_pair.left, kerning_pair.right))
+                    .ok()
+                    .map(|index| kerning_pairs[index].value)
+            },
+            KerningSubtableFormat::Format2(ref kerning_subtable) => kerning_subtable.kerning(left, right),
+            KerningSubtableFormat::Unsupported(_) => None
+        }
+    }
+}
+
+fn kerning_pair_key(left: GlyphId, right: GlyphId) -> u32 {
+    u32::from(left) << 16 | u32::from(right)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum KerningSubtableFormat {
+    /// Ordered list of kerning pairs.
+    Format0(Vec<KerningPair>),
+    /// Two-dimensional array of kerning values indexed by glyph classes.
+    Format2(KerningSubtableFormat2),
+    /// Subtable format not supported, such as the Apple state tables (format 1) and compact
+    /// class-based subtables (format 3).
+    Unsupported(u8)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct KerningPair {
+    left: GlyphId,
+    right: GlyphId,
+    value: i16
+}
+
+impl KerningPair {
+    /// The glyph index for the left-hand glyph in the kerning pair.
+    pub fn left(&self) -> GlyphId {
+        self.left
+    }
+
+    /// The glyph index for the right-hand glyph in the kerning pair.
+    pub fn right(&self) -> GlyphId {
+        self.right
+    }
+
+    /// The kerning value for the above pair, in font design units. If this value is greater than
+    /// zero, the characters will be moved apart. If this value is less than zero, the character
+    /// will be moved closer together.
+    pub fn value(&self) -> i16 {
+        self.value
+    }
+}
+
+/// Class-based kerning subtable.
+///
+/// The left class values are byte offsets, from the beginning of the subtable, to the rows of
+/// the kerning array and the right class values are byte offsets within a row. The kerning value
+/// of a pair is found at the sum of both offsets, glyphs absent from the left class table having
+/// no kerning.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KerningSubtableFormat2 {
+    row_width: u16,
+    left_class_table: KerningClassTable,
+    right_class_table: KerningClassTable,
+    array_offset: u16,
+    kerning_values: Vec<i16>
+}
+
+impl KerningSubtableFormat2 {
+    /// The width, in bytes, of a row in the table.
+    pub fn row_width(&self) -> u16 {
+        self.row_width
+    }
+
+    /// Class table of the left-hand glyphs.
+    pub fn left_class_table(&self) -> &KerningClassTable {
+        &self.left_class_table
+    }
+
+    /// Class table of the right-hand glyphs.
+    pub fn right_class_table(&self) -> &KerningClassTable {
+        &self.right_class_table
+    }
+
+    /// Offset, from the beginning of the subtable, to the kerning array.
+    pub fn array_offset(&self) -> u16 {
+        self.array_offset
+    }
+
+    /// Kerning values of the kerning array, row after row.
+    pub fn kerning_values(&self) -> &[i16] {
+        &self.kerning_values
+    }
+
+    /// Kerning value of the given pair of glyphs, None if the left glyph has no class or if the
+    /// pair is outside of the kerning array.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
+        let left_class = self.left_class_table.class(left).filter(|&left_class| left_class != 0)?;
+        let right_class = self.right_class_table.class(right).unwrap_or(0);
+
+        let offset = (usize::from(left_class) + usize::from(right_class)).checked_sub(usize::from(self.array_offset))?;
+        self.kerning_values.get(offset / 2).cloned()
+    }
+}
+
+/// Class table of a class-based kerning subtable.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KerningClassTable {
+    first_glyph: GlyphId,
+    classes: Vec<u16>
+}
+
+impl KerningClassTable {
+    /// First glyph in the class range.
+    pub fn first_glyph(&self) -> GlyphId {
+        self.first_glyph
+    }
+
+    /// The class values, one for each glyph from the first glyph.
+    pub fn classes(&self) -> &[u16] {
+        &self.classes
+    }
+
+    /// Class value of the given glyph, None if the glyph is outside of the class range.
+    pub fn class(&self, glyph_id: GlyphId) -> Option<u16> {
+        glyph_id.checked_sub(self.first_glyph)
+            .and_then(|index| self.classes.get(usize::from(index)))
+            .cloned()
+    }
+}
+
+pub fn parse_kerning_table(input: &[u8]) -> IResult<&[u8], KerningTable>
+{
+    let (_, version) = be_u16(input)?;
+
+    match version {
+        0 => {
+            let (remainder, _) = be_u16(input)?;
+            let (mut remainder, n_tables) = be_u16(remainder)?;
+
+            let mut subtables = Vec::with_capacity(usize::from(n_tables));
+
+            for _ in 0..n_tables {
+                let (subtable, _) = be_u16(remainder)?;
+                let (subtable, length) = be_u16(subtable)?;
+
+                // Each subtable must at least hold its header for the loop to advance
+                if length < 6 {
+                    return Err(NomErr::Error(error_position!(remainder, ErrorKind::Verify)));
+                }
+
+                let (subtable, coverage) = be_u16(subtable)?;
+
+                subtables.push(KerningSubtable {
+                    coverage: KerningCoverage::from_bits_truncate(coverage as u8),
+                    tuple_index: 0,
+                    format: parse_kerning_subtable_format(kerning_subtable(remainder, usize::from(length)), subtable, (coverage >> 8) as u8)?
+                });
+
+                remainder = next_kerning_subtable(remainder, usize::from(length));
+            }
+
+            Ok((remainder, KerningTable {
+                version: 0,
+                subtables
+            }))
+        },
+        1 => {
+            let (remainder, version) = be_u32(input)?;
+
+            if version != 0x00010000 {
+                return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+            }
+
+            let (mut remainder, n_tables) = be_u32(remainder)?;
+            let mut subtables = Vec::new();
+
+            for _ in 0..n_tables {
+                let (subtable, length) = be_u32(remainder)?;
+
+                // Each subtable must at least hold its header for the loop to advance
+                if length < 8 {
+                    return Err(NomErr::Error(error_position!(remainder, ErrorKind::Verify)));
+                }
+
+                let (subtable, coverage) = be_u16(subtable)?;
+                let (subtable, tuple_index) = be_u16(subtable)?;
+
+                let mut kerning_coverage = KerningCoverage::empty();
+                kerning_coverage.set(KerningCoverage::HORIZONTAL, coverage & 0x8000 == 0);
+                kerning_coverage.set(KerningCoverage::CROSS_STREAM, coverage & 0x4000 != 0);
+                kerning_coverage.set(KerningCoverage::VARIATION, coverage & 0x2000 != 0);
+
+                subtables.push(KerningSubtable {
+                    coverage: kerning_coverage,
+                    tuple_index,
+                    format: parse_kerning_subtable_format(kerning_subtable(remainder, length as usize), subtable, coverage as u8)?
+                });
+
+                remainder = next_kerning_subtable(remainder, length as usize);
+            }
+
+            Ok((remainder, KerningTable {
+                version: version as Fixed,
+                subtables
+            }))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Verify)))
+    }
+}
+
+/// Bytes of a subtable, up to the end of the table if its length is wrong.
+fn kerning_subtable(subtable: &[u8], length: usize) -> &[u8] {
+    subtable.get(..length).unwrap_or(subtable)
+}
+
+/// Skip to the next subtable. The length of a subtable may be wrong, the length field of the
+/// Microsoft headers overflowing for large format 0 subtables, in which case the end of the table
+/// is reached.
+fn next_kerning_subtable(subtable: &[u8], length: usize) -> &[u8] {
+    subtable.get(length..).unwrap_or(&subtable[subtable.len()..])
+}
+
+/// Parse the data of a kerning subtable, the offsets of class-based subtables being relative to
+/// the beginning of the subtable header. Format 0 pairs are not bounded by the subtable length as
+/// it may overflow.
+fn parse_kerning_subtable_format<'a>(subtable: &'a [u8], input: &'a [u8], format: u8) -> Result<KerningSubtableFormat, NomErr<(&'a [u8], ErrorKind)>>
+{
+    match format {
+        0 => {
+            let (input, n_pairs) = be_u16(input)?;
+            // searchRange, entrySelector and rangeShift
+            let input = offset_slice(input, 6)?;
+
+            let (_, kerning_pairs) = count(|input| {
+                let (input, left) = be_u16(input)?;
+                let (input, right) = be_u16(input)?;
+                let (input, value) = be_i16(input)?;
+
+                Ok((input, KerningPair {
+                    left,
+                    right,
+                    value
+                }))
+            }, usize::from(n_pairs))(input)?;
+
+            Ok(KerningSubtableFormat::Format0(kerning_pairs))
+        },
+        2 => {
+            let (input, row_width) = be_u16(input)?;
+            let (input, left_class_table_offset) = be_u16(input)?;
+            let (input, right_class_table_offset) = be_u16(input)?;
+            let (_, array_offset) = be_u16(input)?;
+
+            let kerning_array = offset_slice(subtable, usize::from(array_offset))?;
+            let (_, kerning_values) = count(be_i16, kerning_array.len() / 2)(kerning_array)?;
+
+            Ok(KerningSubtableFormat::Format2(KerningSubtableFormat2 {
+                row_width,
+                left_class_table: parse_kerning_class_table(offset_slice(subtable, usize::from(left_class_table_offset))?)?.1,
+                right_class_table: parse_kerning_class_table(offset_slice(subtable, usize::from(right_class_table_offset))?)?.1,
+                array_offset,
+                kerning_values
+            }))
+        },
+        _ => Ok(KerningSubtableFormat::Unsupported(format))
+    }
+}
+
+fn parse_kerning_class_table(input: &[u8]) -> IResult<&[u8], KerningClassTable>
+{
+    let (input, first_glyph) = be_u16(input)?;
+    let (input, n_glyphs) = be_u16(input)?;
+    let (input, classes) = count(be_u16, usize::from(n_glyphs))(input)?;
+
+    Ok((input, KerningClassTable {
+        first_glyph,
+        classes
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_kerning_table_apple_format_2() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x28, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x04, 0x00, 0x10, 0x00, 0x18, 0x00, 0x20, 0x00, 0x0A, 0x00, 0x02,
+            0x00, 0x20, 0x00, 0x24, 0x00, 0x14, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+            0xFF, 0xF6, 0xFF, 0xE2, 0x00, 0x05];
+
+        let kerning_table = parse_kerning_table(bytes).unwrap().1;
+
+        assert_eq!(kerning_table.version(), 0x00010000);
+        assert_eq!(kerning_table.subtables()[0].coverage(), KerningCoverage::HORIZONTAL);
+
+        match kerning_table.subtables()[0].format() {
+            KerningSubtableFormat::Format2(kerning_subtable) => {
+                assert_eq!(kerning_subtable.row_width(), 4);
+                assert_eq!(kerning_subtable.kerning_values(), &[0, -10, -30, 5]);
+            },
+            _ => assert!(false)
+        }
+
+        assert_eq!(kerning_table.kerning(10, 20), 0);
+        assert_eq!(kerning_table.kerning(10, 21), -10);
+        assert_eq!(kerning_table.kerning(11, 20), -30);
+        assert_eq!(kerning_table.kerning(11, 21), 5);
+
+        // Glyphs outside of the class tables
+        assert_eq!(kerning_table.kerning(12, 21), 0);
+        assert_eq!(kerning_table.kerning(11, 22), -30);
+    }
+
+    #[test]
+    fn case_kerning_table_coverage() {
+        let bytes: &[u8]  = &[
+            0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0x01, 0x00, 0x0C,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x0B, 0xFF, 0xCE, 0x00, 0x00, 0x00, 0x14,
+            0x00, 0x05, 0x00, 0x01, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x0B,
+            0x00, 0x64, 0x00, 0x00, 0x00, 0x14, 0x00, 0x09, 0x00, 0x01, 0x00, 0x0C, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x0A, 0x00, 0x0B, 0xFF, 0xEC];
+
+        let kerning_table = parse_kerning_table(bytes).unwrap().1;
+
+        let coverages: Vec<KerningCoverage> = kerning_table.subtables().iter().map(KerningSubtable::coverage).collect();
+        assert_eq!(coverages, vec![
+            KerningCoverage::HORIZONTAL,
+            KerningCoverage::HORIZONTAL | KerningCoverage::CROSS_STREAM,
+            KerningCoverage::HORIZONTAL | KerningCoverage::OVERRIDE]);
+
+        // The cross-stream subtable is ignored and the last subtable overrides the first one
+        assert_eq!(kerning_table.kerning(10, 11), -20);
+        assert_eq!(kerning_table.subtables()[0].kerning(10, 11), Some(-50));
+    }
+
+    #[test]
+    fn case_kerning_table_microsoft_invalid_subtable_length() {
+        let bytes: &[u8]  = &[
+            0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00];
+
+        let expected = Err(NomErr::Error(error_position!(&bytes[4..], ErrorKind::Verify)));
+        assert_eq!(parse_kerning_table(bytes), expected);
+    }
+
+    #[test]
+    fn case_kerning_table_apple_invalid_subtable_length() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        let expected = Err(NomErr::Error(error_position!(&bytes[8..], ErrorKind::Verify)));
+        assert_eq!(parse_kerning_table(bytes), expected);
+    }
+
+    #[test]
+    fn case_kerning_table_invalid_version() {
+        let bytes: &[u8]  = &[0x00, 0x02, 0x00, 0x00];
+
+        let expected = Err(NomErr::Error(error_position!(bytes, ErrorKind::Verify)));
+        assert_eq!(parse_kerning_table(bytes), expected);
+    }
+}
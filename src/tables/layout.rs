@@ -0,0 +1,1337 @@
+//! Common table formats used by the OpenType layout tables ('GSUB', 'GPOS' and 'GDEF').
+//!
+//! https://docs.microsoft.com/en-gb/typography/opentype/spec/chapter2
+
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::bytes::complete::take;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::be_u16;
+use std::cmp::Ordering;
+use tables::{GlyphId, Tag};
+
+/// Script List Table
+///
+/// The scripts supported by the font, each script defining the language systems that use the
+/// features of the font.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScriptList {
+    script_records: Vec<ScriptRecord>
+}
+
+impl ScriptList {
+    /// Array of ScriptRecords, listed alphabetically by script tag.
+    pub fn script_records(&self) -> &[ScriptRecord] {
+        &self.script_records
+    }
+
+    /// Script identified by the given tag.
+    ///
+    /// The script 'DFLT' is used by applications when a script is not explicitly supported.
+    pub fn script(&self, script_tag: Tag) -> Option<&Script> {
+        self.script_records.iter()
+            .find(|script_record| script_record.script_tag == script_tag)
+            .map(|script_record| &script_record.script)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScriptRecord {
+    script_tag: Tag,
+    script: Script
+}
+
+impl ScriptRecord {
+    /// 4-byte script tag identifier.
+    pub fn script_tag(&self) -> Tag {
+        self.script_tag
+    }
+
+    pub fn script(&self) -> &Script {
+        &self.script
+    }
+}
+
+/// Script Table
+///
+/// The language systems of a script, including an optional default language system used when
+/// the language is not explicitly supported.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Script {
+    default_lang_sys: Option<LangSys>,
+    lang_sys_records: Vec<LangSysRecord>
+}
+
+impl Script {
+    /// Default language system, if any.
+    pub fn default_lang_sys(&self) -> Option<&LangSys> {
+        self.default_lang_sys.as_ref()
+    }
+
+    /// Array of LangSysRecords, listed alphabetically by LangSys tag.
+    pub fn lang_sys_records(&self) -> &[LangSysRecord] {
+        &self.lang_sys_records
+    }
+
+    /// Language system identified by the given tag, the default language system being used
+    /// when no tag is given or when the language system is missing.
+    pub fn lang_sys(&self, lang_sys_tag: Option<Tag>) -> Option<&LangSys> {
+        lang_sys_tag
+            .and_then(|lang_sys_tag| self.lang_sys_records.iter()
+                .find(|lang_sys_record| lang_sys_record.lang_sys_tag == lang_sys_tag))
+            .map(|lang_sys_record| &lang_sys_record.lang_sys)
+            .or(self.default_lang_sys.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LangSysRecord {
+    lang_sys_tag: Tag,
+    lang_sys: LangSys
+}
+
+impl LangSysRecord {
+    /// 4-byte LangSysTag identifier.
+    pub fn lang_sys_tag(&self) -> Tag {
+        self.lang_sys_tag
+    }
+
+    pub fn lang_sys(&self) -> &LangSys {
+        &self.lang_sys
+    }
+}
+
+/// Language System Table
+///
+/// The features of the FeatureList used to render the glyphs of a language system.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LangSys {
+    required_feature_index: u16,
+    feature_indices: Vec<u16>
+}
+
+impl LangSys {
+    /// Index of a feature required for this language system, None if no required features.
+    pub fn required_feature_index(&self) -> Option<u16> {
+        if self.required_feature_index == 0xFFFF {
+            None
+        }
+        else {
+            Some(self.required_feature_index)
+        }
+    }
+
+    /// Array of indices into the FeatureList, in arbitrary order.
+    pub fn feature_indices(&self) -> &[u16] {
+        &self.feature_indices
+    }
+}
+
+/// Feature List Table
+///
+/// The typographic features of the font, each feature referencing the lookups that implement
+/// it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeatureList {
+    feature_records: Vec<FeatureRecord>
+}
+
+impl FeatureList {
+    /// Array of FeatureRecords, listed alphabetically by feature tag.
+    pub fn feature_records(&self) -> &[FeatureRecord] {
+        &self.feature_records
+    }
+
+    /// Indices into the LookupList of the features of the language system identified by the
+    /// given tag, the required feature included. The indices are sorted and deduplicated, which
+    /// is the order in which the lookups must be applied.
+    pub fn lookup_indices(&self, lang_sys: &LangSys, feature_tag: Tag) -> Vec<u16> {
+        let mut lookup_indices: Vec<u16> = lang_sys.required_feature_index().into_iter()
+            .chain(lang_sys.feature_indices().iter().cloned())
+            .filter_map(|feature_index| self.feature_records.get(usize::from(feature_index)))
+            .filter(|feature_record| feature_record.feature_tag == feature_tag)
+            .flat_map(|feature_record| feature_record.feature.lookup_list_indices.iter().cloned())
+            .collect();
+
+        lookup_indices.sort_unstable();
+        lookup_indices.dedup();
+        lookup_indices
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeatureRecord {
+    feature_tag: Tag,
+    feature: Feature
+}
+
+impl FeatureRecord {
+    /// 4-byte feature identification tag.
+    pub fn feature_tag(&self) -> Tag {
+        self.feature_tag
+    }
+
+    pub fn feature(&self) -> &Feature {
+        &self.feature
+    }
+}
+
+/// Feature Table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Feature {
+    feature_params_offset: u16,
+    lookup_list_indices: Vec<u16>
+}
+
+impl Feature {
+    /// Offset from the beginning of the Feature table to the feature parameters table, 0 if
+    /// there is none.
+    pub fn feature_params_offset(&self) -> u16 {
+        self.feature_params_offset
+    }
+
+    /// Array of indices into the LookupList, zero-based (first lookup is LookupListIndex = 0).
+    pub fn lookup_list_indices(&self) -> &[u16] {
+        &self.lookup_list_indices
+    }
+}
+
+/// Lookup List Table
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LookupList<T> {
+    lookups: Vec<Lookup<T>>
+}
+
+impl<T> LookupList<T> {
+    /// Array of lookups, in the order in which they are applied.
+    pub fn lookups(&self) -> &[Lookup<T>] {
+        &self.lookups
+    }
+
+    /// Lookup at the given index.
+    pub fn lookup(&self, lookup_index: u16) -> Option<&Lookup<T>> {
+        self.lookups.get(usize::from(lookup_index))
+    }
+}
+
+/// Lookup Table
+///
+/// A lookup is made of one or several subtables of the same lookup type, the lookup flags
+/// applying to all of them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Lookup<T> {
+    lookup_type: u16,
+    lookup_flag: LookupFlags,
+    subtables: Vec<T>,
+    mark_filtering_set: Option<u16>
+}
+
+impl<T> Lookup<T> {
+    /// Different enumerations for GSUB and GPOS.
+    pub fn lookup_type(&self) -> u16 {
+        self.lookup_type
+    }
+
+    /// Lookup qualifiers.
+    pub fn lookup_flag(&self) -> LookupFlags {
+        self.lookup_flag
+    }
+
+    /// If not zero, skips over all marks of attachment type different from specified.
+    pub fn mark_attachment_type(&self) -> u8 {
+        ((self.lookup_flag & LookupFlags::MARK_ATTACHMENT_TYPE_MASK).bits() >> 8) as u8
+    }
+
+    /// Array of lookup subtables.
+    pub fn subtables(&self) -> &[T] {
+        &self.subtables
+    }
+
+    /// Index (base 0) into GDEF mark glyph sets structure, present if the
+    /// USE_MARK_FILTERING_SET lookup flag is set.
+    pub fn mark_filtering_set(&self) -> Option<u16> {
+        self.mark_filtering_set
+    }
+}
+
+bitflags! {
+    #[doc="Lookup qualifiers."]
+    pub struct LookupFlags: u16 {
+        /// This bit relates only to the correct processing of the cursive attachment lookup type
+        /// (GPOS lookup type 3). When this bit is set, the last glyph in a given sequence to
+        /// which the cursive attachment lookup is applied, will be positioned on the baseline.
+        const RIGHT_TO_LEFT                 = 0x0001;
+        /// If set, skips over base glyphs.
+        const IGNORE_BASE_GLYPHS            = 0x0002;
+        /// If set, skips over ligatures.
+        const IGNORE_LIGATURES              = 0x0004;
+        /// If set, skips over all combining marks.
+        const IGNORE_MARKS                  = 0x0008;
+        /// If set, indicates that the lookup table structure is followed by a MarkFilteringSet
+        /// field. The layout engine skips over all mark glyphs not in the mark filtering set
+        /// indicated.
+        const USE_MARK_FILTERING_SET        = 0x0010;
+        /// If not zero, skips over all marks of attachment type different from specified.
+        const MARK_ATTACHMENT_TYPE_MASK     = 0xFF00;
+
+        // Bits 5–7 reserved
+    }
+}
+
+/// Coverage Table
+///
+/// The glyphs affected by a lookup subtable, each covered glyph having a coverage index used
+/// to find its data in the subtable.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Coverage {
+    /// Individual glyph indices, in numerical order.
+    Format1(Vec<GlyphId>),
+    /// Ranges of consecutive glyph indices, in numerical order.
+    Format2(Vec<RangeRecord>)
+}
+
+impl Coverage {
+    /// Coverage index of the given glyph, None if the glyph is not covered.
+    pub fn coverage_index(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self {
+            Coverage::Format1(glyph_array) => glyph_array.binary_search(&glyph_id).ok().map(|index| index as u16),
+            Coverage::Format2(range_records) => {
+                let index = range_records.binary_search_by(|range_record| {
+                    if range_record.end_glyph_id < glyph_id {
+                        Ordering::Less
+                    }
+                    else if range_record.start_glyph_id > glyph_id {
+                        Ordering::Greater
+                    }
+                    else {
+                        Ordering::Equal
+                    }
+                }).ok()?;
+                let range_record = &range_records[index];
+
+                range_record.start_coverage_index.checked_add(glyph_id - range_record.start_glyph_id)
+            }
+        }
+    }
+
+    /// Whether the given glyph is covered.
+    pub fn contains(&self, glyph_id: GlyphId) -> bool {
+        self.coverage_index(glyph_id).is_some()
+    }
+
+    /// Covered glyphs, in coverage index order.
+    pub fn glyphs(&self) -> Vec<GlyphId> {
+        match self {
+            Coverage::Format1(glyph_array) => glyph_array.clone(),
+            Coverage::Format2(range_records) => range_records.iter()
+                .flat_map(|range_record| range_record.start_glyph_id..=range_record.end_glyph_id)
+                .collect()
+        }
+    }
+}
+
+/// A range of consecutive glyph indices of a Coverage table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RangeRecord {
+    start_glyph_id: GlyphId,
+    end_glyph_id: GlyphId,
+    start_coverage_index: u16
+}
+
+impl RangeRecord {
+    /// First glyph ID in the range.
+    pub fn start_glyph_id(&self) -> GlyphId {
+        self.start_glyph_id
+    }
+
+    /// Last glyph ID in the range.
+    pub fn end_glyph_id(&self) -> GlyphId {
+        self.end_glyph_id
+    }
+
+    /// Coverage Index of first glyph ID in range.
+    pub fn start_coverage_index(&self) -> u16 {
+        self.start_coverage_index
+    }
+}
+
+/// Class Definition Table
+///
+/// Glyphs grouped in classes, every glyph not assigned to a class being in class 0.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ClassDef {
+    /// Classes of a range of consecutive glyph indices.
+    Format1(ClassDefFormat1),
+    /// Ranges of consecutive glyph indices sharing the same class.
+    Format2(Vec<ClassRangeRecord>)
+}
+
+/// A ClassDef without any range, assigning all the glyphs to class 0. This is the ClassDef of a
+/// null offset.
+impl Default for ClassDef {
+    fn default() -> Self {
+        ClassDef::Format2(Vec::new())
+    }
+}
+
+impl ClassDef {
+    /// Class of the given glyph.
+    pub fn class(&self, glyph_id: GlyphId) -> u16 {
+        match self {
+            ClassDef::Format1(class_def) => glyph_id.checked_sub(class_def.start_glyph_id)
+                .and_then(|index| class_def.class_value_array.get(usize::from(index)))
+                .cloned()
+                .unwrap_or(0),
+            ClassDef::Format2(class_range_records) => class_range_records.binary_search_by(|class_range_record| {
+                if class_range_record.end_glyph_id < glyph_id {
+                    Ordering::Less
+                }
+                else if class_range_record.start_glyph_id > glyph_id {
+                    Ordering::Greater
+                }
+                else {
+                    Ordering::Equal
+                }
+            }).map(|index| class_range_records[index].class).unwrap_or(0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassDefFormat1 {
+    start_glyph_id: GlyphId,
+    class_value_array: Vec<u16>
+}
+
+impl ClassDefFormat1 {
+    /// First glyph ID of the classValueArray.
+    pub fn start_glyph_id(&self) -> GlyphId {
+        self.start_glyph_id
+    }
+
+    /// Array of class values — one per glyph ID.
+    pub fn class_value_array(&self) -> &[u16] {
+        &self.class_value_array
+    }
+}
+
+/// A range of consecutive glyph indices sharing the same class.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ClassRangeRecord {
+    start_glyph_id: GlyphId,
+    end_glyph_id: GlyphId,
+    class: u16
+}
+
+impl ClassRangeRecord {
+    /// First glyph ID in the range.
+    pub fn start_glyph_id(&self) -> GlyphId {
+        self.start_glyph_id
+    }
+
+    /// Last glyph ID in the range.
+    pub fn end_glyph_id(&self) -> GlyphId {
+        self.end_glyph_id
+    }
+
+    /// Applied to all glyphs in the range.
+    pub fn class(&self) -> u16 {
+        self.class
+    }
+}
+
+/// Device or VariationIndex Table
+///
+/// Device tables adjust the scaled design units of a value at specific font sizes, VariationIndex
+/// tables reference the deltas of an ItemVariationStore in variable fonts. Both share the same
+/// offsets in the value records and anchor tables.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Device {
+    /// Adjustments for a range of pixel-per-em sizes.
+    Device(DeviceTable),
+    /// Index of a delta-set in the ItemVariationStore of the 'GDEF' table.
+    VariationIndex(VariationIndex)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeviceTable {
+    start_size: u16,
+    end_size: u16,
+    delta_format: u16,
+    delta_values: Vec<i8>
+}
+
+impl DeviceTable {
+    /// Smallest size to correct, in ppem.
+    pub fn start_size(&self) -> u16 {
+        self.start_size
+    }
+
+    /// Largest size to correct, in ppem.
+    pub fn end_size(&self) -> u16 {
+        self.end_size
+    }
+
+    /// Format of the packed deltas: 1 for signed 2-bit values, 2 for signed 4-bit values and 3
+    /// for signed 8-bit values.
+    pub fn delta_format(&self) -> u16 {
+        self.delta_format
+    }
+
+    /// Decoded adjustments, one per size from start_size to end_size.
+    pub fn delta_values(&self) -> &[i8] {
+        &self.delta_values
+    }
+
+    /// Adjustment, in pixels, at the given size in ppem, 0 outside of the corrected sizes.
+    pub fn delta(&self, ppem: u16) -> i8 {
+        ppem.checked_sub(self.start_size)
+            .and_then(|index| self.delta_values.get(usize::from(index)))
+            .cloned()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VariationIndex {
+    delta_set_outer_index: u16,
+    delta_set_inner_index: u16
+}
+
+impl VariationIndex {
+    /// A delta-set outer index — used to select an item variation data subtable within the item
+    /// variation store.
+    pub fn delta_set_outer_index(&self) -> u16 {
+        self.delta_set_outer_index
+    }
+
+    /// A delta-set inner index — used to select a delta-set row within an item variation data
+    /// subtable.
+    pub fn delta_set_inner_index(&self) -> u16 {
+        self.delta_set_inner_index
+    }
+}
+
+/// A lookup to apply at a position of the input sequence matched by a contextual lookup.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SequenceLookupRecord {
+    sequence_index: u16,
+    lookup_list_index: u16
+}
+
+impl SequenceLookupRecord {
+    /// Index (zero-based) into the input glyph sequence.
+    pub fn sequence_index(&self) -> u16 {
+        self.sequence_index
+    }
+
+    /// Index (zero-based) into the LookupList.
+    pub fn lookup_list_index(&self) -> u16 {
+        self.lookup_list_index
+    }
+}
+
+/// Sequence Context
+///
+/// Contextual lookups match an input sequence of glyphs, described by glyph IDs, glyph classes
+/// or coverage tables, and apply nested lookups to the matched glyphs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SequenceContext {
+    /// Simple glyph contexts.
+    Format1(SequenceContextFormat1),
+    /// Class-based glyph contexts.
+    Format2(SequenceContextFormat2),
+    /// Coverage-based glyph contexts.
+    Format3(SequenceContextFormat3)
+}
+
+/// Sequence rules matching glyph IDs, grouped by the coverage index of their first glyph.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SequenceContextFormat1 {
+    coverage: Coverage,
+    seq_rule_sets: Vec<Vec<SequenceRule>>
+}
+
+impl SequenceContextFormat1 {
+    /// Coverage of the first glyph of the input sequences.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of SequenceRuleSets, ordered by coverage index. Null rule sets are empty.
+    pub fn seq_rule_sets(&self) -> &[Vec<SequenceRule>] {
+        &self.seq_rule_sets
+    }
+}
+
+/// Sequence rules matching glyph classes, grouped by the class of their first glyph.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SequenceContextFormat2 {
+    coverage: Coverage,
+    class_def: ClassDef,
+    class_seq_rule_sets: Vec<Vec<SequenceRule>>
+}
+
+impl SequenceContextFormat2 {
+    /// Coverage of the first glyph of the input sequences.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Classes of the glyphs of the input sequences.
+    pub fn class_def(&self) -> &ClassDef {
+        &self.class_def
+    }
+
+    /// Array of ClassSequenceRuleSets, ordered by class. Null rule sets are empty.
+    pub fn class_seq_rule_sets(&self) -> &[Vec<SequenceRule>] {
+        &self.class_seq_rule_sets
+    }
+}
+
+/// A single input sequence, each position being matched by a coverage table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SequenceContextFormat3 {
+    coverages: Vec<Coverage>,
+    seq_lookup_records: Vec<SequenceLookupRecord>
+}
+
+impl SequenceContextFormat3 {
+    /// Coverage tables, in glyph sequence order.
+    pub fn coverages(&self) -> &[Coverage] {
+        &self.coverages
+    }
+
+    /// Array of SequenceLookupRecords.
+    pub fn seq_lookup_records(&self) -> &[SequenceLookupRecord] {
+        &self.seq_lookup_records
+    }
+}
+
+/// An input sequence and the lookups applied when it is matched.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SequenceRule {
+    input_sequence: Vec<u16>,
+    seq_lookup_records: Vec<SequenceLookupRecord>
+}
+
+impl SequenceRule {
+    /// Array of input glyph IDs (or classes), starting with the second glyph.
+    pub fn input_sequence(&self) -> &[u16] {
+        &self.input_sequence
+    }
+
+    /// Array of SequenceLookupRecords.
+    pub fn seq_lookup_records(&self) -> &[SequenceLookupRecord] {
+        &self.seq_lookup_records
+    }
+}
+
+/// Chained Sequence Context
+///
+/// Chained contextual lookups match an input sequence of glyphs along with a backtrack sequence
+/// preceding it and a lookahead sequence following it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ChainedSequenceContext {
+    /// Simple glyph contexts.
+    Format1(ChainedSequenceContextFormat1),
+    /// Class-based glyph contexts.
+    Format2(ChainedSequenceContextFormat2),
+    /// Coverage-based glyph contexts.
+    Format3(ChainedSequenceContextFormat3)
+}
+
+/// Chained sequence rules matching glyph IDs, grouped by the coverage index of their first input
+/// glyph.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChainedSequenceContextFormat1 {
+    coverage: Coverage,
+    chained_seq_rule_sets: Vec<Vec<ChainedSequenceRule>>
+}
+
+impl ChainedSequenceContextFormat1 {
+    /// Coverage of the first glyph of the input sequences.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Array of ChainedSequenceRuleSets, ordered by coverage index. Null rule sets are empty.
+    pub fn chained_seq_rule_sets(&self) -> &[Vec<ChainedSequenceRule>] {
+        &self.chained_seq_rule_sets
+    }
+}
+
+/// Chained sequence rules matching glyph classes, grouped by the class of their first input
+/// glyph.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChainedSequenceContextFormat2 {
+    coverage: Coverage,
+    backtrack_class_def: ClassDef,
+    input_class_def: ClassDef,
+    lookahead_class_def: ClassDef,
+    chained_class_seq_rule_sets: Vec<Vec<ChainedSequenceRule>>
+}
+
+impl ChainedSequenceContextFormat2 {
+    /// Coverage of the first glyph of the input sequences.
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+
+    /// Classes of the glyphs of the backtrack sequences.
+    pub fn backtrack_class_def(&self) -> &ClassDef {
+        &self.backtrack_class_def
+    }
+
+    /// Classes of the glyphs of the input sequences.
+    pub fn input_class_def(&self) -> &ClassDef {
+        &self.input_class_def
+    }
+
+    /// Classes of the glyphs of the lookahead sequences.
+    pub fn lookahead_class_def(&self) -> &ClassDef {
+        &self.lookahead_class_def
+    }
+
+    /// Array of ChainedClassSequenceRuleSets, ordered by input class. Null rule sets are empty.
+    pub fn chained_class_seq_rule_sets(&self) -> &[Vec<ChainedSequenceRule>] {
+        &self.chained_class_seq_rule_sets
+    }
+}
+
+/// A single chained sequence, each position being matched by a coverage table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChainedSequenceContextFormat3 {
+    backtrack_coverages: Vec<Coverage>,
+    input_coverages: Vec<Coverage>,
+    lookahead_coverages: Vec<Coverage>,
+    seq_lookup_records: Vec<SequenceLookupRecord>
+}
+
+impl ChainedSequenceContextFormat3 {
+    /// Coverage tables of the backtrack sequence, in reverse glyph sequence order.
+    pub fn backtrack_coverages(&self) -> &[Coverage] {
+        &self.backtrack_coverages
+    }
+
+    /// Coverage tables of the input sequence, in glyph sequence order.
+    pub fn input_coverages(&self) -> &[Coverage] {
+        &self.input_coverages
+    }
+
+    /// Coverage tables of the lookahead sequence, in glyph sequence order.
+    pub fn lookahead_coverages(&self) -> &[Coverage] {
+        &self.lookahead_coverages
+    }
+
+    /// Array of SequenceLookupRecords.
+    pub fn seq_lookup_records(&self) -> &[SequenceLookupRecord] {
+        &self.seq_lookup_records
+    }
+}
+
+/// A chained sequence and the lookups applied when it is matched.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChainedSequenceRule {
+    backtrack_sequence: Vec<u16>,
+    input_sequence: Vec<u16>,
+    lookahead_sequence: Vec<u16>,
+    seq_lookup_records: Vec<SequenceLookupRecord>
+}
+
+impl ChainedSequenceRule {
+    /// Array of backtrack glyph IDs (or classes), in reverse glyph sequence order.
+    pub fn backtrack_sequence(&self) -> &[u16] {
+        &self.backtrack_sequence
+    }
+
+    /// Array of input glyph IDs (or classes), starting with the second glyph.
+    pub fn input_sequence(&self) -> &[u16] {
+        &self.input_sequence
+    }
+
+    /// Array of lookahead glyph IDs (or classes).
+    pub fn lookahead_sequence(&self) -> &[u16] {
+        &self.lookahead_sequence
+    }
+
+    /// Array of SequenceLookupRecords.
+    pub fn seq_lookup_records(&self) -> &[SequenceLookupRecord] {
+        &self.seq_lookup_records
+    }
+}
+
+/// Slice of the input starting at the given offset.
+pub(crate) fn offset_slice(input: &[u8], offset: usize) -> Result<&[u8], NomErr<(&[u8], ErrorKind)>> {
+    input.get(offset..).ok_or_else(|| NomErr::Error(error_position!(input, ErrorKind::Eof)))
+}
+
+/// Parse the tables at the given offsets, the offsets being relative to the beginning of the
+/// input.
+pub(crate) fn parse_offsets<'a, T, F>(input: &'a [u8], offsets: &[u16], parser: F) -> Result<Vec<T>, NomErr<(&'a [u8], ErrorKind)>>
+    where F: Fn(&'a [u8]) -> IResult<&'a [u8], T>
+{
+    offsets.iter()
+        .map(|&offset| parser(offset_slice(input, usize::from(offset))?).map(|(_, value)| value))
+        .collect()
+}
+
+/// Parse the table at the given offset, relative to the beginning of the input, a null offset
+/// giving None.
+pub(crate) fn parse_nullable<'a, T, F>(input: &'a [u8], offset: usize, parser: F) -> Result<Option<T>, NomErr<(&'a [u8], ErrorKind)>>
+    where F: Fn(&'a [u8]) -> IResult<&'a [u8], T>
+{
+    match offset {
+        0 => Ok(None),
+        _ => Ok(Some(parser(offset_slice(input, offset)?)?.1))
+    }
+}
+
+/// Parse the format of a table, failing if it is not the expected one.
+pub(crate) fn verify_format(input: &[u8], expected_format: u16) -> IResult<&[u8], u16>
+{
+    let (remainder, format) = be_u16(input)?;
+
+    if format != expected_format {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    Ok((remainder, format))
+}
+
+fn parse_tag(input: &[u8]) -> IResult<&[u8], Tag>
+{
+    let (input, tag) = take(4usize)(input)?;
+    Ok((input, Tag::new(tag)))
+}
+
+/// Parse an array of 16-bit values preceded by its count.
+pub(crate) fn parse_array16(input: &[u8]) -> IResult<&[u8], Vec<u16>>
+{
+    let (input, value_count) = be_u16(input)?;
+    count(be_u16, usize::from(value_count))(input)
+}
+
+pub fn parse_script_list(input: &[u8]) -> IResult<&[u8], ScriptList>
+{
+    let (remainder, script_count) = be_u16(input)?;
+    let (remainder, script_records) = count(|record: &[u8]| {
+        let (record, script_tag) = parse_tag(record)?;
+        let (record, script_offset) = be_u16(record)?;
+        let (_, script) = parse_script(offset_slice(input, usize::from(script_offset))?)?;
+
+        Ok((record, ScriptRecord {
+            script_tag,
+            script
+        }))
+    }, usize::from(script_count))(remainder)?;
+
+    Ok((remainder, ScriptList {
+        script_records
+    }))
+}
+
+fn parse_script(input: &[u8]) -> IResult<&[u8], Script>
+{
+    let (remainder, default_lang_sys_offset) = be_u16(input)?;
+    let (remainder, lang_sys_count) = be_u16(remainder)?;
+    let (remainder, lang_sys_records) = count(|record: &[u8]| {
+        let (record, lang_sys_tag) = parse_tag(record)?;
+        let (record, lang_sys_offset) = be_u16(record)?;
+        let (_, lang_sys) = parse_lang_sys(offset_slice(input, usize::from(lang_sys_offset))?)?;
+
+        Ok((record, LangSysRecord {
+            lang_sys_tag,
+            lang_sys
+        }))
+    }, usize::from(lang_sys_count))(remainder)?;
+
+    let default_lang_sys = match default_lang_sys_offset {
+        0 => None,
+        _ => Some(parse_lang_sys(offset_slice(input, usize::from(default_lang_sys_offset))?)?.1)
+    };
+
+    Ok((remainder, Script {
+        default_lang_sys,
+        lang_sys_records
+    }))
+}
+
+fn parse_lang_sys(input: &[u8]) -> IResult<&[u8], LangSys>
+{
+    // lookupOrderOffset, reserved
+    let (input, _) = be_u16(input)?;
+    let (input, required_feature_index) = be_u16(input)?;
+    let (input, feature_indices) = parse_array16(input)?;
+
+    Ok((input, LangSys {
+        required_feature_index,
+        feature_indices
+    }))
+}
+
+pub fn parse_feature_list(input: &[u8]) -> IResult<&[u8], FeatureList>
+{
+    let (remainder, feature_count) = be_u16(input)?;
+    let (remainder, feature_records) = count(|record: &[u8]| {
+        let (record, feature_tag) = parse_tag(record)?;
+        let (record, feature_offset) = be_u16(record)?;
+        let (_, feature) = parse_feature(offset_slice(input, usize::from(feature_offset))?)?;
+
+        Ok((record, FeatureRecord {
+            feature_tag,
+            feature
+        }))
+    }, usize::from(feature_count))(remainder)?;
+
+    Ok((remainder, FeatureList {
+        feature_records
+    }))
+}
+
+fn parse_feature(input: &[u8]) -> IResult<&[u8], Feature>
+{
+    let (input, feature_params_offset) = be_u16(input)?;
+    let (input, lookup_list_indices) = parse_array16(input)?;
+
+    Ok((input, Feature {
+        feature_params_offset,
+        lookup_list_indices
+    }))
+}
+
+/// Parse a LookupList, the subtables being parsed by the given parser from their slice and the
+/// lookup type.
+pub fn parse_lookup_list<'a, T, F>(input: &'a [u8], parse_subtable: F) -> IResult<&'a [u8], LookupList<T>>
+    where F: Fn(&'a [u8], u16) -> IResult<&'a [u8], T>
+{
+    let (remainder, lookup_offsets) = parse_array16(input)?;
+    let lookups = parse_offsets(input, &lookup_offsets, |lookup| parse_lookup(lookup, &parse_subtable))?;
+
+    Ok((remainder, LookupList {
+        lookups
+    }))
+}
+
+fn parse_lookup<'a, T, F>(input: &'a [u8], parse_subtable: &F) -> IResult<&'a [u8], Lookup<T>>
+    where F: Fn(&'a [u8], u16) -> IResult<&'a [u8], T>
+{
+    let (remainder, lookup_type) = be_u16(input)?;
+    let (remainder, lookup_flag) = be_u16(remainder)?;
+    let lookup_flag = LookupFlags::from_bits_truncate(lookup_flag);
+    let (remainder, subtable_offsets) = parse_array16(remainder)?;
+
+    let (remainder, mark_filtering_set) = if lookup_flag.contains(LookupFlags::USE_MARK_FILTERING_SET) {
+        let (remainder, mark_filtering_set) = be_u16(remainder)?;
+        (remainder, Some(mark_filtering_set))
+    }
+    else {
+        (remainder, None)
+    };
+
+    let subtables = parse_offsets(input, &subtable_offsets, |subtable| parse_subtable(subtable, lookup_type))?;
+
+    Ok((remainder, Lookup {
+        lookup_type,
+        lookup_flag,
+        subtables,
+        mark_filtering_set
+    }))
+}
+
+pub fn parse_coverage(input: &[u8]) -> IResult<&[u8], Coverage>
+{
+    let (input, coverage_format) = be_u16(input)?;
+
+    match coverage_format {
+        1 => {
+            let (input, glyph_array) = parse_array16(input)?;
+            Ok((input, Coverage::Format1(glyph_array)))
+        },
+        2 => {
+            let (input, range_count) = be_u16(input)?;
+            let (input, range_records) = count(|input| {
+                let (input, start_glyph_id) = be_u16(input)?;
+                let (input, end_glyph_id) = be_u16(input)?;
+                let (input, start_coverage_index) = be_u16(input)?;
+
+                Ok((input, RangeRecord {
+                    start_glyph_id,
+                    end_glyph_id,
+                    start_coverage_index
+                }))
+            }, usize::from(range_count))(input)?;
+
+            Ok((input, Coverage::Format2(range_records)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+pub fn parse_class_def(input: &[u8]) -> IResult<&[u8], ClassDef>
+{
+    let (input, class_format) = be_u16(input)?;
+
+    match class_format {
+        1 => {
+            let (input, start_glyph_id) = be_u16(input)?;
+            let (input, class_value_array) = parse_array16(input)?;
+
+            Ok((input, ClassDef::Format1(ClassDefFormat1 {
+                start_glyph_id,
+                class_value_array
+            })))
+        },
+        2 => {
+            let (input, class_range_count) = be_u16(input)?;
+            let (input, class_range_records) = count(|input| {
+                let (input, start_glyph_id) = be_u16(input)?;
+                let (input, end_glyph_id) = be_u16(input)?;
+                let (input, class) = be_u16(input)?;
+
+                Ok((input, ClassRangeRecord {
+                    start_glyph_id,
+                    end_glyph_id,
+                    class
+                }))
+            }, usize::from(class_range_count))(input)?;
+
+            Ok((input, ClassDef::Format2(class_range_records)))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+pub fn parse_device(input: &[u8]) -> IResult<&[u8], Device>
+{
+    let (remainder, start_size) = be_u16(input)?;
+    let (remainder, end_size) = be_u16(remainder)?;
+    let (remainder, delta_format) = be_u16(remainder)?;
+
+    let bits_per_value = match delta_format {
+        1 => 2,
+        2 => 4,
+        3 => 8,
+        0x8000 => {
+            return Ok((remainder, Device::VariationIndex(VariationIndex {
+                delta_set_outer_index: start_size,
+                delta_set_inner_index: end_size
+            })));
+        },
+        _ => return Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    };
+
+    let value_count = if end_size >= start_size { usize::from(end_size - start_size) + 1 } else { 0 };
+    let (remainder, delta_words) = count(be_u16, (value_count * bits_per_value + 15) / 16)(remainder)?;
+
+    // Values are packed from the most significant bits of each word and sign-extended
+    let delta_values = (0..value_count)
+        .map(|index| {
+            let bit_offset = index * bits_per_value;
+            let word = delta_words[bit_offset / 16];
+            let value = (word << (bit_offset % 16)) as i16;
+            (value >> (16 - bits_per_value)) as i8
+        })
+        .collect();
+
+    Ok((remainder, Device::Device(DeviceTable {
+        start_size,
+        end_size,
+        delta_format,
+        delta_values
+    })))
+}
+
+fn parse_sequence_lookup_record(input: &[u8]) -> IResult<&[u8], SequenceLookupRecord>
+{
+    let (input, sequence_index) = be_u16(input)?;
+    let (input, lookup_list_index) = be_u16(input)?;
+
+    Ok((input, SequenceLookupRecord {
+        sequence_index,
+        lookup_list_index
+    }))
+}
+
+/// Parse a SequenceRuleSet or a ClassSequenceRuleSet, the rules having the same layout.
+fn parse_sequence_rule_set(input: &[u8]) -> IResult<&[u8], Vec<SequenceRule>>
+{
+    let (remainder, seq_rule_offsets) = parse_array16(input)?;
+    let seq_rules = parse_offsets(input, &seq_rule_offsets, parse_sequence_rule)?;
+
+    Ok((remainder, seq_rules))
+}
+
+fn parse_sequence_rule(input: &[u8]) -> IResult<&[u8], SequenceRule>
+{
+    let (input, glyph_count) = be_u16(input)?;
+    let (input, seq_lookup_count) = be_u16(input)?;
+    let (input, input_sequence) = count(be_u16, usize::from(glyph_count.saturating_sub(1)))(input)?;
+    let (input, seq_lookup_records) = count(parse_sequence_lookup_record, usize::from(seq_lookup_count))(input)?;
+
+    Ok((input, SequenceRule {
+        input_sequence,
+        seq_lookup_records
+    }))
+}
+
+pub fn parse_sequence_context(input: &[u8]) -> IResult<&[u8], SequenceContext>
+{
+    let (remainder, format) = be_u16(input)?;
+
+    match format {
+        1 => {
+            let (remainder, coverage_offset) = be_u16(remainder)?;
+            let (remainder, seq_rule_set_offsets) = parse_array16(remainder)?;
+
+            Ok((remainder, SequenceContext::Format1(SequenceContextFormat1 {
+                coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+                seq_rule_sets: seq_rule_set_offsets.iter()
+                    .map(|&offset| parse_nullable(input, usize::from(offset), parse_sequence_rule_set).map(Option::unwrap_or_default))
+                    .collect::<Result<_, _>>()?
+            })))
+        },
+        2 => {
+            let (remainder, coverage_offset) = be_u16(remainder)?;
+            let (remainder, class_def_offset) = be_u16(remainder)?;
+            let (remainder, class_seq_rule_set_offsets) = parse_array16(remainder)?;
+
+            Ok((remainder, SequenceContext::Format2(SequenceContextFormat2 {
+                coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+                class_def: parse_nullable(input, usize::from(class_def_offset), parse_class_def)?.unwrap_or_default(),
+                class_seq_rule_sets: class_seq_rule_set_offsets.iter()
+                    .map(|&offset| parse_nullable(input, usize::from(offset), parse_sequence_rule_set).map(Option::unwrap_or_default))
+                    .collect::<Result<_, _>>()?
+            })))
+        },
+        3 => {
+            let (remainder, glyph_count) = be_u16(remainder)?;
+            let (remainder, seq_lookup_count) = be_u16(remainder)?;
+            let (remainder, coverage_offsets) = count(be_u16, usize::from(glyph_count))(remainder)?;
+            let (remainder, seq_lookup_records) = count(parse_sequence_lookup_record, usize::from(seq_lookup_count))(remainder)?;
+
+            Ok((remainder, SequenceContext::Format3(SequenceContextFormat3 {
+                coverages: parse_offsets(input, &coverage_offsets, parse_coverage)?,
+                seq_lookup_records
+            })))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+/// Parse a ChainedSequenceRuleSet or a ChainedClassSequenceRuleSet, the rules having the same
+/// layout.
+fn parse_chained_sequence_rule_set(input: &[u8]) -> IResult<&[u8], Vec<ChainedSequenceRule>>
+{
+    let (remainder, chained_seq_rule_offsets) = parse_array16(input)?;
+    let chained_seq_rules = parse_offsets(input, &chained_seq_rule_offsets, parse_chained_sequence_rule)?;
+
+    Ok((remainder, chained_seq_rules))
+}
+
+fn parse_chained_sequence_rule(input: &[u8]) -> IResult<&[u8], ChainedSequenceRule>
+{
+    let (input, backtrack_sequence) = parse_array16(input)?;
+    let (input, input_glyph_count) = be_u16(input)?;
+    let (input, input_sequence) = count(be_u16, usize::from(input_glyph_count.saturating_sub(1)))(input)?;
+    let (input, lookahead_sequence) = parse_array16(input)?;
+    let (input, seq_lookup_count) = be_u16(input)?;
+    let (input, seq_lookup_records) = count(parse_sequence_lookup_record, usize::from(seq_lookup_count))(input)?;
+
+    Ok((input, ChainedSequenceRule {
+        backtrack_sequence,
+        input_sequence,
+        lookahead_sequence,
+        seq_lookup_records
+    }))
+}
+
+pub fn parse_chained_sequence_context(input: &[u8]) -> IResult<&[u8], ChainedSequenceContext>
+{
+    let (remainder, format) = be_u16(input)?;
+
+    match format {
+        1 => {
+            let (remainder, coverage_offset) = be_u16(remainder)?;
+            let (remainder, chained_seq_rule_set_offsets) = parse_array16(remainder)?;
+
+            Ok((remainder, ChainedSequenceContext::Format1(ChainedSequenceContextFormat1 {
+                coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+                chained_seq_rule_sets: chained_seq_rule_set_offsets.iter()
+                    .map(|&offset| parse_nullable(input, usize::from(offset), parse_chained_sequence_rule_set).map(Option::unwrap_or_default))
+                    .collect::<Result<_, _>>()?
+            })))
+        },
+        2 => {
+            let (remainder, coverage_offset) = be_u16(remainder)?;
+            let (remainder, backtrack_class_def_offset) = be_u16(remainder)?;
+            let (remainder, input_class_def_offset) = be_u16(remainder)?;
+            let (remainder, lookahead_class_def_offset) = be_u16(remainder)?;
+            let (remainder, chained_class_seq_rule_set_offsets) = parse_array16(remainder)?;
+
+            Ok((remainder, ChainedSequenceContext::Format2(ChainedSequenceContextFormat2 {
+                coverage: parse_coverage(offset_slice(input, usize::from(coverage_offset))?)?.1,
+                backtrack_class_def: parse_nullable(input, usize::from(backtrack_class_def_offset), parse_class_def)?.unwrap_or_default(),
+                input_class_def: parse_nullable(input, usize::from(input_class_def_offset), parse_class_def)?.unwrap_or_default(),
+                lookahead_class_def: parse_nullable(input, usize::from(lookahead_class_def_offset), parse_class_def)?.unwrap_or_default(),
+                chained_class_seq_rule_sets: chained_class_seq_rule_set_offsets.iter()
+                    .map(|&offset| parse_nullable(input, usize::from(offset), parse_chained_sequence_rule_set).map(Option::unwrap_or_default))
+                    .collect::<Result<_, _>>()?
+            })))
+        },
+        3 => {
+            let (remainder, backtrack_coverage_offsets) = parse_array16(remainder)?;
+            let (remainder, input_coverage_offsets) = parse_array16(remainder)?;
+            let (remainder, lookahead_coverage_offsets) = parse_array16(remainder)?;
+            let (remainder, seq_lookup_count) = be_u16(remainder)?;
+            let (remainder, seq_lookup_records) = count(parse_sequence_lookup_record, usize::from(seq_lookup_count))(remainder)?;
+
+            Ok((remainder, ChainedSequenceContext::Format3(ChainedSequenceContextFormat3 {
+                backtrack_coverages: parse_offsets(input, &backtrack_coverage_offsets, parse_coverage)?,
+                input_coverages: parse_offsets(input, &input_coverage_offsets, parse_coverage)?,
+                lookahead_coverages: parse_offsets(input, &lookahead_coverage_offsets, parse_coverage)?,
+                seq_lookup_records
+            })))
+        },
+        _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_coverage() {
+        let format_1: &[u8]  = &[0x00, 0x01, 0x00, 0x03, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x2A];
+
+        let coverage = parse_coverage(format_1).unwrap().1;
+        assert_eq!(coverage.coverage_index(5), Some(0));
+        assert_eq!(coverage.coverage_index(42), Some(2));
+        assert_eq!(coverage.coverage_index(6), None);
+        assert_eq!(coverage.glyphs(), vec![5, 10, 42]);
+
+        let format_2: &[u8]  = &[
+            0x00, 0x02, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x20, 0x00, 0x20,
+            0x00, 0x03];
+
+        let coverage = parse_coverage(format_2).unwrap().1;
+        assert_eq!(coverage.coverage_index(10), Some(0));
+        assert_eq!(coverage.coverage_index(12), Some(2));
+        assert_eq!(coverage.coverage_index(32), Some(3));
+        assert_eq!(coverage.coverage_index(13), None);
+        assert_eq!(coverage.glyphs(), vec![10, 11, 12, 32]);
+
+        assert!(parse_coverage(&[0x00, 0x03, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn case_class_def() {
+        let format_1: &[u8]  = &[0x00, 0x01, 0x00, 0x0A, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+
+        let class_def = parse_class_def(format_1).unwrap().1;
+        assert_eq!(class_def.class(9), 0);
+        assert_eq!(class_def.class(10), 1);
+        assert_eq!(class_def.class(12), 2);
+        assert_eq!(class_def.class(13), 0);
+
+        let format_2: &[u8]  = &[
+            0x00, 0x02, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x0C, 0x00, 0x01, 0x00, 0x20, 0x00, 0x20,
+            0x00, 0x03];
+
+        let class_def = parse_class_def(format_2).unwrap().1;
+        assert_eq!(class_def.class(11), 1);
+        assert_eq!(class_def.class(32), 3);
+        assert_eq!(class_def.class(20), 0);
+    }
+
+    #[test]
+    fn case_device() {
+        let format_1: &[u8]  = &[0x00, 0x0B, 0x00, 0x0F, 0x00, 0x01, 0x55, 0xC0];
+
+        match parse_device(format_1).unwrap().1 {
+            Device::Device(device) => {
+                assert_eq!(device.delta_values(), &[1, 1, 1, 1, -1]);
+                assert_eq!(device.delta(12), 1);
+                assert_eq!(device.delta(10), 0);
+                assert_eq!(device.delta(16), 0);
+            },
+            _ => assert!(false)
+        }
+
+        let format_2: &[u8]  = &[0x00, 0x0A, 0x00, 0x0D, 0x00, 0x02, 0xF1, 0x23];
+
+        match parse_device(format_2).unwrap().1 {
+            Device::Device(device) => assert_eq!(device.delta_values(), &[-1, 1, 2, 3]),
+            _ => assert!(false)
+        }
+
+        let format_3: &[u8]  = &[0x00, 0x09, 0x00, 0x0B, 0x00, 0x03, 0x80, 0x7F, 0xFE, 0x00];
+
+        match parse_device(format_3).unwrap().1 {
+            Device::Device(device) => assert_eq!(device.delta_values(), &[-128, 127, -2]),
+            _ => assert!(false)
+        }
+
+        let variation_index: &[u8]  = &[0x00, 0x01, 0x00, 0x02, 0x80, 0x00];
+
+        match parse_device(variation_index).unwrap().1 {
+            Device::VariationIndex(variation_index) => {
+                assert_eq!(variation_index.delta_set_outer_index(), 1);
+                assert_eq!(variation_index.delta_set_inner_index(), 2);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_chained_sequence_context_format_3() {
+        let bytes: &[u8]  = &[
+            0x00, 0x03, 0x00, 0x01, 0x00, 0x16, 0x00, 0x01, 0x00, 0x16, 0x00, 0x01, 0x00, 0x1C,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x05,
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x07];
+
+        match parse_chained_sequence_context(bytes).unwrap().1 {
+            ChainedSequenceContext::Format3(context) => {
+                assert_eq!(context.backtrack_coverages(), &[Coverage::Format1(vec![5])]);
+                assert_eq!(context.input_coverages(), &[Coverage::Format1(vec![5])]);
+                assert_eq!(context.lookahead_coverages(), &[Coverage::Format1(vec![7])]);
+                assert_eq!(context.seq_lookup_records(), &[SequenceLookupRecord {
+                    sequence_index: 0,
+                    lookup_list_index: 4
+                }]);
+            },
+            _ => assert!(false)
+        }
+    }
+
+    #[test]
+    fn case_lang_sys_lookup_indices() {
+        let script_list: &[u8]  = &[
+            0x00, 0x01, 0x6C, 0x61, 0x74, 0x6E, 0x00, 0x08, 0x00, 0x0A, 0x00, 0x01, 0x54, 0x52,
+            0x4B, 0x20, 0x00, 0x12, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01];
+        let feature_list: &[u8]  = &[
+            0x00, 0x02, 0x6C, 0x69, 0x67, 0x61, 0x00, 0x0E, 0x6C, 0x69, 0x67, 0x61, 0x00, 0x14,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x03];
+
+        let script_list = parse_script_list(script_list).unwrap().1;
+        let feature_list = parse_feature_list(feature_list).unwrap().1;
+
+        let script = script_list.script(Tag::new(b"latn")).unwrap();
+        assert!(script_list.script(Tag::new(b"cyrl")).is_none());
+
+        let default_lang_sys = script.lang_sys(None).unwrap();
+        assert_eq!(default_lang_sys.required_feature_index(), None);
+        assert_eq!(feature_list.lookup_indices(default_lang_sys, Tag::new(b"liga")), vec![3]);
+
+        let turkish = script.lang_sys(Some(Tag::new(b"TRK "))).unwrap();
+        assert_eq!(turkish.required_feature_index(), Some(1));
+        assert_eq!(feature_list.lookup_indices(turkish, Tag::new(b"liga")), vec![1, 3]);
+        assert_eq!(feature_list.lookup_indices(turkish, Tag::new(b"kern")), Vec::<u16>::new());
+
+        // Missing language systems fall back to the default one
+        assert_eq!(script.lang_sys(Some(Tag::new(b"DEU "))), Some(default_lang_sys));
+    }
+}
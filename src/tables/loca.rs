@@ -4,6 +4,7 @@ use nom::IResult;
 use nom::error::ErrorKind;
 use nom::number::complete::{be_u16, be_u32};
 use nom::multi::count;
+use std::ops::Range;
 use types::{Offset16, Offset32};
 
 /// Index to Location
@@ -32,15 +33,45 @@ pub enum IndexToLocationTable {
 }
 
 impl<'otf> IndexToLocationTable {
+    /// Number of offsets in the table, that is the number of glyphs plus one.
+    pub fn len(&self) -> usize {
+        match self {
+            IndexToLocationTable::Short(offsets) => offsets.len(),
+            IndexToLocationTable::Long(offsets) => offsets.len()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Offset of the glyph data block relative to the beginning of the 'glyf' table.
+    ///
+    /// The short version stores the actual local offset divided by 2.
     pub fn get_glyf_offset(&self, glyph_index: u32) -> Option<u32> {
         match self {
             IndexToLocationTable::Short(offsets) => offsets.get(glyph_index as usize).map(
-                |offset| *offset as u32),
+                |offset| u32::from(*offset) * 2),
             IndexToLocationTable::Long(offsets) => offsets.get(glyph_index as usize).map(
                 |offset| *offset)
         }
     }
 
+    /// Range of the glyph data block relative to the beginning of the 'glyf' table.
+    ///
+    /// Return None if the glyph index is out of bounds or if the offsets are not in ascending
+    /// order.
+    pub fn get_glyf_range(&self, glyph_index: u32) -> Option<Range<u32>> {
+        let start = self.get_glyf_offset(glyph_index)?;
+        let end = self.get_glyf_offset(glyph_index.checked_add(1)?)?;
+
+        if start > end {
+            return None;
+        }
+
+        Some(start..end)
+    }
+
     /// Parse Index to Location Table.
     ///
     /// * `index_to_loc_format` - The index to location table format is determined by the
@@ -55,7 +86,15 @@ impl<'otf> IndexToLocationTable {
     ///
     /// use otf::tables::loca::IndexToLocationTable;
     ///
-    /// // TODO
+    /// let bytes: &[u8]  = &[0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x1A];
+    ///
+    /// let loca = IndexToLocationTable::parse(bytes, 0, 3).unwrap();
+    ///
+    /// assert_eq!(loca.len(), 4);
+    /// assert_eq!(loca.get_glyf_range(0), Some(0..0));
+    /// assert_eq!(loca.get_glyf_range(1), Some(0..24));
+    /// assert_eq!(loca.get_glyf_range(2), Some(24..52));
+    /// assert_eq!(loca.get_glyf_range(3), None);
     /// ```
     pub fn parse(buf: &'otf[u8], index_to_loc_format: i16, num_glyphs: u16) -> Result<IndexToLocationTable, Error> {
         Ok(parse_index_to_location_table(buf, index_to_loc_format, num_glyphs)?.1)
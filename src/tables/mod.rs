@@ -1,14 +1,26 @@
 use std::{fmt, str};
 
+pub mod cff;
+pub mod cff2;
 pub mod cmap;
+pub mod gdef;
+pub mod glyf;
+pub mod gpos;
+pub mod gsub;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
+pub mod kern;
+pub mod layout;
 pub mod loca;
 pub mod maxp;
 pub mod name;
 pub mod os2;
 pub mod post;
+pub mod variation;
+pub mod vhea;
+pub mod vmtx;
+pub mod vorg;
 
 /// A glyph identifier.
 pub type GlyphId = u16;
@@ -114,7 +126,10 @@ pub enum TableTag {
     /// Vertical Origin Table
     Vorg,
     /// Vertical Metrics Variations Table
-    Vvar
+    Vvar,
+    /// Any other table, such as private or vendor specific tables (e.g. 'FFTM', 'TTFA' or
+    /// 'Silf'), identified by its raw tag.
+    Unknown(Tag)
 }
 
 impl TableTag {
@@ -189,6 +204,25 @@ impl TableTag {
     }
 }
 
+impl From<Tag> for TableTag {
+    /// Converts a Tag into a TableTag, tags that are not recognized by
+    /// [parse](enum.TableTag.html#method.parse) are kept as `TableTag::Unknown`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::{Tag, TableTag};
+    ///
+    /// assert_eq!(TableTag::from(Tag::new(b"head")), TableTag::Head);
+    /// assert_eq!(TableTag::from(Tag::new(b"FFTM")), TableTag::Unknown(Tag::new(b"FFTM")));
+    /// ```
+    fn from(tag: Tag) -> Self {
+        TableTag::parse(tag).unwrap_or(TableTag::Unknown(tag))
+    }
+}
+
 impl fmt::Display for TableTag {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", Tag::from(*self))
@@ -222,6 +256,7 @@ impl<'a> PartialEq<Tag> for &'a [u8] {
 impl From<TableTag> for Tag {
     fn from(table_tag: TableTag) -> Self {
         Tag::new(match table_tag {
+            TableTag::Unknown(tag) => return tag,
             TableTag::Avar => b"avar",
             TableTag::Base => b"BASE",
             TableTag::Cbdt => b"CBDT",
@@ -3,6 +3,7 @@ use nom::Err as NomErr;
 use nom::IResult;
 use nom::multi::count;
 use nom::number::complete::be_u16;
+use error::Error;
 
 /// Naming Table
 ///
@@ -64,6 +65,168 @@ impl NamingTable {
     pub fn lang_tag_records(&self) -> &Vec<LangTagRecord> {
         self.lang_tag_records.as_ref()
     }
+
+    /// Decode the string of the given name record, `buf` being the naming table.
+    ///
+    /// Strings of the Unicode and Windows platforms are decoded from UTF-16BE, strings of the
+    /// Macintosh platform from the single-byte Roman, Greek and Cyrillic scripts. An error is
+    /// returned for the encodings that cannot be decoded, such as the Macintosh double-byte
+    /// scripts, and for strings out of the string storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::parser::Parse;
+    /// use otf::tables::TableTag;
+    /// use otf::tables::name::{NamingTable, NameId};
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let name_buf = font.table(TableTag::Name).unwrap().as_slice().unwrap();
+    /// let naming_table = NamingTable::parse(name_buf).unwrap().1;
+    ///
+    /// let name_record = naming_table.name_records().iter()
+    ///     .find(|name_record| name_record.name_id() == NameId::PostScript)
+    ///     .unwrap();
+    /// assert_eq!(naming_table.get_string(name_record, name_buf).unwrap(), "Roboto-Regular");
+    /// ```
+    pub fn get_string(&self, name_record: &NameRecord, buf: &[u8]) -> Result<String, Error> {
+        let bytes = self.storage(buf, name_record.offset(), name_record.length())?;
+        decode_string(name_record.platform(), bytes)
+    }
+
+    /// Find the best string for the given name ID across platforms and languages, `buf` being
+    /// the naming table.
+    ///
+    /// The typographic family and subfamily names (name IDs 16 and 17) are preferred to the
+    /// font family and subfamily names (name IDs 1 and 2). For a given name ID, the strings are
+    /// picked in the following order:
+    /// - strings whose BCP 47 language tag matches the preferred language, then strings whose
+    ///   primary language subtag matches the preferred language;
+    /// - strings in US English, then in other variants of English;
+    /// - any other string.
+    ///
+    /// Windows strings are preferred to Unicode strings, and Unicode strings to Macintosh
+    /// strings. Strings that cannot be decoded are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::parser::Parse;
+    /// use otf::tables::TableTag;
+    /// use otf::tables::name::{NamingTable, NameId};
+    ///
+    /// let buf = include_bytes!("../../fonts/source-serif-pro/SourceSerifPro-Regular.otf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let name_buf = font.table(TableTag::Name).unwrap().as_slice().unwrap();
+    /// let naming_table = NamingTable::parse(name_buf).unwrap().1;
+    ///
+    /// assert_eq!(naming_table.find(NameId::FontFamilyName, None, name_buf).unwrap(), "Source Serif Pro");
+    /// assert_eq!(naming_table.find(NameId::FontSubfamilyName, Some("fr"), name_buf).unwrap(), "Regular");
+    /// assert_eq!(naming_table.find(NameId::PostScript, None, name_buf).unwrap(), "SourceSerifPro-Regular");
+    /// assert!(naming_table.find(NameId::SampleText, None, name_buf).is_none());
+    /// ```
+    pub fn find(&self, name_id: NameId, preferred_language: Option<&str>, buf: &[u8]) -> Option<String> {
+        let name_ids: &[NameId] = match name_id {
+            NameId::FontFamilyName => &[NameId::TypographicFamilyName, NameId::FontFamilyName],
+            NameId::FontSubfamilyName => &[NameId::TypographicSubfamilyName, NameId::FontSubfamilyName],
+            _ => &[name_id]
+        };
+
+        name_ids.iter().filter_map(|&name_id| {
+            let mut name_records: Vec<(u8, u8, &NameRecord)> = self.name_records.iter()
+                .filter(|name_record| name_record.name_id() == name_id)
+                .map(|name_record| (self.language_rank(name_record, preferred_language, buf),
+                                    platform_rank(name_record.platform()), name_record))
+                .collect();
+            // The sort is stable, the order of the name records is kept for equal ranks
+            name_records.sort_by_key(|&(language_rank, platform_rank, _)| (language_rank, platform_rank));
+
+            name_records.into_iter()
+                .filter_map(|(_, _, name_record)| self.get_string(name_record, buf).ok())
+                .next()
+        }).next()
+    }
+
+    /// Language of the given name record, `buf` being the naming table.
+    ///
+    /// Language IDs greater than or equal to 0x8000 are resolved to the language-tag strings of
+    /// format 1 naming tables. Return None if the language ID is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::OpenTypeFontFile;
+    /// use otf::parser::Parse;
+    /// use otf::tables::TableTag;
+    /// use otf::tables::name::{NamingTable, Language, WindowsLanguage};
+    ///
+    /// let buf = include_bytes!("../../fonts/Roboto/Roboto-Regular.ttf") as &[u8];
+    /// let font = OpenTypeFontFile::parse(buf).unwrap().into_iter().next().unwrap();
+    /// let name_buf = font.table(TableTag::Name).unwrap().as_slice().unwrap();
+    /// let naming_table = NamingTable::parse(name_buf).unwrap().1;
+    ///
+    /// let language = naming_table.language(&naming_table.name_records()[25], name_buf).unwrap();
+    /// assert_eq!(language, Language::Windows(WindowsLanguage::EnglishUnitedStates));
+    /// assert_eq!(language.bcp47(), "en-US");
+    /// ```
+    pub fn language(&self, name_record: &NameRecord, buf: &[u8]) -> Option<Language> {
+        match name_record.platform() {
+            Platform::Unicode(..) | Platform::Windows(..) if name_record.language_id() >= 0x8000 => {
+                let lang_tag_record = self.lang_tag_records.get(usize::from(name_record.language_id() - 0x8000))?;
+                let bytes = self.storage(buf, lang_tag_record.offset(), lang_tag_record.length()).ok()?;
+                decode_utf16_be(bytes).ok().map(Language::Tag)
+            },
+            Platform::Windows(_, language) => language.map(Language::Windows),
+            Platform::Macintosh(_, language) => language.map(Language::Macintosh),
+            _ => None
+        }
+    }
+
+    /// Rank of the language of a name record with regard to the preferred language, the lowest
+    /// rank being the best match.
+    fn language_rank(&self, name_record: &NameRecord, preferred_language: Option<&str>, buf: &[u8]) -> u8 {
+        let language = match self.language(name_record, buf) {
+            Some(language) => language,
+            None => return 4
+        };
+        let language_tag = language.bcp47();
+
+        if let Some(preferred_language) = preferred_language {
+            if language_tag.eq_ignore_ascii_case(preferred_language) {
+                return 0;
+            }
+
+            if primary_language_subtag(language_tag).eq_ignore_ascii_case(primary_language_subtag(preferred_language)) {
+                return 1;
+            }
+        }
+
+        if language_tag.eq_ignore_ascii_case("en-US") {
+            2
+        }
+        else if primary_language_subtag(language_tag).eq_ignore_ascii_case("en") {
+            3
+        }
+        else {
+            4
+        }
+    }
+
+    /// Bytes of the string storage at the given offset.
+    fn storage<'otf>(&self, buf: &'otf[u8], offset: u16, length: u16) -> Result<&'otf[u8], Error> {
+        let start = usize::from(self.string_offset) + usize::from(offset);
+        buf.get(start..start + usize::from(length))
+            .ok_or_else(|| Error::new(format!("String out of the storage area at offset {}", offset)))
+    }
 }
 
 impl_parse!(
@@ -720,6 +883,238 @@ impl WindowsLanguage {
             _ => None
         }
     }
+
+    /// BCP 47 language tag of the language.
+    pub fn bcp47(&self) -> &'static str {
+        match self {
+            WindowsLanguage::Afrikaans => "af-ZA",
+            WindowsLanguage::Albanian => "sq-AL",
+            WindowsLanguage::Alsatian => "gsw-FR",
+            WindowsLanguage::Amharic => "am-ET",
+            WindowsLanguage::ArabicAlgeria => "ar-DZ",
+            WindowsLanguage::ArabicBahrain => "ar-BH",
+            WindowsLanguage::ArabicEgypt => "ar-EG",
+            WindowsLanguage::ArabicIraq => "ar-IQ",
+            WindowsLanguage::ArabicJordan => "ar-JO",
+            WindowsLanguage::ArabicKuwait => "ar-KW",
+            WindowsLanguage::ArabicLebanon => "ar-LB",
+            WindowsLanguage::ArabicLibya => "ar-LY",
+            WindowsLanguage::ArabicMorocco => "ar-MA",
+            WindowsLanguage::ArabicOman => "ar-OM",
+            WindowsLanguage::ArabicQatar => "ar-QA",
+            WindowsLanguage::ArabicSaudi => "ar-SA",
+            WindowsLanguage::ArabicSyria => "ar-SY",
+            WindowsLanguage::ArabicTunisia => "ar-TN",
+            WindowsLanguage::ArabicUAE => "ar-AE",
+            WindowsLanguage::ArabicYemen => "ar-YE",
+            WindowsLanguage::Armenian => "hy-AM",
+            WindowsLanguage::Assamese => "as-IN",
+            WindowsLanguage::AzeriCyrillic => "az-Cyrl-AZ",
+            WindowsLanguage::AzeriLatin => "az-Latn-AZ",
+            WindowsLanguage::Bashkir => "ba-RU",
+            WindowsLanguage::Basque => "eu-ES",
+            WindowsLanguage::Belarusian => "be-BY",
+            WindowsLanguage::BengaliBangladesh => "bn-BD",
+            WindowsLanguage::BengaliIndia => "bn-IN",
+            WindowsLanguage::BosnianCyrillic => "bs-Cyrl-BA",
+            WindowsLanguage::BosnianLatin => "bs-Latn-BA",
+            WindowsLanguage::Breton => "br-FR",
+            WindowsLanguage::Bulgarian => "bg-BG",
+            WindowsLanguage::Catalan => "ca-ES",
+            WindowsLanguage::ChineseHongKongSAR => "zh-HK",
+            WindowsLanguage::ChineseMacaoSAR => "zh-MO",
+            WindowsLanguage::ChineseRepublicOfChina => "zh-CN",
+            WindowsLanguage::ChineseSingapore => "zh-SG",
+            WindowsLanguage::ChineseTaiwan => "zh-TW",
+            WindowsLanguage::Corsican => "co-FR",
+            WindowsLanguage::Croatian => "hr-HR",
+            WindowsLanguage::CroatianLatin => "hr-BA",
+            WindowsLanguage::Czech => "cs-CZ",
+            WindowsLanguage::Danish => "da-DK",
+            WindowsLanguage::Dari => "prs-AF",
+            WindowsLanguage::Divehi => "dv-MV",
+            WindowsLanguage::DutchBelgium => "nl-BE",
+            WindowsLanguage::DutchNetherlands => "nl-NL",
+            WindowsLanguage::EnglishAustralia => "en-AU",
+            WindowsLanguage::EnglishBelize => "en-BZ",
+            WindowsLanguage::EnglishCanada => "en-CA",
+            WindowsLanguage::EnglishCaribbean => "en-029",
+            WindowsLanguage::EnglishIndia => "en-IN",
+            WindowsLanguage::EnglishIreland => "en-IE",
+            WindowsLanguage::EnglishJamaica => "en-JM",
+            WindowsLanguage::EnglishMalaysia => "en-MY",
+            WindowsLanguage::EnglishNewZealand => "en-NZ",
+            WindowsLanguage::EnglishPhilippines => "en-PH",
+            WindowsLanguage::EnglishSingapore => "en-SG",
+            WindowsLanguage::EnglishSouthAfrica => "en-ZA",
+            WindowsLanguage::EnglishTrinidadAndTobago => "en-TT",
+            WindowsLanguage::EnglishUnitedKingdom => "en-GB",
+            WindowsLanguage::EnglishUnitedStates => "en-US",
+            WindowsLanguage::EnglishZimbabwe => "en-ZW",
+            WindowsLanguage::Estonian => "et-EE",
+            WindowsLanguage::Faroese => "fo-FO",
+            WindowsLanguage::Filipino => "fil-PH",
+            WindowsLanguage::Finnish => "fi-FI",
+            WindowsLanguage::FrenchBelgium => "fr-BE",
+            WindowsLanguage::FrenchCanada => "fr-CA",
+            WindowsLanguage::FrenchFrance => "fr-FR",
+            WindowsLanguage::FrenchLuxembourg => "fr-LU",
+            WindowsLanguage::FrenchMonaco => "fr-MC",
+            WindowsLanguage::FrenchSwitzerland => "fr-CH",
+            WindowsLanguage::Frisian => "fy-NL",
+            WindowsLanguage::Galician => "gl-ES",
+            WindowsLanguage::Georgian => "ka-GE",
+            WindowsLanguage::GermanAustria => "de-AT",
+            WindowsLanguage::GermanGermany => "de-DE",
+            WindowsLanguage::GermanLiechtenstein => "de-LI",
+            WindowsLanguage::GermanLuxembourg => "de-LU",
+            WindowsLanguage::GermanSwitzerland => "de-CH",
+            WindowsLanguage::Greek => "el-GR",
+            WindowsLanguage::Greenlandic => "kl-GL",
+            WindowsLanguage::Gujarati => "gu-IN",
+            WindowsLanguage::Hausa => "ha-Latn-NG",
+            WindowsLanguage::Hebrew => "he-IL",
+            WindowsLanguage::Hindi => "hi-IN",
+            WindowsLanguage::Hungarian => "hu-HU",
+            WindowsLanguage::Icelandic => "is-IS",
+            WindowsLanguage::Igbo => "ig-NG",
+            WindowsLanguage::Indonesian => "id-ID",
+            WindowsLanguage::Inuktitut => "iu-Cans-CA",
+            WindowsLanguage::InuktitutLatin => "iu-Latn-CA",
+            WindowsLanguage::Irish => "ga-IE",
+            WindowsLanguage::IsiXhosa => "xh-ZA",
+            WindowsLanguage::IsiZulu => "zu-ZA",
+            WindowsLanguage::ItalianItaly => "it-IT",
+            WindowsLanguage::ItalianSwitzerland => "it-CH",
+            WindowsLanguage::Japanese => "ja-JP",
+            WindowsLanguage::Kannada => "kn-IN",
+            WindowsLanguage::Kazakh => "kk-KZ",
+            WindowsLanguage::Khmer => "km-KH",
+            WindowsLanguage::Kiche => "quc-Latn-GT",
+            WindowsLanguage::Kinyarwanda => "rw-RW",
+            WindowsLanguage::Kiswahili => "sw-KE",
+            WindowsLanguage::Konkani => "kok-IN",
+            WindowsLanguage::Korean => "ko-KR",
+            WindowsLanguage::Kyrgyz => "ky-KG",
+            WindowsLanguage::Lao => "lo-LA",
+            WindowsLanguage::Latvian => "lv-LV",
+            WindowsLanguage::Lithuanian => "lt-LT",
+            WindowsLanguage::LowerSorbian => "dsb-DE",
+            WindowsLanguage::Luxembourgish => "lb-LU",
+            WindowsLanguage::Macedonian => "mk-MK",
+            WindowsLanguage::MalayBrunei => "ms-BN",
+            WindowsLanguage::MalayMalaysia => "ms-MY",
+            WindowsLanguage::Malayalam => "ml-IN",
+            WindowsLanguage::Maltese => "mt-MT",
+            WindowsLanguage::Maori => "mi-NZ",
+            WindowsLanguage::Mapudungun => "arn-CL",
+            WindowsLanguage::Marathi => "mr-IN",
+            WindowsLanguage::Mohawk => "moh-CA",
+            WindowsLanguage::MongolianCyrillic => "mn-MN",
+            WindowsLanguage::MongolianTraditional => "mn-Mong-CN",
+            WindowsLanguage::Nepali => "ne-NP",
+            WindowsLanguage::NorwegianBokmal => "nb-NO",
+            WindowsLanguage::NorwegianNynorsk => "nn-NO",
+            WindowsLanguage::Occitan => "oc-FR",
+            WindowsLanguage::Odia => "or-IN",
+            WindowsLanguage::Pashto => "ps-AF",
+            WindowsLanguage::Polish => "pl-PL",
+            WindowsLanguage::PortugueseBrazil => "pt-BR",
+            WindowsLanguage::PortuguesePortugal => "pt-PT",
+            WindowsLanguage::Punjabi => "pa-IN",
+            WindowsLanguage::QuechuaBolivia => "quz-BO",
+            WindowsLanguage::QuechuaEcuador => "quz-EC",
+            WindowsLanguage::QuechuaPeru => "quz-PE",
+            WindowsLanguage::Romanian => "ro-RO",
+            WindowsLanguage::Romansh => "rm-CH",
+            WindowsLanguage::Russian => "ru-RU",
+            WindowsLanguage::SamiInariFinland => "smn-FI",
+            WindowsLanguage::SamiLuleNorway => "smj-NO",
+            WindowsLanguage::SamiLuleSweden => "smj-SE",
+            WindowsLanguage::SamiNorthernFinland => "se-FI",
+            WindowsLanguage::SamiNorthernNorway => "se-NO",
+            WindowsLanguage::SamiNorthernSweden => "se-SE",
+            WindowsLanguage::SamiSkoltFinland => "sms-FI",
+            WindowsLanguage::SamiSouthernNorway => "sma-NO",
+            WindowsLanguage::SamiSouthernSweden => "sma-SE",
+            WindowsLanguage::Sanskrit => "sa-IN",
+            WindowsLanguage::SerbianCyrillicBosniaAndHerzegovina => "sr-Cyrl-BA",
+            WindowsLanguage::SerbianCyrillicSerbia => "sr-Cyrl-RS",
+            WindowsLanguage::SerbianLatinBosniAndHerzegovina => "sr-Latn-BA",
+            WindowsLanguage::SerbianLatinSerbia => "sr-Latn-RS",
+            WindowsLanguage::Sesotho => "nso-ZA",
+            WindowsLanguage::Setswana => "tn-ZA",
+            WindowsLanguage::Sinhala => "si-LK",
+            WindowsLanguage::Slovak => "sk-SK",
+            WindowsLanguage::Slovenian => "sl-SI",
+            WindowsLanguage::SpanishArgentina => "es-AR",
+            WindowsLanguage::SpanishBolivia => "es-BO",
+            WindowsLanguage::SpanishChile => "es-CL",
+            WindowsLanguage::SpanishColombia => "es-CO",
+            WindowsLanguage::SpanishCostaRica => "es-CR",
+            WindowsLanguage::SpanishDominicanRepublic => "es-DO",
+            WindowsLanguage::SpanishEcuador => "es-EC",
+            WindowsLanguage::SpanishElSalvador => "es-SV",
+            WindowsLanguage::SpanishGuatemala => "es-GT",
+            WindowsLanguage::SpanishHonduras => "es-HN",
+            WindowsLanguage::SpanishMexico => "es-MX",
+            WindowsLanguage::SpanishNicaragua => "es-NI",
+            WindowsLanguage::SpanishPanama => "es-PA",
+            WindowsLanguage::SpanishParaguay => "es-PY",
+            WindowsLanguage::SpanishPeru => "es-PE",
+            WindowsLanguage::SpanishPuertoRico => "es-PR",
+            WindowsLanguage::SpanishModernSpain => "es-ES",
+            WindowsLanguage::SpanishTraditionalSpain => "es-ES-tradnl",
+            WindowsLanguage::SpanishUnitedStates => "es-US",
+            WindowsLanguage::SpanishUruguay => "es-UY",
+            WindowsLanguage::SpanishVenezuela => "es-VE",
+            WindowsLanguage::SwedenFinland => "sv-FI",
+            WindowsLanguage::SwedishSweden => "sv-SE",
+            WindowsLanguage::Syriac => "syr-SY",
+            WindowsLanguage::Tajik => "tg-Cyrl-TJ",
+            WindowsLanguage::Tamazight => "tzm-Latn-DZ",
+            WindowsLanguage::Tamil => "ta-IN",
+            WindowsLanguage::Tatar => "tt-RU",
+            WindowsLanguage::Telugu => "te-IN",
+            WindowsLanguage::Thai => "th-TH",
+            WindowsLanguage::Tibetan => "bo-CN",
+            WindowsLanguage::Turkish => "tr-TR",
+            WindowsLanguage::Turkmen => "tk-TM",
+            WindowsLanguage::Uighur => "ug-CN",
+            WindowsLanguage::Ukrainian => "uk-UA",
+            WindowsLanguage::Upper => "hsb-DE",
+            WindowsLanguage::Urdu => "ur-PK",
+            WindowsLanguage::UzbekCyrillic => "uz-Cyrl-UZ",
+            WindowsLanguage::UzbekLatin => "uz-Latn-UZ",
+            WindowsLanguage::Vietnamese => "vi-VN",
+            WindowsLanguage::Welsh => "cy-GB",
+            WindowsLanguage::Wolof => "wo-SN",
+            WindowsLanguage::Yakut => "sah-RU",
+            WindowsLanguage::Yi => "ii-CN",
+            WindowsLanguage::Yoruba => "yo-NG",
+        }
+    }
+}
+
+/// Language of a name record, either a platform-specific language or a language-tag string of
+/// a format 1 naming table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Language {
+    Windows(WindowsLanguage),
+    Macintosh(MacintoshLanguage),
+    /// BCP 47 language tag, such as “zh-Hant-HK”.
+    Tag(String)
+}
+
+impl Language {
+    /// BCP 47 language tag of the language.
+    pub fn bcp47(&self) -> &str {
+        match self {
+            Language::Windows(language) => language.bcp47(),
+            Language::Macintosh(language) => language.bcp47(),
+            Language::Tag(language_tag) => language_tag
+        }
+    }
 }
 
 /// Macintosh encoding IDs
@@ -1016,6 +1411,130 @@ impl MacintoshLanguage {
             _ => None
         }
     }
+
+    /// BCP 47 language tag of the language.
+    pub fn bcp47(&self) -> &'static str {
+        match self {
+            MacintoshLanguage::English => "en",
+            MacintoshLanguage::French => "fr",
+            MacintoshLanguage::German => "de",
+            MacintoshLanguage::Italian => "it",
+            MacintoshLanguage::Dutch => "nl",
+            MacintoshLanguage::Swedish => "sv",
+            MacintoshLanguage::Spanish => "es",
+            MacintoshLanguage::Danish => "da",
+            MacintoshLanguage::Portuguese => "pt",
+            MacintoshLanguage::Norwegian => "nb",
+            MacintoshLanguage::Hebrew => "he",
+            MacintoshLanguage::Japanese => "ja",
+            MacintoshLanguage::Arabic => "ar",
+            MacintoshLanguage::Finnish => "fi",
+            MacintoshLanguage::Greek => "el",
+            MacintoshLanguage::Icelandic => "is",
+            MacintoshLanguage::Maltese => "mt",
+            MacintoshLanguage::Turkish => "tr",
+            MacintoshLanguage::Croatian => "hr",
+            MacintoshLanguage::ChineseTraditional => "zh-Hant",
+            MacintoshLanguage::Urdu => "ur",
+            MacintoshLanguage::Hindi => "hi",
+            MacintoshLanguage::Thai => "th",
+            MacintoshLanguage::Korean => "ko",
+            MacintoshLanguage::Lithuanian => "lt",
+            MacintoshLanguage::Polish => "pl",
+            MacintoshLanguage::Hungarian => "hu",
+            MacintoshLanguage::Estonian => "et",
+            MacintoshLanguage::Latvian => "lv",
+            MacintoshLanguage::Sami => "se",
+            MacintoshLanguage::Faroese => "fo",
+            MacintoshLanguage::Farsi => "fa",
+            MacintoshLanguage::Russian => "ru",
+            MacintoshLanguage::ChineseSimplified => "zh-Hans",
+            MacintoshLanguage::Flemish => "nl-BE",
+            MacintoshLanguage::Irish => "ga",
+            MacintoshLanguage::Albanian => "sq",
+            MacintoshLanguage::Romanian => "ro",
+            MacintoshLanguage::Czech => "cs",
+            MacintoshLanguage::Slovak => "sk",
+            MacintoshLanguage::Slovenian => "sl",
+            MacintoshLanguage::Yiddish => "yi",
+            MacintoshLanguage::Serbian => "sr",
+            MacintoshLanguage::Macedonian => "mk",
+            MacintoshLanguage::Bulgarian => "bg",
+            MacintoshLanguage::Ukrainian => "uk",
+            MacintoshLanguage::Byelorussian => "be",
+            MacintoshLanguage::Uzbek => "uz",
+            MacintoshLanguage::Kazakh => "kk",
+            MacintoshLanguage::AzerbaijaniCyrillic => "az-Cyrl",
+            MacintoshLanguage::AzerbaijaniArabic => "az-Arab",
+            MacintoshLanguage::Armenian => "hy",
+            MacintoshLanguage::Georgian => "ka",
+            MacintoshLanguage::Moldavian => "ro-MD",
+            MacintoshLanguage::Kirghiz => "ky",
+            MacintoshLanguage::Tajiki => "tg",
+            MacintoshLanguage::Turkmen => "tk",
+            MacintoshLanguage::Mongolian => "mn-Mong",
+            MacintoshLanguage::MongolianCyrillic => "mn-Cyrl",
+            MacintoshLanguage::Pashto => "ps",
+            MacintoshLanguage::Kurdish => "ku",
+            MacintoshLanguage::Kashmiri => "ks",
+            MacintoshLanguage::Sindhi => "sd",
+            MacintoshLanguage::Tibetan => "bo",
+            MacintoshLanguage::Nepali => "ne",
+            MacintoshLanguage::Sanskrit => "sa",
+            MacintoshLanguage::Marathi => "mr",
+            MacintoshLanguage::Bengali => "bn",
+            MacintoshLanguage::Assamese => "as",
+            MacintoshLanguage::Gujarati => "gu",
+            MacintoshLanguage::Punjabi => "pa",
+            MacintoshLanguage::Oriya => "or",
+            MacintoshLanguage::Malayalam => "ml",
+            MacintoshLanguage::Kannada => "kn",
+            MacintoshLanguage::Tamil => "ta",
+            MacintoshLanguage::Telugu => "te",
+            MacintoshLanguage::Sinhalese => "si",
+            MacintoshLanguage::Burmese => "my",
+            MacintoshLanguage::Khmer => "km",
+            MacintoshLanguage::Lao => "lo",
+            MacintoshLanguage::Vietnamese => "vi",
+            MacintoshLanguage::Indonesian => "id",
+            MacintoshLanguage::Tagalog => "tl",
+            MacintoshLanguage::MalayRoman => "ms",
+            MacintoshLanguage::MalayArabic => "ms-Arab",
+            MacintoshLanguage::Amharic => "am",
+            MacintoshLanguage::Tigrinya => "ti",
+            MacintoshLanguage::Galla => "om",
+            MacintoshLanguage::Somali => "so",
+            MacintoshLanguage::Swahili => "sw",
+            MacintoshLanguage::Kinyarwanda => "rw",
+            MacintoshLanguage::Rundi => "rn",
+            MacintoshLanguage::Nyanja => "ny",
+            MacintoshLanguage::Malagasy => "mg",
+            MacintoshLanguage::Esperanto => "eo",
+            MacintoshLanguage::Welsh => "cy",
+            MacintoshLanguage::Basque => "eu",
+            MacintoshLanguage::Catalan => "ca",
+            MacintoshLanguage::Latin => "la",
+            MacintoshLanguage::Quechua => "qu",
+            MacintoshLanguage::Guarani => "gn",
+            MacintoshLanguage::Aymara => "ay",
+            MacintoshLanguage::Tatar => "tt",
+            MacintoshLanguage::Uighur => "ug",
+            MacintoshLanguage::Dzongkha => "dz",
+            MacintoshLanguage::Javanese => "jv",
+            MacintoshLanguage::Sundanese => "su",
+            MacintoshLanguage::Galician => "gl",
+            MacintoshLanguage::Afrikaans => "af",
+            MacintoshLanguage::Breton => "br",
+            MacintoshLanguage::Inuktitut => "iu",
+            MacintoshLanguage::Scottish => "gd",
+            MacintoshLanguage::Manx => "gv",
+            MacintoshLanguage::IrishGaelicWithDotAbove => "ga-Latg",
+            MacintoshLanguage::Tongan => "to",
+            MacintoshLanguage::GreekPolytonic => "el-polyton",
+            MacintoshLanguage::Greenlandic => "kl",
+            MacintoshLanguage::AzerbaijaniRoman => "az-Latn",
+        }
+    }
 }
 
 /// ISO encoding IDs
@@ -1333,6 +1852,7 @@ impl NameId {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct NameRecord {
     platform: Platform,
+    language_id: u16,
     name_id: NameId,
     length: u16,
     offset: u16
@@ -1344,6 +1864,12 @@ impl NameRecord {
         self.platform
     }
 
+    /// Language ID, platform-specific if lower than 0x8000 and referencing a language-tag
+    /// record otherwise.
+    pub fn language_id(&self) -> u16 {
+        self.language_id
+    }
+
     /// Name ID.
     ///
     /// The name ID identifies a logical string category, such as family name or copyright.
@@ -1397,6 +1923,121 @@ impl LangTagRecord {
     }
 }
 
+/// Rank of a platform when looking for a string, the lowest rank being preferred.
+fn platform_rank(platform: Platform) -> u8 {
+    match platform {
+        Platform::Windows(..) => 0,
+        Platform::Unicode(..) => 1,
+        Platform::Macintosh(..) => 2,
+        _ => 3
+    }
+}
+
+/// Primary language subtag of a BCP 47 language tag, e.g. “zh” for “zh-Hant-HK”.
+fn primary_language_subtag(language_tag: &str) -> &str {
+    language_tag.split('-').next().unwrap_or(language_tag)
+}
+
+/// Decode a string of the naming table according to the encoding of its platform.
+fn decode_string(platform: Platform, bytes: &[u8]) -> Result<String, Error> {
+    match platform {
+        Platform::Unicode(..) | Platform::Windows(..) | Platform::Iso(IsoEncoding::Iso10646, _) => decode_utf16_be(bytes),
+        Platform::Iso(IsoEncoding::Ascii, _) => decode_single_byte(bytes, None),
+        Platform::Iso(IsoEncoding::Iso8859_1, _) => Ok(bytes.iter().map(|&byte| char::from(byte)).collect()),
+        Platform::Macintosh(MacintoshEncoding::Roman, _) => decode_single_byte(bytes, Some(&MAC_ROMAN)),
+        Platform::Macintosh(MacintoshEncoding::Greek, _) => decode_single_byte(bytes, Some(&MAC_GREEK)),
+        Platform::Macintosh(MacintoshEncoding::Russian, _) => decode_single_byte(bytes, Some(&MAC_CYRILLIC)),
+        Platform::Macintosh(encoding, _) => Err(Error::new(format!("Unsupported Macintosh encoding {:?}", encoding))),
+        Platform::Custom(encoding_id, _) => Err(Error::new(format!("Unsupported custom encoding {}", encoding_id))),
+        Platform::UserDefined(encoding_id, _) => Err(Error::new(format!("Unsupported user-defined encoding {}", encoding_id)))
+    }
+}
+
+fn decode_utf16_be(bytes: &[u8]) -> Result<String, Error> {
+    if bytes.len() % 2 != 0 {
+        return Err(Error::new(format!("Invalid UTF-16BE string length {}", bytes.len())));
+    }
+
+    let code_units: Vec<u16> = bytes.chunks_exact(2)
+        .map(|code_unit| u16::from(code_unit[0]) << 8 | u16::from(code_unit[1]))
+        .collect();
+
+    String::from_utf16(&code_units).map_err(|_| Error::new("Invalid UTF-16BE string"))
+}
+
+/// Decode a single-byte encoding whose first 128 characters are the ASCII characters, the other
+/// characters being given by the high table if any.
+fn decode_single_byte(bytes: &[u8], high_table: Option<&[char; 128]>) -> Result<String, Error> {
+    bytes.iter().map(|&byte| {
+        if byte < 0x80 {
+            return Ok(char::from(byte));
+        }
+
+        high_table.map(|high_table| high_table[usize::from(byte - 0x80)])
+            .ok_or_else(|| Error::new(format!("Invalid character 0x{:02X}", byte)))
+    }).collect()
+}
+
+/// Characters 0x80 to 0xFF of the Mac OS Roman encoding.
+const MAC_ROMAN: [char; 128] = [
+    '\u{00C4}', '\u{00C5}', '\u{00C7}', '\u{00C9}', '\u{00D1}', '\u{00D6}', '\u{00DC}', '\u{00E1}',
+    '\u{00E0}', '\u{00E2}', '\u{00E4}', '\u{00E3}', '\u{00E5}', '\u{00E7}', '\u{00E9}', '\u{00E8}',
+    '\u{00EA}', '\u{00EB}', '\u{00ED}', '\u{00EC}', '\u{00EE}', '\u{00EF}', '\u{00F1}', '\u{00F3}',
+    '\u{00F2}', '\u{00F4}', '\u{00F6}', '\u{00F5}', '\u{00FA}', '\u{00F9}', '\u{00FB}', '\u{00FC}',
+    '\u{2020}', '\u{00B0}', '\u{00A2}', '\u{00A3}', '\u{00A7}', '\u{2022}', '\u{00B6}', '\u{00DF}',
+    '\u{00AE}', '\u{00A9}', '\u{2122}', '\u{00B4}', '\u{00A8}', '\u{2260}', '\u{00C6}', '\u{00D8}',
+    '\u{221E}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{00A5}', '\u{00B5}', '\u{2202}', '\u{2211}',
+    '\u{220F}', '\u{03C0}', '\u{222B}', '\u{00AA}', '\u{00BA}', '\u{03A9}', '\u{00E6}', '\u{00F8}',
+    '\u{00BF}', '\u{00A1}', '\u{00AC}', '\u{221A}', '\u{0192}', '\u{2248}', '\u{2206}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{00C0}', '\u{00C3}', '\u{00D5}', '\u{0152}', '\u{0153}',
+    '\u{2013}', '\u{2014}', '\u{201C}', '\u{201D}', '\u{2018}', '\u{2019}', '\u{00F7}', '\u{25CA}',
+    '\u{00FF}', '\u{0178}', '\u{2044}', '\u{20AC}', '\u{2039}', '\u{203A}', '\u{FB01}', '\u{FB02}',
+    '\u{2021}', '\u{00B7}', '\u{201A}', '\u{201E}', '\u{2030}', '\u{00C2}', '\u{00CA}', '\u{00C1}',
+    '\u{00CB}', '\u{00C8}', '\u{00CD}', '\u{00CE}', '\u{00CF}', '\u{00CC}', '\u{00D3}', '\u{00D4}',
+    '\u{F8FF}', '\u{00D2}', '\u{00DA}', '\u{00DB}', '\u{00D9}', '\u{0131}', '\u{02C6}', '\u{02DC}',
+    '\u{00AF}', '\u{02D8}', '\u{02D9}', '\u{02DA}', '\u{00B8}', '\u{02DD}', '\u{02DB}', '\u{02C7}',
+];
+
+/// Characters 0x80 to 0xFF of the Mac OS Greek encoding.
+const MAC_GREEK: [char; 128] = [
+    '\u{00C4}', '\u{00B9}', '\u{00B2}', '\u{00C9}', '\u{00B3}', '\u{00D6}', '\u{00DC}', '\u{0385}',
+    '\u{00E0}', '\u{00E2}', '\u{00E4}', '\u{0384}', '\u{00A8}', '\u{00E7}', '\u{00E9}', '\u{00E8}',
+    '\u{00EA}', '\u{00EB}', '\u{00A3}', '\u{2122}', '\u{00EE}', '\u{00EF}', '\u{2022}', '\u{00BD}',
+    '\u{2030}', '\u{00F4}', '\u{00F6}', '\u{00A6}', '\u{20AC}', '\u{00F9}', '\u{00FB}', '\u{00FC}',
+    '\u{2020}', '\u{0393}', '\u{0394}', '\u{0398}', '\u{039B}', '\u{039E}', '\u{03A0}', '\u{00DF}',
+    '\u{00AE}', '\u{00A9}', '\u{03A3}', '\u{03AA}', '\u{00A7}', '\u{2260}', '\u{00B0}', '\u{00B7}',
+    '\u{0391}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{00A5}', '\u{0392}', '\u{0395}', '\u{0396}',
+    '\u{0397}', '\u{0399}', '\u{039A}', '\u{039C}', '\u{03A6}', '\u{03AB}', '\u{03A8}', '\u{03A9}',
+    '\u{03AC}', '\u{039D}', '\u{00AC}', '\u{039F}', '\u{03A1}', '\u{2248}', '\u{03A4}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{03A5}', '\u{03A7}', '\u{0386}', '\u{0388}', '\u{0153}',
+    '\u{2013}', '\u{2015}', '\u{201C}', '\u{201D}', '\u{2018}', '\u{2019}', '\u{00F7}', '\u{0389}',
+    '\u{038A}', '\u{038C}', '\u{038E}', '\u{03AD}', '\u{03AE}', '\u{03AF}', '\u{03CC}', '\u{038F}',
+    '\u{03CD}', '\u{03B1}', '\u{03B2}', '\u{03C8}', '\u{03B4}', '\u{03B5}', '\u{03C6}', '\u{03B3}',
+    '\u{03B7}', '\u{03B9}', '\u{03BE}', '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}', '\u{03BF}',
+    '\u{03C0}', '\u{03CE}', '\u{03C1}', '\u{03C3}', '\u{03C4}', '\u{03B8}', '\u{03C9}', '\u{03C2}',
+    '\u{03C7}', '\u{03C5}', '\u{03B6}', '\u{03CA}', '\u{03CB}', '\u{0390}', '\u{03B0}', '\u{00AD}',
+];
+
+/// Characters 0x80 to 0xFF of the Mac OS Cyrillic encoding.
+const MAC_CYRILLIC: [char; 128] = [
+    '\u{0410}', '\u{0411}', '\u{0412}', '\u{0413}', '\u{0414}', '\u{0415}', '\u{0416}', '\u{0417}',
+    '\u{0418}', '\u{0419}', '\u{041A}', '\u{041B}', '\u{041C}', '\u{041D}', '\u{041E}', '\u{041F}',
+    '\u{0420}', '\u{0421}', '\u{0422}', '\u{0423}', '\u{0424}', '\u{0425}', '\u{0426}', '\u{0427}',
+    '\u{0428}', '\u{0429}', '\u{042A}', '\u{042B}', '\u{042C}', '\u{042D}', '\u{042E}', '\u{042F}',
+    '\u{2020}', '\u{00B0}', '\u{0490}', '\u{00A3}', '\u{00A7}', '\u{2022}', '\u{00B6}', '\u{0406}',
+    '\u{00AE}', '\u{00A9}', '\u{2122}', '\u{0402}', '\u{0452}', '\u{2260}', '\u{0403}', '\u{0453}',
+    '\u{221E}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{0456}', '\u{00B5}', '\u{0491}', '\u{0408}',
+    '\u{0404}', '\u{0454}', '\u{0407}', '\u{0457}', '\u{0409}', '\u{0459}', '\u{040A}', '\u{045A}',
+    '\u{0458}', '\u{0405}', '\u{00AC}', '\u{221A}', '\u{0192}', '\u{2248}', '\u{2206}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{040B}', '\u{045B}', '\u{040C}', '\u{045C}', '\u{0455}',
+    '\u{2013}', '\u{2014}', '\u{201C}', '\u{201D}', '\u{2018}', '\u{2019}', '\u{00F7}', '\u{201E}',
+    '\u{040E}', '\u{045E}', '\u{040F}', '\u{045F}', '\u{2116}', '\u{0401}', '\u{0451}', '\u{044F}',
+    '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}', '\u{0437}',
+    '\u{0438}', '\u{0439}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}', '\u{043E}', '\u{043F}',
+    '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}', '\u{0446}', '\u{0447}',
+    '\u{0448}', '\u{0449}', '\u{044A}', '\u{044B}', '\u{044C}', '\u{044D}', '\u{044E}', '\u{20AC}',
+];
+
 use nom::Err;
 use nom::error::ErrorKind;
 
@@ -1445,6 +2086,7 @@ fn parse_name_record(input: &[u8]) -> IResult<&[u8], NameRecord>
 
     Ok((input, NameRecord {
         platform,
+        language_id,
         name_id,
         length,
         offset
@@ -1468,6 +2110,24 @@ mod tests {
     use nom::Err;
     use nom::error::ErrorKind;
 
+    // Format 1 naming table with the language tags “fr-CA” and “zh-Hant-HK”
+    static NAMING_TABLE_FORMAT_1: &[u8] = &[
+        0x00, 0x01, 0x00, 0x07, 0x00, 0x64, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x05,
+        0x00, 0x03, 0x00, 0x03, 0x00, 0x01, 0x04, 0x09, 0x00, 0x01, 0x00, 0x0C, 0x00, 0x08,
+        0x00, 0x03, 0x00, 0x01, 0x80, 0x00, 0x00, 0x01, 0x00, 0x0E, 0x00, 0x14, 0x00, 0x03,
+        0x00, 0x01, 0x08, 0x09, 0x00, 0x02, 0x00, 0x0C, 0x00, 0x22, 0x00, 0x03, 0x00, 0x01,
+        0x04, 0x09, 0x00, 0x04, 0x00, 0x08, 0x00, 0x2E, 0x00, 0x03, 0x00, 0x01, 0x80, 0x01,
+        0x00, 0x04, 0x00, 0x0E, 0x00, 0x36, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x44, 0x00, 0x14,
+        0x00, 0x4E, 0x4D, 0x61, 0x63, 0x50, 0x6C, 0x61, 0x69, 0x6E, 0x00, 0x46, 0x00, 0x61,
+        0x00, 0x6D, 0x00, 0x69, 0x00, 0x6C, 0x00, 0x79, 0x00, 0x46, 0x00, 0x61, 0x00, 0x6D,
+        0x00, 0x69, 0x00, 0x6C, 0x00, 0x6C, 0x00, 0x65, 0x00, 0x4E, 0x00, 0x6F, 0x00, 0x72,
+        0x00, 0x6D, 0x00, 0x61, 0x00, 0x6C, 0x00, 0x46, 0x00, 0x75, 0x00, 0x6C, 0x00, 0x6C,
+        0x00, 0x5A, 0x00, 0x68, 0x00, 0x20, 0x00, 0x46, 0x00, 0x75, 0x00, 0x6C, 0x00, 0x6C,
+        0x00, 0x66, 0x00, 0x72, 0x00, 0x2D, 0x00, 0x43, 0x00, 0x41, 0x00, 0x7A, 0x00, 0x68,
+        0x00, 0x2D, 0x00, 0x48, 0x00, 0x61, 0x00, 0x6E, 0x00, 0x74, 0x00, 0x2D, 0x00, 0x48,
+        0x00, 0x4B];
+
     #[test]
     fn case_naming_table_invalid_empty_slice() {
         let bytes: &[u8] = &[];
@@ -1502,6 +2162,7 @@ mod tests {
 
         let expected = (&b""[..], NameRecord {
             platform: Platform::new(1, 0, Some(0)).unwrap(),
+            language_id: 0,
             name_id: NameId::FontSpecificName(0x0FFF),
             offset: 0,
             length: 0
@@ -1534,6 +2195,7 @@ mod tests {
 
         let expected = (&b""[..], NameRecord {
             platform: Platform::new(1, 0, None).unwrap(),
+            language_id: 0xFF,
             name_id: NameId::FontFamilyName,
             offset: 0,
             length: 0
@@ -1550,4 +2212,99 @@ mod tests {
         let expected =  Err(Err::Error(error_position!(&b""[..], ErrorKind::Verify)));
         assert_eq!(parse_name_record(bytes), expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn case_naming_table_get_string() {
+        let bytes: &[u8] = &[
+            0x00, 0x00, 0x00, 0x04, 0x00, 0x36, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04,
+            0x00, 0x06, 0x00, 0x03, 0x00, 0x01, 0x04, 0x09, 0x00, 0x01, 0x00, 0x08, 0x00, 0x0A,
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x0B, 0x00, 0x01, 0x00, 0x02, 0x00, 0x12,
+            0xA9, 0x20, 0x43, 0x61, 0x66, 0x8E, 0x81, 0xE2, 0xDF, 0xF2, 0x00, 0x43, 0x00, 0x61,
+            0x00, 0x66, 0x00, 0xE9, 0x82, 0xA0];
+
+        let naming_table = parse_naming_table(bytes).unwrap().1;
+        let name_records = naming_table.name_records();
+
+        // Mac OS Roman
+        assert_eq!(naming_table.get_string(&name_records[0], bytes).unwrap(), "© Café");
+        // Mac OS Cyrillic
+        assert_eq!(naming_table.get_string(&name_records[1], bytes).unwrap(), "Бвят");
+        // UTF-16BE
+        assert_eq!(naming_table.get_string(&name_records[2], bytes).unwrap(), "Café");
+        // Mac OS Japanese
+        assert!(naming_table.get_string(&name_records[3], bytes).is_err());
+    }
+
+    #[test]
+    fn case_naming_table_get_string_out_of_bounds() {
+        let bytes: &[u8] = &[
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x12, 0x00, 0x03, 0x00, 0x01, 0x04, 0x09, 0x00, 0x01,
+            0x00, 0x04, 0x00, 0x00, 0x00, 0x52];
+
+        let naming_table = parse_naming_table(bytes).unwrap().1;
+
+        assert!(naming_table.get_string(&naming_table.name_records()[0], bytes).is_err());
+    }
+
+    #[test]
+    fn case_naming_table_find() {
+        let naming_table = parse_naming_table(NAMING_TABLE_FORMAT_1).unwrap().1;
+
+        assert_eq!(naming_table.lang_tag_records().len(), 2);
+
+        let find = |name_id: NameId, preferred_language: Option<&str>| naming_table.find(name_id, preferred_language, NAMING_TABLE_FORMAT_1);
+
+        assert_eq!(find(NameId::FontFamilyName, None), Some(String::from("Family")));
+        assert_eq!(find(NameId::FontFamilyName, Some("de")), Some(String::from("Family")));
+        assert_eq!(find(NameId::FontFamilyName, Some("fr")), Some(String::from("Famille")));
+        // Windows English before Macintosh English
+        assert_eq!(find(NameId::FontSubfamilyName, None), Some(String::from("Normal")));
+        assert_eq!(find(NameId::FullFontName, None), Some(String::from("Full")));
+        assert_eq!(find(NameId::FullFontName, Some("zh-Hant-HK")), Some(String::from("Zh Full")));
+        assert_eq!(find(NameId::FullFontName, Some("zh-TW")), Some(String::from("Zh Full")));
+        assert_eq!(find(NameId::PostScript, None), None);
+    }
+
+    #[test]
+    fn case_naming_table_find_typographic_family_name() {
+        let bytes: &[u8] = &[
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x1E, 0x00, 0x03, 0x00, 0x01, 0x04, 0x09, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x41, 0x42];
+
+        let naming_table = parse_naming_table(bytes).unwrap().1;
+
+        // The Macintosh typographic family name is preferred to the Windows family name
+        assert_eq!(naming_table.find(NameId::FontFamilyName, None, bytes), Some(String::from("B")));
+    }
+
+    #[test]
+    fn case_naming_table_language() {
+        let naming_table = parse_naming_table(NAMING_TABLE_FORMAT_1).unwrap().1;
+        let name_records = naming_table.name_records();
+
+        assert_eq!(naming_table.language(&name_records[0], NAMING_TABLE_FORMAT_1), Some(Language::Macintosh(MacintoshLanguage::English)));
+        assert_eq!(naming_table.language(&name_records[4], NAMING_TABLE_FORMAT_1), Some(Language::Windows(WindowsLanguage::EnglishUnitedKingdom)));
+        assert_eq!(naming_table.language(&name_records[3], NAMING_TABLE_FORMAT_1), Some(Language::Tag(String::from("fr-CA"))));
+        assert_eq!(naming_table.language(&name_records[6], NAMING_TABLE_FORMAT_1).unwrap().bcp47(), "zh-Hant-HK");
+
+        assert_eq!(Language::Windows(WindowsLanguage::EnglishUnitedKingdom).bcp47(), "en-GB");
+        assert_eq!(Language::Windows(WindowsLanguage::ChineseTaiwan).bcp47(), "zh-TW");
+        assert_eq!(Language::Windows(WindowsLanguage::SerbianLatinSerbia).bcp47(), "sr-Latn-RS");
+        assert_eq!(Language::Macintosh(MacintoshLanguage::ChineseTraditional).bcp47(), "zh-Hant");
+    }
+
+    #[test]
+    fn case_naming_table_language_unknown_tag() {
+        // Language ID 0x8000 without language-tag record
+        let bytes: &[u8] = &[
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x12, 0x00, 0x03, 0x00, 0x01, 0x80, 0x00, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x41];
+
+        let naming_table = parse_naming_table(bytes).unwrap().1;
+
+        assert_eq!(naming_table.language(&naming_table.name_records()[0], bytes), None);
+        assert_eq!(naming_table.find(NameId::FontFamilyName, Some("en"), bytes), Some(String::from("A")));
+    }
+}
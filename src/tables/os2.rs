@@ -3,7 +3,7 @@ use nom::IResult;
 use nom::number::complete::{be_i16, be_u16, be_u32};
 use nom::bytes::complete::take;
 use nom::error::ErrorKind;
-use nom::combinator::map_opt;
+use nom::combinator::map;
 use std::ops;
 use tables::Tag;
 
@@ -331,6 +331,28 @@ impl Os2 {
             Os2Version::Version5(os2) => os2.us_win_descent(),
         }
     }
+
+    /// See [sx_height](Os2V4.t.html#method.sx_height), None before version 2.
+    pub fn sx_height(&self) -> Option<i16> {
+        match &self.0 {
+            Os2Version::Version0(_) | Os2Version::Version1(_) => None,
+            Os2Version::Version2(os2) => Some(os2.sx_height()),
+            Os2Version::Version3(os2) => Some(os2.sx_height()),
+            Os2Version::Version4(os2) => Some(os2.sx_height()),
+            Os2Version::Version5(os2) => Some(os2.sx_height()),
+        }
+    }
+
+    /// See [s_cap_height](Os2V4.t.html#method.s_cap_height), None before version 2.
+    pub fn s_cap_height(&self) -> Option<i16> {
+        match &self.0 {
+            Os2Version::Version0(_) | Os2Version::Version1(_) => None,
+            Os2Version::Version2(os2) => Some(os2.s_cap_height()),
+            Os2Version::Version3(os2) => Some(os2.s_cap_height()),
+            Os2Version::Version4(os2) => Some(os2.s_cap_height()),
+            Os2Version::Version5(os2) => Some(os2.s_cap_height()),
+        }
+    }
 }
 
 impl_parse!(
@@ -387,7 +409,7 @@ impl_parse!(
     ///         assert_eq!(os2.panose(), &Panose::new(&[2, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
     ///         assert_eq!(os2.ul_unicode_range(), UnicodeRange::new(3758097151, 1342185563, 32, 0));
     ///         assert_eq!(os2.ach_vend_id(), Tag::new(b"GOOG"));
-    ///         assert_eq!(os2.fs_selection(), FontSelectionFlags::OBLIQUE);
+    ///         assert_eq!(os2.fs_selection(), FontSelectionFlags::REGULAR);
     ///         assert_eq!(os2.us_first_char_index(), 0);
     ///         assert_eq!(os2.us_last_char_index(), 65533);
     ///         assert_eq!(os2.s_typo_ascender(), 1536);
@@ -721,27 +743,27 @@ bitflags! {
     #[doc="Font selection flags."]
     pub struct FontSelectionFlags: u16 {
         /// Font contains italic or oblique glyphs, otherwise they are upright.
-        const ITALIC            = 0b1000000000000000;
+        const ITALIC            = 0b0000000000000001;
         /// glyphs are underscored.
-        const UNDERSCORE        = 0b0100000000000000;
+        const UNDERSCORE        = 0b0000000000000010;
         /// glyphs have their foreground and background reversed.
-        const NEGATIVE          = 0b0010000000000000;
+        const NEGATIVE          = 0b0000000000000100;
         /// Outline (hollow) glyphs, otherwise they are solid.
-        const OUTLINED          = 0b0001000000000000;
+        const OUTLINED          = 0b0000000000001000;
         /// glyphs are overstruck.
-        const STRIKEOUT         = 0b0000100000000000;
+        const STRIKEOUT         = 0b0000000000010000;
         /// glyphs are emboldened.
-        const BOLD              = 0b0000010000000000;
+        const BOLD              = 0b0000000000100000;
         /// glyphs are in the standard weight/style for the font.
-        const REGULAR           = 0b0000001000000000;
+        const REGULAR           = 0b0000000001000000;
         /// If set, it is strongly recommended that applications use OS/2.sTypoAscender -
         /// OS/2.sTypoDescender + OS/2.sTypoLineGap as the default line spacing for this font.
-        const USE_TYPO_METRICS  = 0b0000000100000000;
+        const USE_TYPO_METRICS  = 0b0000000010000000;
         /// The font has 'name' table strings consistent with a weight/width/slope family without
         /// requiring use of name IDs 21 and 22. (Please see more detailed description below.)
-        const WWS               = 0b0000000010000000;
+        const WWS               = 0b0000000100000000;
         /// Font contains oblique glyphs.
-        const OBLIQUE           = 0b0000000001000000;
+        const OBLIQUE           = 0b0000001000000000;
 
         // Bits 10–15 reserved
     }
@@ -1823,7 +1845,7 @@ fn parse_os2v0(input: &[u8]) -> IResult<&[u8], Os2V0>
     let (input, ul_unicode_range3) = be_u32(input)?;
     let (input, ul_unicode_range4) = be_u32(input)?;
     let (input, ach_vend_id) = take(4usize)(input)?;
-    let (input, fs_selection) = map_opt(be_u16, |flags| FontSelectionFlags::from_bits(flags))(input)?;
+    let (input, fs_selection) = map(be_u16, FontSelectionFlags::from_bits_truncate)(input)?;
     let (input, us_first_char_index) = be_u16(input)?;
     let (input, us_last_char_index) = be_u16(input)?;
     let (input, s_typo_ascender) = be_i16(input)?;
@@ -6,8 +6,9 @@ use nom::multi::count;
 use nom::combinator::map_res;
 use nom::bytes::complete::take;
 use nom::error::ErrorKind;
-use nom::number::complete::{be_u8, be_i16, be_u16, be_i32, be_u32};
+use nom::number::complete::{be_i8, be_u8, be_i16, be_u16, be_i32, be_u32};
 use std::{ops, str};
+use super::GlyphId;
 
 /// PostScript Table
 ///
@@ -28,6 +29,33 @@ impl PostScriptTable {
         &self.0
     }
 
+    /// PostScript name of the given glyph.
+    ///
+    /// Names are taken from the standard Macintosh glyph order for version 1.0 tables, from the
+    /// glyph name indexes and the Pascal strings of version 2.0 tables and from the offsets to
+    /// the standard Macintosh glyph order of version 2.5 tables. Versions 3.0 and 4.0 provide no
+    /// glyph names.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        match &self.0 {
+            PostScriptVersion::Version_1_0(_) => MACINTOSH_GLYPH_NAMES.get(usize::from(glyph_id)).cloned(),
+            PostScriptVersion::Version_2_0(post_script_table_v2) => post_script_table_v2.glyph_name(glyph_id),
+            PostScriptVersion::Version_2_5(post_script_table_v2_5) => post_script_table_v2_5.glyph_name(glyph_id),
+            PostScriptVersion::Version_3_0(_) | PostScriptVersion::Version_4_0(_) => None
+        }
+    }
+
+    /// Glyph whose PostScript name is the given name, the glyphs being searched in order.
+    pub fn glyph_id(&self, glyph_name: &str) -> Option<GlyphId> {
+        let num_glyphs = match &self.0 {
+            PostScriptVersion::Version_1_0(_) => MACINTOSH_GLYPH_NAMES.len() as u16,
+            PostScriptVersion::Version_2_0(post_script_table_v2) => post_script_table_v2.num_glyphs(),
+            PostScriptVersion::Version_2_5(post_script_table_v2_5) => post_script_table_v2_5.num_glyphs(),
+            PostScriptVersion::Version_3_0(_) | PostScriptVersion::Version_4_0(_) => 0
+        };
+
+        (0..num_glyphs).find(|&glyph_id| self.glyph_name(glyph_id) == Some(glyph_name))
+    }
+
     /// See [italic_angle](PostScriptTableHeader.t.html#method.italic_angle).
     pub fn italic_angle(&self) -> i32 {
         match &self.0 {
@@ -124,7 +152,35 @@ impl_parse!(
     ///
     /// Post Script Table version 2
     /// ```
-    /// // TODO
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::post::{PostScriptTable, PostScriptVersion};
+    /// use otf::parser::Parse;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x6A, 0x00, 0x64, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x24, 0x01, 0x02, 0x01, 0x03,
+    ///     0x05, 0x41, 0x6C, 0x70, 0x68, 0x61, 0x04, 0x42, 0x65, 0x74, 0x61];
+    ///
+    /// let post_script_table = PostScriptTable::parse(bytes).unwrap().1;
+    ///
+    /// assert_eq!(post_script_table.underline_position(), -150);
+    /// assert_eq!(post_script_table.glyph_name(0), Some(".notdef"));
+    /// assert_eq!(post_script_table.glyph_name(1), Some("A"));
+    /// assert_eq!(post_script_table.glyph_name(2), Some("Alpha"));
+    /// assert_eq!(post_script_table.glyph_name(3), Some("Beta"));
+    /// assert_eq!(post_script_table.glyph_name(4), None);
+    /// assert_eq!(post_script_table.glyph_id("Beta"), Some(3));
+    ///
+    /// match post_script_table.version() {
+    ///     PostScriptVersion::Version_2_0(post_script_table_v2) => {
+    ///         assert_eq!(post_script_table_v2.num_glyphs(), 4);
+    ///         assert_eq!(post_script_table_v2.glyph_name_indexes(), &[0, 36, 258, 259]);
+    ///         assert_eq!(post_script_table_v2.glyph_names(), &["Alpha", "Beta"]);
+    ///     },
+    ///     _ => assert!(false)
+    /// }
     /// ```
     ///
     /// Post Script Table version 3
@@ -187,7 +243,7 @@ pub enum PostScriptVersion {
     /// This version provides a space-saving table for TrueType-based fonts which contain a pure
     /// subset of, or a simple reordering of, the standard Macintosh glyph set.
     #[deprecated]
-    Version_2_5(PostScriptTableHeader),
+    Version_2_5(PostScriptTableV25),
 
     /// This version makes it possible to create a font that is not burdened with a large 'post'
     /// table set of glyph names. A version 3.0 'post' table can be used by OpenType fonts with
@@ -219,7 +275,7 @@ pub enum PostScriptVersion {
     /// As a rule, format 4 'post' tables are no longer necessary and should be avoided.
     /// Source: [https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html)
     #[deprecated]
-    Version_4_0(PostScriptTableHeader)
+    Version_4_0(PostScriptTableV40)
 }
 
 /// The last four entries in the table are present because PostScript drivers can do better memory
@@ -316,7 +372,8 @@ impl PostScriptTableHeader {
 pub struct PostScriptTableV20 {
     header: PostScriptTableHeader,
     num_glyphs: u16,
-    glyph_name_indexes: Vec<u16>
+    glyph_name_indexes: Vec<u16>,
+    glyph_names: Vec<String>
 }
 
 impl PostScriptTableV20 {
@@ -330,6 +387,22 @@ impl PostScriptTableV20 {
         &self.glyph_name_indexes
     }
 
+    /// Glyph names with length bytes [variable] (a Pascal string).
+    pub fn glyph_names(&self) -> &[String] {
+        &self.glyph_names
+    }
+
+    /// PostScript name of the given glyph, either a standard Macintosh glyph name or one of the
+    /// glyph names of the table.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        let glyph_name_index = usize::from(*self.glyph_name_indexes.get(usize::from(glyph_id))?);
+
+        match glyph_name_index.checked_sub(MACINTOSH_GLYPH_NAMES.len()) {
+            Some(index) => self.glyph_names.get(index).map(String::as_str),
+            None => Some(MACINTOSH_GLYPH_NAMES[glyph_name_index])
+        }
+    }
+
     /// Parse the glyph names into a vector of &str.
     pub fn parse_glyph_names<'otf>(&self, input: &'otf[u8]) -> IResult<&'otf[u8], Vec<&'otf str>> {
         let count = self.glyph_name_indexes.iter().fold(0, |n, &i| {
@@ -356,6 +429,78 @@ impl<'otf> ops::Deref for PostScriptTableV20 {
     }
 }
 
+/// This font file contains a pure subset of, or a simple reordering of, the standard Macintosh
+/// glyph set. The glyph names are given by an offset for each glyph, which is added to the glyph
+/// index to get the index of the glyph name in the standard Macintosh order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PostScriptTableV25 {
+    header: PostScriptTableHeader,
+    num_glyphs: u16,
+    offsets: Vec<i8>
+}
+
+impl PostScriptTableV25 {
+    /// Number of glyphs.
+    pub fn num_glyphs(&self) -> u16 {
+        self.num_glyphs
+    }
+
+    /// Difference between the standard Macintosh glyph index and the glyph index, for each glyph.
+    pub fn offsets(&self) -> &[i8] {
+        &self.offsets
+    }
+
+    /// PostScript name of the given glyph, taken from the standard Macintosh glyph names.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        let offset = *self.offsets.get(usize::from(glyph_id))?;
+        let index = i32::from(glyph_id) + i32::from(offset);
+
+        if index < 0 {
+            return None;
+        }
+
+        MACINTOSH_GLYPH_NAMES.get(index as usize).cloned()
+    }
+}
+
+impl ops::Deref for PostScriptTableV25 {
+    type Target = PostScriptTableHeader;
+    fn deref(&self) -> &Self::Target {
+        &self.header
+    }
+}
+
+/// This font file names its glyphs by their character codes. The header is followed by the
+/// character code of every glyph, the table having no count of the glyphs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PostScriptTableV40 {
+    header: PostScriptTableHeader,
+    character_codes: Vec<u16>
+}
+
+impl PostScriptTableV40 {
+    /// Character code that maps to each glyph, or 0xFFFF if there is no associated character
+    /// code for that glyph.
+    pub fn character_codes(&self) -> &[u16] {
+        &self.character_codes
+    }
+
+    /// Character code that maps to the given glyph.
+    pub fn character_code(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self.character_codes.get(usize::from(glyph_id)) {
+            Some(&character_code) if character_code != 0xFFFF => Some(character_code),
+            _ => None
+        }
+    }
+}
+
+impl ops::Deref for PostScriptTableV40 {
+    type Target = PostScriptTableHeader;
+    fn deref(&self) -> &Self::Target {
+        &self.header
+    }
+}
+
 pub fn parse_post_script_table(input: &[u8]) -> IResult<&[u8], PostScriptTable>
 {
     let (input, version) = be_i32(input)?;
@@ -370,16 +515,16 @@ pub fn parse_post_script_table(input: &[u8]) -> IResult<&[u8], PostScriptTable>
             Ok((input, PostScriptTable(PostScriptVersion::Version_2_0(header))))
         },
         0x00025000 => {
-            let (input, header) = parse_post_script_header(input)?;
-            Ok((input, PostScriptTable(PostScriptVersion::Version_2_5(header))))
+            let (input, post_script_table_v2_5) = parse_post_script_table_v2_5(input)?;
+            Ok((input, PostScriptTable(PostScriptVersion::Version_2_5(post_script_table_v2_5))))
         },
         0x00030000 => {
             let (input, header) = parse_post_script_header(input)?;
             Ok((input, PostScriptTable(PostScriptVersion::Version_3_0(header))))
         },
         0x00040000 => {
-            let (input, header) = parse_post_script_header(input)?;
-            Ok((input, PostScriptTable(PostScriptVersion::Version_4_0(header))))
+            let (input, post_script_table_v4) = parse_post_script_table_v4_0(input)?;
+            Ok((input, PostScriptTable(PostScriptVersion::Version_4_0(post_script_table_v4))))
         },
         _ => Err(NomErr::Error(error_position!(input, ErrorKind::Alt)))
     }
@@ -412,12 +557,57 @@ fn parse_post_script_table_v2_0(input: &[u8]) -> IResult<&[u8], PostScriptTableV
 {
     let (input, header) = parse_post_script_header(input)?;
     let (input, num_glyphs) = be_u16(input)?;
-    let (input, glyph_name_indexes) = count(be_u16, usize::from(num_glyphs))(input)?;
+    let (mut input, glyph_name_indexes) = count(be_u16, usize::from(num_glyphs))(input)?;
+
+    // The Pascal strings are referenced by the glyph name indexes from 258, the strings that are
+    // missing or that are not valid UTF-8 end the glyph names
+    let num_glyph_names = glyph_name_indexes.iter()
+        .map(|&glyph_name_index| usize::from(glyph_name_index))
+        .filter(|&glyph_name_index| glyph_name_index >= MACINTOSH_GLYPH_NAMES.len())
+        .map(|glyph_name_index| glyph_name_index - MACINTOSH_GLYPH_NAMES.len() + 1)
+        .max()
+        .unwrap_or(0);
+    let mut glyph_names = Vec::with_capacity(num_glyph_names);
+    while glyph_names.len() < num_glyph_names {
+        match parse_pascal_string_to_owned(input) {
+            Ok((remainder, glyph_name)) => {
+                glyph_names.push(glyph_name);
+                input = remainder;
+            },
+            Err(_) => break
+        }
+    }
 
     Ok((input, PostScriptTableV20 {
         header,
         num_glyphs,
-        glyph_name_indexes
+        glyph_name_indexes,
+        glyph_names
+    }))
+}
+
+fn parse_post_script_table_v2_5(input: &[u8]) -> IResult<&[u8], PostScriptTableV25>
+{
+    let (input, header) = parse_post_script_header(input)?;
+    let (input, num_glyphs) = be_u16(input)?;
+    let (input, offsets) = count(be_i8, usize::from(num_glyphs))(input)?;
+
+    Ok((input, PostScriptTableV25 {
+        header,
+        num_glyphs,
+        offsets
+    }))
+}
+
+/// Parse a version 4.0 table, the character codes being read up to the end of the input.
+fn parse_post_script_table_v4_0(input: &[u8]) -> IResult<&[u8], PostScriptTableV40>
+{
+    let (input, header) = parse_post_script_header(input)?;
+    let (input, character_codes) = count(be_u16, input.len() / 2)(input)?;
+
+    Ok((input, PostScriptTableV40 {
+        header,
+        character_codes
     }))
 }
 
@@ -442,6 +632,40 @@ pub fn parse_pascal_strings_to_owned(input: &[u8], length: usize) -> IResult<&[u
     count(parse_pascal_string_to_owned, length)(input)
 }
 
+/// Glyph names of the 258 glyphs of the standard Macintosh TrueType font file, in the standard
+/// Macintosh order.
+const MACINTOSH_GLYPH_NAMES: [&str; 258] = [
+    ".notdef", ".null", "nonmarkingreturn", "space", "exclam", "quotedbl", "numbersign", "dollar",
+    "percent", "ampersand", "quotesingle", "parenleft", "parenright", "asterisk", "plus", "comma",
+    "hyphen", "period", "slash", "zero", "one", "two", "three", "four", "five", "six", "seven",
+    "eight", "nine", "colon", "semicolon", "less", "equal", "greater", "question", "at", "A", "B",
+    "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U",
+    "V", "W", "X", "Y", "Z", "bracketleft", "backslash", "bracketright", "asciicircum",
+    "underscore", "grave", "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n",
+    "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z", "braceleft", "bar", "braceright",
+    "asciitilde", "Adieresis", "Aring", "Ccedilla", "Eacute", "Ntilde", "Odieresis", "Udieresis",
+    "aacute", "agrave", "acircumflex", "adieresis", "atilde", "aring", "ccedilla", "eacute",
+    "egrave", "ecircumflex", "edieresis", "iacute", "igrave", "icircumflex", "idieresis", "ntilde",
+    "oacute", "ograve", "ocircumflex", "odieresis", "otilde", "uacute", "ugrave", "ucircumflex",
+    "udieresis", "dagger", "degree", "cent", "sterling", "section", "bullet", "paragraph",
+    "germandbls", "registered", "copyright", "trademark", "acute", "dieresis", "notequal", "AE",
+    "Oslash", "infinity", "plusminus", "lessequal", "greaterequal", "yen", "mu", "partialdiff",
+    "summation", "product", "pi", "integral", "ordfeminine", "ordmasculine", "Omega", "ae",
+    "oslash", "questiondown", "exclamdown", "logicalnot", "radical", "florin", "approxequal",
+    "Delta", "guillemotleft", "guillemotright", "ellipsis", "nonbreakingspace", "Agrave", "Atilde",
+    "Otilde", "OE", "oe", "endash", "emdash", "quotedblleft", "quotedblright", "quoteleft",
+    "quoteright", "divide", "lozenge", "ydieresis", "Ydieresis", "fraction", "currency",
+    "guilsinglleft", "guilsinglright", "fi", "fl", "daggerdbl", "periodcentered", "quotesinglbase",
+    "quotedblbase", "perthousand", "Acircumflex", "Ecircumflex", "Aacute", "Edieresis", "Egrave",
+    "Iacute", "Icircumflex", "Idieresis", "Igrave", "Oacute", "Ocircumflex", "apple", "Ograve",
+    "Uacute", "Ucircumflex", "Ugrave", "dotlessi", "circumflex", "tilde", "macron", "breve",
+    "dotaccent", "ring", "cedilla", "hungarumlaut", "ogonek", "caron", "Lslash", "lslash", "Scaron",
+    "scaron", "Zcaron", "zcaron", "brokenbar", "Eth", "eth", "Yacute", "yacute", "Thorn", "thorn",
+    "minus", "multiply", "onesuperior", "twosuperior", "threesuperior", "onehalf", "onequarter",
+    "threequarters", "franc", "Gbreve", "gbreve", "Idotaccent", "Scedilla", "scedilla", "Cacute",
+    "cacute", "Ccaron", "ccaron", "dcroat"
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -479,4 +703,83 @@ mod tests {
         let expected = Err(Err::Error(error_position!(bytes, ErrorKind::Eof)));
         assert_eq!(parse_post_script_table(bytes), expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn case_post_script_table_v1_0_glyph_names() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x6A, 0x00, 0x64, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00];
+
+        let post_script_table = parse_post_script_table(bytes).unwrap().1;
+
+        assert_eq!(post_script_table.glyph_name(0), Some(".notdef"));
+        assert_eq!(post_script_table.glyph_name(36), Some("A"));
+        assert_eq!(post_script_table.glyph_name(172), Some("nonbreakingspace"));
+        assert_eq!(post_script_table.glyph_name(257), Some("dcroat"));
+        assert_eq!(post_script_table.glyph_name(258), None);
+        assert_eq!(post_script_table.glyph_id("dcroat"), Some(257));
+        assert_eq!(post_script_table.glyph_id("Alpha"), None);
+    }
+
+    #[test]
+    fn case_post_script_table_v2_5_glyph_names() {
+        let bytes: &[u8]  = &[
+            0x00, 0x02, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x6A, 0x00, 0x64, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x22, 0x80];
+
+        let post_script_table = parse_post_script_table(bytes).unwrap().1;
+
+        match post_script_table.version() {
+            PostScriptVersion::Version_2_5(post_script_table_v2_5) => {
+                assert_eq!(post_script_table_v2_5.underline_position(), -150);
+                assert_eq!(post_script_table_v2_5.offsets(), &[0, 2, 34, -128]);
+            },
+            _ => assert!(false)
+        }
+
+        assert_eq!(post_script_table.glyph_name(0), Some(".notdef"));
+        assert_eq!(post_script_table.glyph_name(1), Some("space"));
+        assert_eq!(post_script_table.glyph_name(2), Some("A"));
+        assert_eq!(post_script_table.glyph_name(3), None);
+        assert_eq!(post_script_table.glyph_id("A"), Some(2));
+    }
+
+    #[test]
+    fn case_post_script_table_v2_0_missing_glyph_names() {
+        let bytes: &[u8]  = &[
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x6A, 0x00, 0x64, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x03, 0x01, 0x02, 0x04, 0x42, 0x65, 0x74,
+            0x61];
+
+        let post_script_table = parse_post_script_table(bytes).unwrap().1;
+
+        assert_eq!(post_script_table.glyph_name(0), None);
+        assert_eq!(post_script_table.glyph_name(1), Some("Beta"));
+    }
+
+    #[test]
+    fn case_post_script_table_v4_0() {
+        let bytes: &[u8]  = &[
+            0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x6A, 0x00, 0x64, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x20, 0x82, 0xA0];
+
+        let post_script_table = parse_post_script_table(bytes).unwrap().1;
+
+        match post_script_table.version() {
+            PostScriptVersion::Version_4_0(post_script_table_v4) => {
+                assert_eq!(post_script_table_v4.underline_position(), -150);
+                assert_eq!(post_script_table_v4.character_codes(), &[0xFFFF, 0x0020, 0x82A0]);
+                assert_eq!(post_script_table_v4.character_code(0), None);
+                assert_eq!(post_script_table_v4.character_code(2), Some(0x82A0));
+                assert_eq!(post_script_table_v4.character_code(3), None);
+            },
+            _ => assert!(false)
+        }
+
+        assert_eq!(post_script_table.glyph_name(1), None);
+    }
+}
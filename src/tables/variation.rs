@@ -0,0 +1,361 @@
+//! Common table formats used by the font variations.
+//!
+//! https://docs.microsoft.com/en-gb/typography/opentype/spec/otvarcommonformats
+
+use nom::Err as NomErr;
+use nom::IResult;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_i8, be_i16, be_i32, be_u16, be_u32};
+use types::F2Dot14;
+
+/// Item Variation Store
+///
+/// Item variation stores are used for most variation data other than that used for TrueType
+/// glyph outlines, including the variation data of the 'CFF2', 'GDEF' and 'GPOS' tables.
+///
+/// The variation data is organized as a two-level hierarchy: an outer level indexing the
+/// ItemVariationData subtables and an inner level indexing the delta-set rows within a subtable.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ItemVariationStore {
+    format: u16,
+    variation_region_list: VariationRegionList,
+    item_variation_data: Vec<ItemVariationData>
+}
+
+impl ItemVariationStore {
+    /// Format — set to 1.
+    pub fn format(&self) -> u16 {
+        self.format
+    }
+
+    pub fn variation_region_list(&self) -> &VariationRegionList {
+        &self.variation_region_list
+    }
+
+    pub fn item_variation_data(&self) -> &[ItemVariationData] {
+        &self.item_variation_data
+    }
+
+    /// Scalars of the regions referenced by the given ItemVariationData subtable, for the given
+    /// normalized coordinates.
+    pub fn region_scalars(&self, outer_index: u16, coordinates: &[F2Dot14]) -> Option<Vec<f32>> {
+        let item_variation_data = self.item_variation_data.get(usize::from(outer_index))?;
+
+        item_variation_data.region_indexes.iter()
+            .map(|&region_index| self.variation_region_list.regions.get(usize::from(region_index))
+                .map(|region| region.scalar(coordinates)))
+            .collect()
+    }
+
+    /// Interpolated delta of the given delta-set row, for the given normalized coordinates.
+    ///
+    /// * `outer_index` - Index of the ItemVariationData subtable.
+    /// * `inner_index` - Index of the delta-set row within the subtable.
+    pub fn delta(&self, outer_index: u16, inner_index: u16, coordinates: &[F2Dot14]) -> Option<f32> {
+        let item_variation_data = self.item_variation_data.get(usize::from(outer_index))?;
+        let delta_set = item_variation_data.delta_sets.get(usize::from(inner_index))?;
+        let scalars = self.region_scalars(outer_index, coordinates)?;
+
+        Some(delta_set.iter().zip(scalars.iter()).map(|(&delta, scalar)| delta as f32 * scalar).sum())
+    }
+}
+
+/// Variation Region List
+///
+/// The regions of the variation space in which a variation delta applies.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VariationRegionList {
+    axis_count: u16,
+    regions: Vec<VariationRegion>
+}
+
+impl VariationRegionList {
+    /// The number of variation axes for this font. This must be the same number as axisCount
+    /// in the 'fvar' table.
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count
+    }
+
+    pub fn regions(&self) -> &[VariationRegion] {
+        &self.regions
+    }
+}
+
+/// A region of the variation space, defined by a range on each axis.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VariationRegion {
+    region_axes: Vec<RegionAxisCoordinates>
+}
+
+impl VariationRegion {
+    /// Array of region axis coordinates records, in the order of axes given in the 'fvar' table.
+    pub fn region_axes(&self) -> &[RegionAxisCoordinates] {
+        &self.region_axes
+    }
+
+    /// Scalar of the region for the given normalized coordinates, the product of the scalars
+    /// of each axis. Missing coordinates are considered to be 0 (default instance).
+    pub fn scalar(&self, coordinates: &[F2Dot14]) -> f32 {
+        self.region_axes.iter()
+            .enumerate()
+            .map(|(axis_index, region_axis)| region_axis.scalar(coordinates.get(axis_index).cloned().unwrap_or(0)))
+            .product()
+    }
+}
+
+/// The range of a region on one axis, using normalized coordinates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RegionAxisCoordinates {
+    start_coord: F2Dot14,
+    peak_coord: F2Dot14,
+    end_coord: F2Dot14
+}
+
+impl RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    pub fn start_coord(&self) -> F2Dot14 {
+        self.start_coord
+    }
+
+    /// The region peak coordinate value for the current axis.
+    pub fn peak_coord(&self) -> F2Dot14 {
+        self.peak_coord
+    }
+
+    /// The region end coordinate value for the current axis.
+    pub fn end_coord(&self) -> F2Dot14 {
+        self.end_coord
+    }
+
+    /// Scalar of the axis for the given normalized coordinate.
+    pub fn scalar(&self, coordinate: F2Dot14) -> f32 {
+        let (start, peak, end) = (self.start_coord, self.peak_coord, self.end_coord);
+
+        // Invalid or cross-zero ranges, and ranges peaking at zero, do not restrict the region
+        if start > peak || peak > end || (start < 0 && end > 0) || peak == 0 {
+            return 1.0;
+        }
+
+        if coordinate < start || coordinate > end {
+            return 0.0;
+        }
+
+        if coordinate == peak {
+            return 1.0;
+        }
+
+        if coordinate < peak {
+            f32::from(coordinate - start) / f32::from(peak - start)
+        }
+        else {
+            f32::from(end - coordinate) / f32::from(end - peak)
+        }
+    }
+}
+
+/// Item Variation Data
+///
+/// Delta-set rows, each row holding one delta per referenced region.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ItemVariationData {
+    region_indexes: Vec<u16>,
+    delta_sets: Vec<Vec<i32>>
+}
+
+impl ItemVariationData {
+    /// Array of indices into the variation region list for the regions referenced by this
+    /// item variation data table.
+    pub fn region_indexes(&self) -> &[u16] {
+        &self.region_indexes
+    }
+
+    /// Delta-set rows.
+    pub fn delta_sets(&self) -> &[Vec<i32>] {
+        &self.delta_sets
+    }
+}
+
+/// Parse an Item Variation Store, the offsets of its subtables being relative to the beginning
+/// of the input.
+pub fn parse_item_variation_store(input: &[u8]) -> IResult<&[u8], ItemVariationStore>
+{
+    let (remainder, format) = be_u16(input)?;
+
+    if format != 1 {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let (remainder, variation_region_list_offset) = be_u32(remainder)?;
+    let (remainder, item_variation_data_count) = be_u16(remainder)?;
+    let (remainder, item_variation_data_offsets) = count(be_u32, usize::from(item_variation_data_count))(remainder)?;
+
+    let (_, variation_region_list) = parse_variation_region_list(offset_slice(input, variation_region_list_offset)?)?;
+
+    let mut item_variation_data = Vec::with_capacity(item_variation_data_offsets.len());
+
+    for item_variation_data_offset in item_variation_data_offsets {
+        item_variation_data.push(parse_item_variation_data(offset_slice(input, item_variation_data_offset)?)?.1);
+    }
+
+    Ok((remainder, ItemVariationStore {
+        format,
+        variation_region_list,
+        item_variation_data
+    }))
+}
+
+fn offset_slice(input: &[u8], offset: u32) -> Result<&[u8], NomErr<(&[u8], ErrorKind)>> {
+    input.get(offset as usize..).ok_or_else(|| NomErr::Error(error_position!(input, ErrorKind::Eof)))
+}
+
+fn parse_variation_region_list(input: &[u8]) -> IResult<&[u8], VariationRegionList>
+{
+    let (input, axis_count) = be_u16(input)?;
+    let (input, region_count) = be_u16(input)?;
+    let (input, regions) = count(|input| {
+        let (input, region_axes) = count(parse_region_axis_coordinates, usize::from(axis_count))(input)?;
+        Ok((input, VariationRegion {
+            region_axes
+        }))
+    }, usize::from(region_count))(input)?;
+
+    Ok((input, VariationRegionList {
+        axis_count,
+        regions
+    }))
+}
+
+fn parse_region_axis_coordinates(input: &[u8]) -> IResult<&[u8], RegionAxisCoordinates>
+{
+    let (input, start_coord) = be_i16(input)?;
+    let (input, peak_coord) = be_i16(input)?;
+    let (input, end_coord) = be_i16(input)?;
+
+    Ok((input, RegionAxisCoordinates {
+        start_coord,
+        peak_coord,
+        end_coord
+    }))
+}
+
+fn parse_item_variation_data(input: &[u8]) -> IResult<&[u8], ItemVariationData>
+{
+    let (input, item_count) = be_u16(input)?;
+    let (input, word_delta_count) = be_u16(input)?;
+    let (input, region_index_count) = be_u16(input)?;
+    let (mut input, region_indexes) = count(be_u16, usize::from(region_index_count))(input)?;
+
+    // The high bit flags 32-bit and 16-bit deltas instead of 16-bit and 8-bit deltas, the low
+    // bits count the deltas stored with the larger size
+    let long_words = word_delta_count & 0x8000 != 0;
+    let word_count = usize::from(word_delta_count & 0x7FFF);
+
+    if word_count > usize::from(region_index_count) {
+        return Err(NomErr::Error(error_position!(input, ErrorKind::Verify)));
+    }
+
+    let mut delta_sets = Vec::with_capacity(usize::from(item_count));
+
+    for _ in 0..item_count {
+        let mut deltas = Vec::with_capacity(usize::from(region_index_count));
+
+        for region in 0..usize::from(region_index_count) {
+            let (remainder, delta) = match (long_words, region < word_count) {
+                (true, true) => be_i32(input)?,
+                (true, false) | (false, true) => {
+                    let (remainder, delta) = be_i16(input)?;
+                    (remainder, i32::from(delta))
+                },
+                (false, false) => {
+                    let (remainder, delta) = be_i8(input)?;
+                    (remainder, i32::from(delta))
+                }
+            };
+
+            input = remainder;
+            deltas.push(delta);
+        }
+
+        delta_sets.push(deltas);
+    }
+
+    Ok((input, ItemVariationData {
+        region_indexes,
+        delta_sets
+    }))
+}
+
+impl_parse!(
+    /// Parse Item Variation Store.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::parser::Parse;
+    /// use otf::tables::variation::ItemVariationStore;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x00, 0x01, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x01,
+    ///     0x00, 0x02, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00,
+    ///     0x00, 0x01, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x64, 0xEC];
+    ///
+    /// let item_variation_store = ItemVariationStore::parse(bytes).unwrap().1;
+    ///
+    /// assert_eq!(item_variation_store.delta(0, 0, &[0x2000]), Some(50.0));
+    /// assert_eq!(item_variation_store.delta(0, 0, &[-0x2000]), Some(-10.0));
+    /// assert_eq!(item_variation_store.delta(0, 0, &[]), Some(0.0));
+    /// ```
+    ItemVariationStore,
+    parse_item_variation_store
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_region_axis_coordinates_scalar() {
+        let region_axis = RegionAxisCoordinates {
+            start_coord: 0,
+            peak_coord: 0x2000,
+            end_coord: 0x4000
+        };
+
+        assert_eq!(region_axis.scalar(-0x1000), 0.0);
+        assert_eq!(region_axis.scalar(0), 0.0);
+        assert_eq!(region_axis.scalar(0x1000), 0.5);
+        assert_eq!(region_axis.scalar(0x2000), 1.0);
+        assert_eq!(region_axis.scalar(0x3000), 0.5);
+        assert_eq!(region_axis.scalar(0x4000), 0.0);
+
+        let cross_zero_region_axis = RegionAxisCoordinates {
+            start_coord: -0x4000,
+            peak_coord: 0x2000,
+            end_coord: 0x4000
+        };
+
+        assert_eq!(cross_zero_region_axis.scalar(0x4000), 1.0);
+    }
+
+    #[test]
+    fn case_item_variation_data_long_words() {
+        let bytes: &[u8]  = &[
+            0x00, 0x01, 0x80, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x86, 0xA0,
+            0xFF, 0x38];
+
+        let item_variation_data = parse_item_variation_data(bytes).unwrap().1;
+
+        assert_eq!(item_variation_data.region_indexes(), &[0, 1]);
+        assert_eq!(item_variation_data.delta_sets(), &[vec![100000, -200]]);
+    }
+
+    #[test]
+    fn case_item_variation_store_invalid_format() {
+        let bytes: &[u8]  = &[0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00];
+
+        assert!(parse_item_variation_store(bytes).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+use nom::IResult;
+use nom::bytes::complete::take;
+use nom::combinator::verify;
+use nom::number::complete::{be_i16, be_u16, be_u32};
+use types::Fixed;
+
+/// Vertical Header Table
+///
+/// The vertical header table (tag name: 'vhea') contains information needed for vertical fonts.
+/// The glyphs of vertical fonts are written either top to bottom or bottom to top. This table
+/// contains information that is general to the font as a whole. Information that pertains to
+/// specific glyphs is given in the vertical metrics table (tag name: 'vmtx').
+///
+/// In version 1.1 the ascender, descender and line gap are the vertical typographic metrics
+/// (vertTypoAscender, vertTypoDescender and vertTypoLineGap). In version 1.0 they are the
+/// distances from the centerline to the previous and next line's descent and the line gap.
+///
+/// More information on ['vhea'](https://docs.microsoft.com/en-gb/typography/opentype/spec/vhea)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VerticalHeaderTable {
+    version: Fixed,
+    ascender: i16,
+    descender: i16,
+    line_gap: i16,
+    advance_height_max: u16,
+    min_top_side_bearing: i16,
+    min_bottom_side_bearing: i16,
+    y_max_extent: i16,
+    caret_slope_rise: i16,
+    caret_slope_run: i16,
+    caret_offset: i16,
+    metric_data_format: i16,
+    num_of_long_ver_metrics: u16
+}
+
+impl VerticalHeaderTable {
+    /// Version number of the vertical header table; 0x00010000 for version 1.0 and 0x00011000 for
+    /// version 1.1.
+    pub fn version(&self) -> Fixed {
+        self.version
+    }
+
+    /// The vertical typographic ascender for this font. It is the distance in FUnits from the
+    /// ideographic em-box center baseline for the vertical axis to the right edge of the design
+    /// space for CJK / ideographic glyphs.
+    pub fn ascender(&self) -> i16 {
+        self.ascender
+    }
+
+    /// The vertical typographic descender for this font. It is the distance in FUnits from the
+    /// ideographic em-box center baseline for the vertical axis to the left edge of the design
+    /// space for CJK / ideographic glyphs.
+    pub fn descender(&self) -> i16 {
+        self.descender
+    }
+
+    /// The vertical typographic gap for this font.
+    pub fn line_gap(&self) -> i16 {
+        self.line_gap
+    }
+
+    /// The maximum advance height measurement in FUnits found in the font.
+    pub fn advance_height_max(&self) -> u16 {
+        self.advance_height_max
+    }
+
+    /// The minimum top side bearing measured in FUnits found in the font.
+    pub fn min_top_side_bearing(&self) -> i16 {
+        self.min_top_side_bearing
+    }
+
+    /// The minimum bottom side bearing measured in FUnits found in the font.
+    pub fn min_bottom_side_bearing(&self) -> i16 {
+        self.min_bottom_side_bearing
+    }
+
+    /// This is defined as the value of the minTopSideBearing field added to the result of the
+    /// value of the yMin field subtracted from the value of the yMax field.
+    pub fn y_max_extent(&self) -> i16 {
+        self.y_max_extent
+    }
+
+    /// The value of the caretSlopeRise field divided by the value of the caretSlopeRun field
+    /// determines the slope of the caret. A value of 0 for the rise and a value of 1 for the run
+    /// specifies a horizontal caret.
+    pub fn caret_slope_rise(&self) -> i16 {
+        self.caret_slope_rise
+    }
+
+    /// See [caret_slope_rise](#method.caret_slope_rise).
+    pub fn caret_slope_run(&self) -> i16 {
+        self.caret_slope_run
+    }
+
+    /// The amount by which the highlight on a slanted glyph needs to be shifted away from the
+    /// glyph in order to produce the best appearance. Set value equal to 0 for nonslanted fonts.
+    pub fn caret_offset(&self) -> i16 {
+        self.caret_offset
+    }
+
+    /// 0 for current format.
+    pub fn metric_data_format(&self) -> i16 {
+        self.metric_data_format
+    }
+
+    /// Number of advance heights in the vertical metrics table.
+    pub fn num_of_long_ver_metrics(&self) -> u16 {
+        self.num_of_long_ver_metrics
+    }
+}
+
+impl_parse!(
+    /// Parse Vertical Header Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::vhea::VerticalHeaderTable;
+    /// use otf::parser::Parse;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x00, 0x01, 0x10, 0x00, 0x01, 0xF4, 0xFE, 0x0C, 0x00, 0x00, 0x0B, 0xB8, 0xFF, 0x38,
+    ///     0xFE, 0xD4, 0x0B, 0x54, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03];
+    ///
+    /// let vertical_header_table = VerticalHeaderTable::parse(bytes).unwrap().1;
+    ///
+    /// assert_eq!(vertical_header_table.version(), 0x00011000);
+    /// assert_eq!(vertical_header_table.ascender(), 500);
+    /// assert_eq!(vertical_header_table.descender(), -500);
+    /// assert_eq!(vertical_header_table.line_gap(), 0);
+    /// assert_eq!(vertical_header_table.advance_height_max(), 3000);
+    /// assert_eq!(vertical_header_table.min_top_side_bearing(), -200);
+    /// assert_eq!(vertical_header_table.min_bottom_side_bearing(), -300);
+    /// assert_eq!(vertical_header_table.y_max_extent(), 2900);
+    /// assert_eq!(vertical_header_table.caret_slope_rise(), 0);
+    /// assert_eq!(vertical_header_table.caret_slope_run(), 1);
+    /// assert_eq!(vertical_header_table.caret_offset(), 0);
+    /// assert_eq!(vertical_header_table.metric_data_format(), 0);
+    /// assert_eq!(vertical_header_table.num_of_long_ver_metrics(), 3);
+    /// ```
+    VerticalHeaderTable, parse_vertical_header_table
+);
+
+pub fn parse_vertical_header_table(input: &[u8]) -> IResult<&[u8], VerticalHeaderTable>
+{
+    let (input, version) = verify(be_u32, |version| *version == 0x00010000 || *version == 0x00011000)(input)?;
+    let (input, ascender) = be_i16(input)?;
+    let (input, descender) = be_i16(input)?;
+    let (input, line_gap) = be_i16(input)?;
+    let (input, advance_height_max) = be_u16(input)?;
+    let (input, min_top_side_bearing) = be_i16(input)?;
+    let (input, min_bottom_side_bearing) = be_i16(input)?;
+    let (input, y_max_extent) = be_i16(input)?;
+    let (input, caret_slope_rise) = be_i16(input)?;
+    let (input, caret_slope_run) = be_i16(input)?;
+    let (input, caret_offset) = be_i16(input)?;
+    // reserved
+    let (input, _) = take(8usize)(input)?;
+    let (input, metric_data_format) = be_i16(input)?;
+    let (input, num_of_long_ver_metrics) = be_u16(input)?;
+
+    Ok((input, VerticalHeaderTable {
+        version: version as Fixed,
+        ascender,
+        descender,
+        line_gap,
+        advance_height_max,
+        min_top_side_bearing,
+        min_bottom_side_bearing,
+        y_max_extent,
+        caret_slope_rise,
+        caret_slope_run,
+        caret_offset,
+        metric_data_format,
+        num_of_long_ver_metrics
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Err;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn case_vertical_header_table_invalid_version() {
+        let bytes: &[u8] = &[
+            0x00, 0x02, 0x00, 0x00, 0x01, 0xF4, 0xFE, 0x0C, 0x00, 0x00, 0x0B, 0xB8, 0xFF, 0x38,
+            0xFE, 0xD4, 0x0B, 0x54, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03];
+
+        let expected = Err(Err::Error(error_position!(bytes, ErrorKind::Verify)));
+        assert_eq!(parse_vertical_header_table(bytes), expected);
+    }
+
+    #[test]
+    fn case_vertical_header_table_invalid_empty_slice() {
+        let bytes: &[u8] = &[];
+
+        let expected = Err(Err::Error(error_position!(bytes, ErrorKind::Eof)));
+        assert_eq!(parse_vertical_header_table(bytes), expected);
+    }
+}
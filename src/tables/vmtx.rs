@@ -0,0 +1,194 @@
+use error::Error;
+use nom::IResult;
+use nom::number::complete::{be_i16, be_u16};
+use nom::multi::count;
+use tables::GlyphId;
+use types::Rect;
+
+/// Vertical Metrics Table
+///
+/// The vertical metrics table allows you to specify the vertical spacing for each glyph in a
+/// vertical font. This table consists of either one or two arrays that contain metric
+/// information (the advance heights and top sidebearings) for the vertical layout of each of the
+/// glyphs in the font. The vertical metrics coordinate system is shown below.
+///
+/// Vertical fonts require both a vertical header table (tag name: 'vhea') and the vertical
+/// metrics table discussed below. The vertical header table contains information that is general
+/// to the font as a whole. The vertical metrics table contains information that pertains to
+/// specific glyphs.
+///
+/// As with the horizontal metrics, the number of longVerMetric records can be less than the
+/// number of glyphs, in which case the advance height of the last record applies to all remaining
+/// glyph IDs. The number of longVerMetric records is determined by the numOfLongVerMetrics field
+/// in the 'vhea' table, and the top side bearings of the remaining glyphs follow the records.
+///
+/// More information on ['vmtx'](https://docs.microsoft.com/en-gb/typography/opentype/spec/vmtx)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerticalMetricsTable {
+    v_metrics: Vec<LongVerMetricRecord>,
+    top_side_bearings: Vec<i16>
+}
+
+impl<'otf> VerticalMetricsTable {
+    pub fn v_metrics(&self) -> &Vec<LongVerMetricRecord> {
+        &self.v_metrics
+    }
+
+    pub fn top_side_bearings(&self) -> &[i16] {
+        &self.top_side_bearings
+    }
+
+    /// Advance height of the given glyph.
+    ///
+    /// Glyphs past the last longVerMetric record use the advance height of the last record.
+    /// Return None if the glyph ID is greater or equal to the number of glyphs.
+    pub fn advance_height(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self.v_metrics.get(usize::from(glyph_id)) {
+            Some(v_metric) => Some(v_metric.advance_height()),
+            None if self.top_side_bearing(glyph_id).is_some() => self.v_metrics.last().map(LongVerMetricRecord::advance_height),
+            None => None
+        }
+    }
+
+    /// Top side bearing of the given glyph, taken from the longVerMetric records or from the top
+    /// side bearings of the remaining glyphs.
+    pub fn top_side_bearing(&self, glyph_id: GlyphId) -> Option<i16> {
+        match self.v_metrics.get(usize::from(glyph_id)) {
+            Some(v_metric) => Some(v_metric.top_side_bearing()),
+            None => self.top_side_bearings.get(usize::from(glyph_id) - self.v_metrics.len()).cloned()
+        }
+    }
+
+    /// Bottom side bearing of the given glyph, computed from its bounding box as
+    /// `bsb = ah - (tsb + yMax - yMin)`.
+    pub fn bottom_side_bearing(&self, glyph_id: GlyphId, bounding_box: Rect<i16>) -> Option<i32> {
+        let advance_height = i32::from(self.advance_height(glyph_id)?);
+        let top_side_bearing = i32::from(self.top_side_bearing(glyph_id)?);
+
+        Some(advance_height - (top_side_bearing + i32::from(bounding_box.y_max()) - i32::from(bounding_box.y_min())))
+    }
+
+    /// Y coordinate of the vertical origin of the given glyph, computed from its bounding box as
+    /// `vertOriginY = tsb + yMax`.
+    pub fn vertical_origin(&self, glyph_id: GlyphId, bounding_box: Rect<i16>) -> Option<i32> {
+        Some(i32::from(self.top_side_bearing(glyph_id)?) + i32::from(bounding_box.y_max()))
+    }
+
+    /// Parse Vertical Metrics Table.
+    ///
+    /// * `num_of_long_ver_metrics` - The number of longVerMetric records is determined by the
+    ///   [numOfLongVerMetrics](../vhea/struct.VerticalHeaderTable.html#method.num_of_long_ver_metrics)
+    ///   field in the 'vhea' table.
+    /// * `num_glyphs` - The number of glyphs in the font is determined by the
+    ///   [numGlyphs](../maxp/struct.MaximumProfileTable.html#method.num_glyphs) field in the
+    ///   'maxp' table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::vmtx::VerticalMetricsTable;
+    /// use otf::types::Rect;
+    ///
+    /// let bytes: &[u8]  = &[0x03, 0xE8, 0x00, 0x64, 0x03, 0xE8, 0x00, 0x2E, 0x00, 0x78];
+    ///
+    /// let vertical_metrics_table = VerticalMetricsTable::parse(bytes, 2, 3).unwrap();
+    ///
+    /// assert_eq!(vertical_metrics_table.v_metrics().len(), 2);
+    /// assert_eq!(vertical_metrics_table.top_side_bearings(), &[120]);
+    ///
+    /// assert_eq!(vertical_metrics_table.advance_height(2), Some(1000));
+    /// assert_eq!(vertical_metrics_table.top_side_bearing(2), Some(120));
+    /// assert_eq!(vertical_metrics_table.vertical_origin(1, Rect::new(37, -72, 962, 834)), Some(880));
+    /// ```
+    pub fn parse(buf: &'otf[u8], num_of_long_ver_metrics: u16, num_glyphs: u16) -> Result<VerticalMetricsTable, Error> {
+        Ok(parse_vertical_metrics_table(buf, num_of_long_ver_metrics, num_glyphs)?.1)
+    }
+}
+
+/// The advance height and the top side bearing of a glyph. The top side bearing is the distance
+/// from the vertical origin to the top of the glyph bounding box (yMax), measured downwards.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LongVerMetricRecord {
+    advance_height: u16,
+    top_side_bearing: i16
+}
+
+impl LongVerMetricRecord {
+    /// The advance height of the glyph. Unsigned integer in font design units.
+    pub fn advance_height(&self) -> u16 {
+        self.advance_height
+    }
+
+    /// The top sidebearing of the glyph. Signed integer in font design units.
+    pub fn top_side_bearing(&self) -> i16 {
+        self.top_side_bearing
+    }
+}
+
+pub fn parse_vertical_metrics_table(input: &[u8], num_of_long_ver_metrics: u16, num_glyphs: u16)
+    -> IResult<&[u8], VerticalMetricsTable> {
+    let (input, v_metrics) = count(parse_long_ver_metric_record, usize::from(num_of_long_ver_metrics))(input)?;
+
+    if num_of_long_ver_metrics < num_glyphs {
+        let (input, top_side_bearings) = count(be_i16, usize::from(num_glyphs - num_of_long_ver_metrics))(input)?;
+
+        Ok((input, VerticalMetricsTable {
+            v_metrics,
+            top_side_bearings
+        }))
+    }
+    else {
+        Ok((input, VerticalMetricsTable {
+            v_metrics,
+            top_side_bearings: Vec::new()
+        }))
+    }
+}
+
+pub fn parse_long_ver_metric_record(input: &[u8]) -> IResult<&[u8], LongVerMetricRecord>
+{
+    let (input, advance_height) = be_u16(input)?;
+    let (input, top_side_bearing) = be_i16(input)?;
+
+    Ok((input, LongVerMetricRecord {
+        advance_height,
+        top_side_bearing
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Err;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn case_vertical_metrics_table_glyph_metrics() {
+        let bytes: &[u8] = &[0x03, 0xE8, 0x00, 0x64, 0x04, 0xB0, 0x00, 0x2E, 0xFF, 0xF6];
+
+        let vertical_metrics_table = parse_vertical_metrics_table(bytes, 2, 3).unwrap().1;
+
+        assert_eq!(vertical_metrics_table.advance_height(1), Some(1200));
+        assert_eq!(vertical_metrics_table.top_side_bearing(1), Some(46));
+        assert_eq!(vertical_metrics_table.bottom_side_bearing(1, Rect::new(37, -72, 962, 834)), Some(248));
+
+        // Trailing glyphs reuse the last advance height
+        assert_eq!(vertical_metrics_table.advance_height(2), Some(1200));
+        assert_eq!(vertical_metrics_table.top_side_bearing(2), Some(-10));
+        assert_eq!(vertical_metrics_table.vertical_origin(2, Rect::new(0, 0, 500, 890)), Some(880));
+
+        assert_eq!(vertical_metrics_table.advance_height(3), None);
+        assert_eq!(vertical_metrics_table.top_side_bearing(3), None);
+        assert_eq!(vertical_metrics_table.vertical_origin(3, Rect::new(0, 0, 0, 0)), None);
+    }
+
+    #[test]
+    fn case_vertical_metrics_table_invalid_empty_slice() {
+        let bytes: &[u8] = &[];
+
+        let expected = Err(Err::Error(error_position!(bytes, ErrorKind::Eof)));
+        assert_eq!(parse_vertical_metrics_table(bytes, 10, 10), expected);
+    }
+}
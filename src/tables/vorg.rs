@@ -0,0 +1,131 @@
+use nom::IResult;
+use nom::combinator::verify;
+use nom::multi::count;
+use nom::number::complete::{be_i16, be_u16};
+use tables::GlyphId;
+
+/// Vertical Origin Table
+///
+/// This optional table specifies the y coordinate of the vertical origin of every glyph in the
+/// font. This table may be optionally present only in CFF or CFF2 OpenType fonts. If present in
+/// TrueType OpenType fonts it must be ignored by font clients, because the glyph outline data
+/// already allows the origin to be computed from the 'vmtx' top side bearings and the glyph
+/// bounding boxes.
+///
+/// The vertical origin records are sorted by glyph index. Glyphs that have no record use the
+/// default vertical origin.
+///
+/// More information on ['VORG'](https://docs.microsoft.com/en-gb/typography/opentype/spec/vorg)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerticalOriginTable {
+    default_vert_origin_y: i16,
+    vert_origin_y_metrics: Vec<VertOriginYMetricsRecord>
+}
+
+impl VerticalOriginTable {
+    /// The y coordinate of a glyph’s vertical origin, in the font’s design coordinate system, to
+    /// be used if no entry is present for the glyph in the vertOriginYMetrics array.
+    pub fn default_vert_origin_y(&self) -> i16 {
+        self.default_vert_origin_y
+    }
+
+    /// Array of VertOriginYMetrics records, sorted by glyph ID.
+    pub fn vert_origin_y_metrics(&self) -> &[VertOriginYMetricsRecord] {
+        &self.vert_origin_y_metrics
+    }
+
+    /// The y coordinate of the vertical origin of the given glyph.
+    pub fn vert_origin_y(&self, glyph_id: GlyphId) -> i16 {
+        match self.vert_origin_y_metrics.binary_search_by_key(&glyph_id, VertOriginYMetricsRecord::glyph_index) {
+            Ok(index) => self.vert_origin_y_metrics[index].vert_origin_y(),
+            Err(_) => self.default_vert_origin_y
+        }
+    }
+}
+
+impl_parse!(
+    /// Parse Vertical Origin Table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate opentype_rs as otf;
+    ///
+    /// use otf::tables::vorg::VerticalOriginTable;
+    /// use otf::parser::Parse;
+    ///
+    /// let bytes: &[u8]  = &[
+    ///     0x00, 0x01, 0x00, 0x00, 0x03, 0x70, 0x00, 0x02, 0x00, 0x05, 0x03, 0x52, 0x00, 0x2A,
+    ///     0x03, 0x84];
+    ///
+    /// let vertical_origin_table = VerticalOriginTable::parse(bytes).unwrap().1;
+    ///
+    /// assert_eq!(vertical_origin_table.default_vert_origin_y(), 880);
+    /// assert_eq!(vertical_origin_table.vert_origin_y_metrics().len(), 2);
+    ///
+    /// assert_eq!(vertical_origin_table.vert_origin_y(5), 850);
+    /// assert_eq!(vertical_origin_table.vert_origin_y(42), 900);
+    /// assert_eq!(vertical_origin_table.vert_origin_y(6), 880);
+    /// ```
+    VerticalOriginTable, parse_vertical_origin_table
+);
+
+/// Vertical origin of a glyph.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VertOriginYMetricsRecord {
+    glyph_index: GlyphId,
+    vert_origin_y: i16
+}
+
+impl VertOriginYMetricsRecord {
+    /// Glyph index.
+    pub fn glyph_index(&self) -> GlyphId {
+        self.glyph_index
+    }
+
+    /// Y coordinate, in the font’s design coordinate system, of the glyph’s vertical origin.
+    pub fn vert_origin_y(&self) -> i16 {
+        self.vert_origin_y
+    }
+}
+
+pub fn parse_vertical_origin_table(input: &[u8]) -> IResult<&[u8], VerticalOriginTable>
+{
+    let (input, _) = verify(be_u16, |major_version| *major_version == 1)(input)?;
+    let (input, _) = verify(be_u16, |minor_version| *minor_version == 0)(input)?;
+    let (input, default_vert_origin_y) = be_i16(input)?;
+    let (input, num_vert_origin_y_metrics) = be_u16(input)?;
+    let (input, vert_origin_y_metrics) = count(parse_vert_origin_y_metrics_record,
+        usize::from(num_vert_origin_y_metrics))(input)?;
+
+    Ok((input, VerticalOriginTable {
+        default_vert_origin_y,
+        vert_origin_y_metrics
+    }))
+}
+
+fn parse_vert_origin_y_metrics_record(input: &[u8]) -> IResult<&[u8], VertOriginYMetricsRecord>
+{
+    let (input, glyph_index) = be_u16(input)?;
+    let (input, vert_origin_y) = be_i16(input)?;
+
+    Ok((input, VertOriginYMetricsRecord {
+        glyph_index,
+        vert_origin_y
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Err;
+    use nom::error::ErrorKind;
+
+    #[test]
+    fn case_vertical_origin_table_truncated_records() {
+        let bytes: &[u8] = &[0x00, 0x01, 0x00, 0x00, 0x03, 0x70, 0x00, 0x02, 0x00, 0x05, 0x03, 0x52];
+
+        let expected = Err(Err::Error(error_position!(&bytes[12..], ErrorKind::Eof)));
+        assert_eq!(parse_vertical_origin_table(bytes), expected);
+    }
+}
@@ -13,6 +13,9 @@ pub type LongDateTime = i64;
 
 pub type Fixed = i32;
 
+/// 16-bit signed fixed number with the low 14 bits of fraction (2.14).
+pub type F2Dot14 = i16;
+
 /// A rectangular bounding box defined by two points (x_min, y_min) and (x_max, y_max).
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Rect<T> {